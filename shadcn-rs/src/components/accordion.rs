@@ -34,6 +34,11 @@ use yew::prelude::*;
 
 /// Accordion type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum AccordionType {
     /// Only one item can be open at a time
     Single,
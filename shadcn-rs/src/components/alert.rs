@@ -177,7 +177,7 @@ mod tests {
 
         for variant in variants {
             let props = AlertProps {
-                variant: variant.clone(),
+                variant,
                 class: Classes::new(),
                 style: None,
                 id: None,
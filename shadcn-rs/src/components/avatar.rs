@@ -25,6 +25,11 @@ use yew::prelude::*;
 
 /// Avatar shape
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum AvatarShape {
     /// Circular avatar
     Circle,
@@ -118,7 +118,7 @@ mod tests {
 
         for variant in variants {
             let props = BadgeProps {
-                variant: variant.clone(),
+                variant,
                 class: Classes::new(),
                 style: None,
                 id: None,
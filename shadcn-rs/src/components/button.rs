@@ -211,7 +211,7 @@ mod tests {
 
         for variant in variants {
             let props = ButtonProps {
-                variant: variant.clone(),
+                variant,
                 size: Size::Md,
                 disabled: false,
                 loading: false,
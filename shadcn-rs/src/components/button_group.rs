@@ -25,6 +25,11 @@ use yew::prelude::*;
 
 /// Button group orientation
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ButtonGroupOrientation {
     /// Horizontal layout (side by side)
     Horizontal,
@@ -24,20 +24,101 @@
 //!     }
 //! }
 //! ```
+//!
+//! ## Range selection
+//!
+//! ```rust,no_run
+//! use yew::prelude::*;
+//! use shadcn_rs::{Calendar, CalendarMode, DateRange};
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     let range = use_state(|| None::<DateRange>);
+//!
+//!     let on_range_change = {
+//!         let range = range.clone();
+//!         Callback::from(move |new_range: DateRange| range.set(Some(new_range)))
+//!     };
+//!
+//!     html! {
+//!         <Calendar
+//!             mode={CalendarMode::Range}
+//!             range={*range}
+//!             {on_range_change}
+//!             max_range_days={14}
+//!         />
+//!     }
+//! }
+//! ```
+
+mod grid;
+mod keyboard;
+mod selection;
 
+pub use grid::{
+    CalendarWeek, add_months, decade_years, format_date, is_date_disabled, is_month_outside,
+    is_year_outside, month_end, month_start, month_weeks, parse_date, reveal_month,
+    weekday_from_index, weekdays,
+};
+pub use keyboard::navigate_date;
+pub use selection::{DateRange, RangePosition, is_range_length_allowed, toggle_date};
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use yew::prelude::*;
 
+use keyboard::focus_day;
+
+use crate::components::locale::{Locale, use_locale};
+use crate::hooks::{use_controllable_state, use_controllable_state_optional};
+use crate::utils::generate_id;
+
 /// Calendar mode
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum CalendarMode {
-    /// Select a single date
+    /// Select a single date (`value`)
     Single,
-    /// Select multiple dates
+    /// Toggle any number of dates (`values`)
     Multiple,
-    /// Select a range of dates
+    /// Select a range of dates with two clicks (`range`)
     Range,
 }
 
+/// What the calendar grid shows
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CalendarView {
+    /// Days of the displayed months
+    Days,
+    /// Months of a year
+    Months,
+    /// Years of a decade
+    Years,
+}
+
+/// A day of the month grid, as passed to the `render_day` callback
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarDay {
+    /// Date of the day
+    pub date: NaiveDate,
+    /// Whether the day belongs to an adjacent month
+    pub outside: bool,
+    /// Whether the day is today
+    pub today: bool,
+    /// Whether the day is selected, or a bound of the shown range
+    pub selected: bool,
+    /// Whether the day cannot be selected
+    pub disabled: bool,
+    /// Modifiers of the day, from the `modifiers` prop
+    pub modifiers: Vec<AttrValue>,
+}
+
 /// Calendar component properties
 #[derive(Properties, PartialEq, Clone)]
 pub struct CalendarProps {
@@ -45,19 +126,63 @@ pub struct CalendarProps {
     #[prop_or(CalendarMode::Single)]
     pub mode: CalendarMode,
 
-    /// Selected date (ISO format YYYY-MM-DD)
+    /// Selected date (ISO format YYYY-MM-DD), the untyped form of `value`
     #[prop_or_default]
     pub selected: Option<AttrValue>,
 
-    /// Date selection handler
+    /// Date selection handler, called with the clicked date (ISO format) in every mode
     #[prop_or_default]
     pub onselect: Option<Callback<String>>,
 
-    /// Minimum selectable date
+    /// Selected date in single mode (controlled)
+    #[prop_or_default]
+    pub value: Option<NaiveDate>,
+
+    /// Initially selected date in single mode (uncontrolled)
+    #[prop_or_default]
+    pub default_value: Option<NaiveDate>,
+
+    /// Called with the selected date in single mode
+    #[prop_or_default]
+    pub on_change: Option<Callback<NaiveDate>>,
+
+    /// Selected dates in multiple mode (controlled)
+    #[prop_or_default]
+    pub values: Option<Vec<NaiveDate>>,
+
+    /// Initially selected dates in multiple mode (uncontrolled)
+    #[prop_or_default]
+    pub default_values: Vec<NaiveDate>,
+
+    /// Called with the sorted selected dates when a date is toggled in multiple mode
+    #[prop_or_default]
+    pub on_values_change: Option<Callback<Vec<NaiveDate>>>,
+
+    /// Selected range in range mode (controlled)
+    #[prop_or_default]
+    pub range: Option<DateRange>,
+
+    /// Initially selected range in range mode (uncontrolled)
+    #[prop_or_default]
+    pub default_range: Option<DateRange>,
+
+    /// Called with the range once both of its days are picked in range mode
+    #[prop_or_default]
+    pub on_range_change: Option<Callback<DateRange>>,
+
+    /// Minimum number of days of a range, bounds included
+    #[prop_or_default]
+    pub min_range_days: Option<u32>,
+
+    /// Maximum number of days of a range, bounds included
+    #[prop_or_default]
+    pub max_range_days: Option<u32>,
+
+    /// Minimum selectable date (ISO format)
     #[prop_or_default]
     pub min_date: Option<AttrValue>,
 
-    /// Maximum selectable date
+    /// Maximum selectable date (ISO format)
     #[prop_or_default]
     pub max_date: Option<AttrValue>,
 
@@ -65,13 +190,32 @@ pub struct CalendarProps {
     #[prop_or_default]
     pub disabled_dates: Vec<AttrValue>,
 
+    /// Returns `true` for dates that cannot be selected, besides
+    /// `disabled_dates` and the dates outside `min_date` and `max_date`
+    #[prop_or_default]
+    pub disabled: Option<Callback<NaiveDate, bool>>,
+
+    /// CSS classes added to the day buttons of dates, such as `marked` for
+    /// an event dot (see also `render_day`)
+    #[prop_or_default]
+    pub modifiers: HashMap<NaiveDate, Vec<AttrValue>>,
+
+    /// Content of the day buttons, instead of the day of the month
+    #[prop_or_default]
+    pub render_day: Option<Callback<CalendarDay, Html>>,
+
     /// Show week numbers
     #[prop_or(false)]
     pub show_week_numbers: bool,
 
-    /// First day of week (0 = Sunday, 1 = Monday, etc.)
-    #[prop_or(0)]
-    pub first_day_of_week: u8,
+    /// First day of week (0 = Sunday, 1 = Monday, etc.), defaults to the
+    /// first day of the week of the locale
+    #[prop_or_default]
+    pub first_day_of_week: Option<u8>,
+
+    /// Number of months shown side by side
+    #[prop_or(1)]
+    pub number_of_months: u32,
 
     /// Additional CSS classes
     #[prop_or_default]
@@ -80,181 +224,692 @@ pub struct CalendarProps {
 
 /// Calendar component
 ///
-/// A date picker with month/year navigation and date selection.
+/// Month grids with month navigation and date selection. The grid opens
+/// on the month of the selected date, or the current month, and shows
+/// `number_of_months` consecutive months. Clicking a month caption switches
+/// to a grid of the months of its year, whose caption switches to a grid
+/// of the years of its decade.
+///
+/// In range mode the first click picks the start of the range and the
+/// second its end, with the pending range previewed under the pointer.
+/// While a range is pending, days that would make it shorter than
+/// `min_range_days` or longer than `max_range_days` are disabled.
+///
+/// Days are customised with `modifiers`, classes added to their buttons,
+/// and `render_day`, which replaces the day of the month with any content
+/// such as a price or a badge. The buttons themselves stay with the
+/// calendar, so selection and keyboard navigation are unchanged. The
+/// `disabled` predicate disables days beyond the static `disabled_dates`.
 ///
 /// # Accessibility
-/// - Full keyboard navigation
-/// - Screen reader support
-/// - ARIA attributes for dates and navigation
+/// - WAI-ARIA date grid with a single tab stop on the focused day
+/// - Arrow keys move by day and week, `PageUp`/`PageDown` by month,
+///   `Shift+PageUp`/`Shift+PageDown` by year and `Home`/`End` to the week
+///   boundaries; `Enter` or `Space` selects the focused day
+/// - `Escape` cancels a pending range
+/// - Day buttons are labelled with the full date, with `aria-selected`,
+///   `aria-disabled` and `aria-current="date"` states
 #[function_component(Calendar)]
 pub fn calendar(props: &CalendarProps) -> Html {
     let CalendarProps {
-        mode: _,
-        selected: _,
-        onselect: _,
-        min_date: _,
-        max_date: _,
-        disabled_dates: _,
+        mode,
+        selected,
+        onselect,
+        value,
+        default_value,
+        on_change,
+        values,
+        default_values,
+        on_values_change,
+        range,
+        default_range,
+        on_range_change,
+        min_range_days,
+        max_range_days,
+        min_date,
+        max_date,
+        disabled_dates,
+        disabled,
+        modifiers,
+        render_day,
         show_week_numbers,
-        first_day_of_week: _,
+        first_day_of_week,
+        number_of_months,
         class,
     } = props.clone();
 
-    // Current month/year being displayed
-    // Default to January 2024 for now (in a real implementation, use chrono or time crate)
-    let current_month = use_state(|| 0u8);
-    let current_year = use_state(|| 2024i32);
+    let today = Local::now().date_naive();
+    let min_date = min_date.as_deref().and_then(parse_date);
+    let max_date = max_date.as_deref().and_then(parse_date);
+    let disabled_dates: Vec<NaiveDate> = disabled_dates
+        .iter()
+        .filter_map(|date| parse_date(date))
+        .collect();
+    let locale = use_locale();
+    let first_day_of_week = week_start(first_day_of_week, &locale);
+
+    let (value, set_value) = use_controllable_state_optional(
+        value.or_else(|| selected.as_deref().and_then(parse_date)),
+        default_value,
+        None,
+    );
+    let (values, set_values) = use_controllable_state(values, default_values, on_values_change);
+    let (range, set_range) = use_controllable_state_optional(range, default_range, None);
+    let mut values = values;
+    values.sort();
+
+    // Start of a range waiting for its end, and the day under the pointer
+    let anchor = use_state(|| None::<NaiveDate>);
+    let hover = use_state(|| None::<NaiveDate>);
+
+    // First of the displayed months
+    let month = use_state(|| {
+        let initial = match mode {
+            CalendarMode::Single => value,
+            CalendarMode::Multiple => values.first().copied(),
+            CalendarMode::Range => range.map(|range| range.start),
+        };
+        month_start(initial.unwrap_or(today))
+    });
+    let view = use_state(|| CalendarView::Days);
+    // Year shown by the month grid, and whose decade the year grid shows
+    let picker_year = use_state(|| month.year());
+    let caption_id = use_state(|| generate_id("calendar-caption"));
+    // Day holding the tab stop, and whether to move focus to it after rendering
+    let focused = use_state(|| None::<NaiveDate>);
+    let focus_requested = use_mut_ref(|| false);
+    let calendar_ref = use_node_ref();
+
+    let number_of_months = number_of_months.max(1);
+    let last_month = add_months(*month, number_of_months as i32 - 1);
+    let in_view = |date: &NaiveDate| (*month..=last_month).contains(&month_start(*date));
+    let selected_in_view = match mode {
+        CalendarMode::Single => value.filter(in_view),
+        CalendarMode::Multiple => values.iter().copied().find(in_view),
+        CalendarMode::Range => range.map(|range| range.start).filter(in_view),
+    };
+    let focus_target = focused
+        .filter(in_view)
+        .or(selected_in_view)
+        .or(Some(today).filter(in_view))
+        .unwrap_or(*month);
+    {
+        let calendar_ref = calendar_ref.clone();
+        let focus_requested = focus_requested.clone();
+        use_effect_with(focus_target, move |target| {
+            if std::mem::take(&mut *focus_requested.borrow_mut()) {
+                focus_day(&calendar_ref, &format_date(*target));
+            }
+        });
+    }
+
+    // While a range is pending, ends that make it too short or too long are disabled
+    let is_disabled = {
+        let anchor = *anchor;
+        let disabled_dates = disabled_dates.clone();
+        Rc::new(move |date: NaiveDate| {
+            is_date_disabled(date, min_date, max_date, &disabled_dates)
+                || disabled
+                    .as_ref()
+                    .is_some_and(|disabled| disabled.emit(date))
+                || anchor.is_some_and(|start| {
+                    !is_range_length_allowed(
+                        &DateRange::new(start, date),
+                        min_range_days,
+                        max_range_days,
+                    )
+                })
+        })
+    };
 
     let classes: Classes = vec![Classes::from("calendar"), class].into_iter().collect();
 
-    // Month names
-    let month_names = vec![
-        "January",
-        "February",
-        "March",
-        "April",
-        "May",
-        "June",
-        "July",
-        "August",
-        "September",
-        "October",
-        "November",
-        "December",
-    ];
-
-    // Day names
-    let day_names = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
-
-    // Navigate to previous month
-    let go_prev_month = {
-        let current_month = current_month.clone();
-        let current_year = current_year.clone();
-        Callback::from(move |_| {
-            if *current_month == 0 {
-                current_month.set(11);
-                current_year.set(*current_year - 1);
-            } else {
-                current_month.set(*current_month - 1);
+    // Navigation steps by a month, a year or a decade depending on the view,
+    // and stops at the months of `min_date` and `max_date`
+    let (can_go_previous, can_go_next) = match *view {
+        CalendarView::Days => (
+            min_date.is_none_or(|min| min < *month),
+            max_date.is_none_or(|max| max >= add_months(last_month, 1)),
+        ),
+        CalendarView::Months => (
+            !is_year_outside(*picker_year - 1, min_date, max_date),
+            !is_year_outside(*picker_year + 1, min_date, max_date),
+        ),
+        CalendarView::Years => {
+            let years = decade_years(*picker_year);
+            (
+                min_date.is_none_or(|min| min.year() <= years[0]),
+                max_date.is_none_or(|max| max.year() >= years[11]),
+            )
+        }
+    };
+    let go_by = |step: i32| {
+        let month = month.clone();
+        let picker_year = picker_year.clone();
+        let view = view.clone();
+        Callback::from(move |_: MouseEvent| match *view {
+            CalendarView::Days => month.set(add_months(*month, step)),
+            CalendarView::Months => picker_year.set(*picker_year + step),
+            CalendarView::Years => picker_year.set(*picker_year + step * 10),
+        })
+    };
+    let go_previous = go_by(-1);
+    let go_next = go_by(1);
+    let (previous_label, next_label) = match *view {
+        CalendarView::Days => (
+            locale.previous_month_label.clone(),
+            locale.next_month_label.clone(),
+        ),
+        CalendarView::Months => (
+            locale.previous_year_label.clone(),
+            locale.next_year_label.clone(),
+        ),
+        CalendarView::Years => (
+            locale.previous_decade_label.clone(),
+            locale.next_decade_label.clone(),
+        ),
+    };
+
+    let on_day_select = {
+        let values = values.clone();
+        let anchor = anchor.clone();
+        let hover = hover.clone();
+        let month = month.clone();
+        let focused = focused.clone();
+        let mode = mode.clone();
+        Callback::from(move |date: NaiveDate| {
+            // Selecting a day of an adjacent month also shows that month
+            month.set(reveal_month(*month, number_of_months, date));
+            focused.set(Some(date));
+            match mode {
+                CalendarMode::Single => {
+                    set_value.emit(Some(date));
+                    if let Some(on_change) = &on_change {
+                        on_change.emit(date);
+                    }
+                }
+                CalendarMode::Multiple => set_values.emit(toggle_date(&values, date)),
+                CalendarMode::Range => match *anchor {
+                    None => {
+                        anchor.set(Some(date));
+                        hover.set(Some(date));
+                    }
+                    Some(start) => {
+                        let range = DateRange::new(start, date);
+                        anchor.set(None);
+                        hover.set(None);
+                        set_range.emit(Some(range));
+                        if let Some(on_range_change) = &on_range_change {
+                            on_range_change.emit(range);
+                        }
+                    }
+                },
+            }
+            if let Some(onselect) = &onselect {
+                onselect.emit(format_date(date));
             }
         })
     };
 
-    // Navigate to next month
-    let go_next_month = {
-        let current_month = current_month.clone();
-        let current_year = current_year.clone();
-        Callback::from(move |_| {
-            if *current_month == 11 {
-                current_month.set(0);
-                current_year.set(*current_year + 1);
-            } else {
-                current_month.set(*current_month + 1);
+    let onkeydown = {
+        let on_day_select = on_day_select.clone();
+        let is_disabled = is_disabled.clone();
+        let anchor = anchor.clone();
+        let hover = hover.clone();
+        let month = month.clone();
+        let focused = focused.clone();
+        let rtl = locale.rtl;
+        Callback::from(move |event: KeyboardEvent| {
+            // Left and right follow the reading direction
+            let key = match (rtl, event.key().as_str()) {
+                (true, "ArrowLeft") => String::from("ArrowRight"),
+                (true, "ArrowRight") => String::from("ArrowLeft"),
+                (_, key) => key.to_string(),
+            };
+            match key.as_str() {
+                "Enter" | " " => {
+                    event.prevent_default();
+                    if !is_disabled(focus_target) {
+                        on_day_select.emit(focus_target);
+                    }
+                }
+                "Escape" if anchor.is_some() => {
+                    anchor.set(None);
+                    hover.set(None);
+                }
+                _ => {
+                    let Some(date) =
+                        navigate_date(focus_target, &key, event.shift_key(), first_day_of_week)
+                    else {
+                        return;
+                    };
+                    event.prevent_default();
+                    let date = min_date.map_or(date, |min| date.max(min));
+                    let date = max_date.map_or(date, |max| date.min(max));
+                    month.set(reveal_month(*month, number_of_months, date));
+                    if anchor.is_some() {
+                        hover.set(Some(date));
+                    }
+                    *focus_requested.borrow_mut() = true;
+                    focused.set(Some(date));
+                }
             }
         })
     };
 
-    html! {
-        <div class={classes} role="application" aria-label="Calendar">
-            <div class="calendar-header">
+    // The pending range is previewed instead of the selected one
+    let pending = anchor.map(|start| DateRange::new(start, hover.unwrap_or(start)));
+    let shown_range = match mode {
+        CalendarMode::Range => pending.or(range),
+        _ => None,
+    };
+
+    // Side by side months leave out the days of adjacent months, which
+    // would otherwise show up twice
+    let show_outside_days = number_of_months == 1;
+
+    let day_cell = |date: NaiveDate, shown_month: NaiveDate| -> Html {
+        let outside = month_start(date) != shown_month;
+        if outside && !show_outside_days {
+            return html! { <td class="calendar-cell" key={format_date(date)} role="gridcell" /> };
+        }
+        let disabled = is_disabled(date);
+        let position = shown_range.and_then(|range| RangePosition::of(&range, date));
+        let is_selected = match mode {
+            CalendarMode::Single => value == Some(date),
+            CalendarMode::Multiple => values.binary_search(&date).is_ok(),
+            CalendarMode::Range => matches!(
+                position,
+                Some(RangePosition::Start | RangePosition::End | RangePosition::Single)
+            ),
+        };
+        let onclick = (!disabled).then(|| {
+            let on_day_select = on_day_select.clone();
+            Callback::from(move |_: MouseEvent| on_day_select.emit(date))
+        });
+        let onmouseenter = anchor.is_some().then(|| {
+            let hover = hover.clone();
+            Callback::from(move |_: MouseEvent| hover.set(Some(date)))
+        });
+        let day_modifiers = modifiers.get(&date).cloned().unwrap_or_default();
+        let modifier_classes: Classes = day_modifiers
+            .iter()
+            .map(|modifier| modifier.to_string())
+            .collect();
+        let content = match &render_day {
+            Some(render_day) => render_day.emit(CalendarDay {
+                date,
+                outside,
+                today: date == today,
+                selected: is_selected,
+                disabled,
+                modifiers: day_modifiers,
+            }),
+            None => html! { { date.day() } },
+        };
+        html! {
+            <td
+                class="calendar-cell"
+                key={format_date(date)}
+                role="gridcell"
+                aria-selected={is_selected.to_string()}
+            >
                 <button
                     type="button"
-                    class="calendar-nav-button"
-                    onclick={go_prev_month}
-                    aria-label="Previous month"
+                    data-date={format_date(date)}
+                    tabindex={if date == focus_target { "0" } else { "-1" }}
+                    class={classes!(
+                        "calendar-day",
+                        is_selected.then_some("selected"),
+                        (date == today).then_some("today"),
+                        outside.then_some("outside"),
+                        position.map(|position| position.classes()),
+                        (position.is_some() && pending.is_some()).then_some("range-preview"),
+                        modifier_classes,
+                    )}
+                    {onclick}
+                    {onmouseenter}
+                    aria-label={locale.format_long_date(date)}
+                    aria-disabled={disabled.then_some("true")}
+                    aria-current={(date == today).then_some("date")}
                 >
-                    { "‹" }
+                    { content }
                 </button>
-                <div class="calendar-month-year">
-                    { format!("{} {}", month_names[*current_month as usize], *current_year) }
+            </td>
+        }
+    };
+
+    let render_caption = |label: String, is_first: bool, is_last: bool, id: String| -> Html {
+        // Clicking the caption zooms out to the month grid, then the year grid
+        let (onclick, title) = match *view {
+            CalendarView::Days => {
+                let view = view.clone();
+                let picker_year = picker_year.clone();
+                let year = month.year();
+                let onclick = Callback::from(move |_: MouseEvent| {
+                    picker_year.set(year);
+                    view.set(CalendarView::Months);
+                });
+                (Some(onclick), locale.choose_month_label.clone())
+            }
+            CalendarView::Months => {
+                let view = view.clone();
+                let onclick = Callback::from(move |_: MouseEvent| view.set(CalendarView::Years));
+                (Some(onclick), locale.choose_year_label.clone())
+            }
+            CalendarView::Years => (None, AttrValue::default()),
+        };
+        html! {
+            <div class="calendar-caption">
+                if is_first {
+                    <button
+                        type="button"
+                        class="calendar-nav-button calendar-nav-previous"
+                        onclick={go_previous.clone()}
+                        disabled={!can_go_previous}
+                        aria-label={previous_label.clone()}
+                    >
+                        { if locale.rtl { "›" } else { "‹" } }
+                    </button>
+                }
+                <div class="calendar-caption-label" {id} aria-live="polite">
+                    if let Some(onclick) = onclick {
+                        <button type="button" class="calendar-caption-button" {onclick} {title}>
+                            { label }
+                        </button>
+                    } else {
+                        { label }
+                    }
                 </div>
-                <button
-                    type="button"
-                    class="calendar-nav-button"
-                    onclick={go_next_month}
-                    aria-label="Next month"
+                if is_last {
+                    <button
+                        type="button"
+                        class="calendar-nav-button calendar-nav-next"
+                        onclick={go_next.clone()}
+                        disabled={!can_go_next}
+                        aria-label={next_label.clone()}
+                    >
+                        { if locale.rtl { "‹" } else { "›" } }
+                    </button>
+                }
+            </div>
+        }
+    };
+
+    let render_month = |offset: usize| -> Html {
+        let shown_month = add_months(*month, offset as i32);
+        let id = format!("{}-{}", *caption_id, offset);
+        let label = locale.format_month_year(shown_month);
+        html! {
+            <div class="calendar-month" key={format_date(shown_month)}>
+                { render_caption(label, offset == 0, offset == number_of_months as usize - 1, id.clone()) }
+                <table
+                    class="calendar-table"
+                    role="grid"
+                    aria-labelledby={id}
+                    aria-multiselectable={(mode != CalendarMode::Single).then_some("true")}
                 >
-                    { "›" }
-                </button>
+                    <thead>
+                        <tr class="calendar-head-row">
+                            if show_week_numbers {
+                                <th class="calendar-head-cell calendar-week-number" scope="col" aria-label={locale.week_name.clone()}>
+                                    { locale.week_label.clone() }
+                                </th>
+                            }
+                            {
+                                weekdays(first_day_of_week).into_iter().map(|day| {
+                                    html! {
+                                        <th
+                                            class="calendar-head-cell"
+                                            scope="col"
+                                            key={day.num_days_from_sunday()}
+                                            abbr={locale.day_name(day).clone()}
+                                        >
+                                            { locale.day_name_short(day).clone() }
+                                        </th>
+                                    }
+                                }).collect::<Html>()
+                            }
+                        </tr>
+                    </thead>
+                    <tbody onkeydown={onkeydown.clone()}>
+                        {
+                            month_weeks(shown_month, first_day_of_week).into_iter().map(|week| {
+                                html! {
+                                    <tr class="calendar-row" key={format_date(week.days[0])}>
+                                        if show_week_numbers {
+                                            <td class="calendar-cell calendar-week-number">
+                                                { week.number }
+                                            </td>
+                                        }
+                                        {
+                                            week.days.into_iter()
+                                                .map(|date| day_cell(date, shown_month))
+                                                .collect::<Html>()
+                                        }
+                                    </tr>
+                                }
+                            }).collect::<Html>()
+                        }
+                    </tbody>
+                </table>
             </div>
-            <div class="calendar-grid">
-                <div class="calendar-weekdays">
-                    if show_week_numbers {
-                        <div class="calendar-weekday">{ "Wk" }</div>
-                    }
+        }
+    };
+
+    let render_month_picker = || -> Html {
+        let year = *picker_year;
+        html! {
+            <div class="calendar-month calendar-picker">
+                { render_caption(year.to_string(), true, true, format!("{}-0", *caption_id)) }
+                <div class="calendar-picker-grid" role="group" aria-label={locale.months_label.clone()}>
                     {
-                        day_names.iter().map(|day| {
+                        (1..=12).map(|number| {
+                            let shown_month = NaiveDate::from_ymd_opt(year, number, 1)
+                                .unwrap_or(*month);
+                            let is_current = shown_month == *month;
+                            let onclick = {
+                                let month = month.clone();
+                                let view = view.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    month.set(shown_month);
+                                    view.set(CalendarView::Days);
+                                })
+                            };
                             html! {
-                                <div class="calendar-weekday" key={*day}>
-                                    { day }
-                                </div>
+                                <button
+                                    type="button"
+                                    key={number}
+                                    class={classes!("calendar-picker-cell", is_current.then_some("selected"))}
+                                    {onclick}
+                                    disabled={is_month_outside(shown_month, min_date, max_date)}
+                                    aria-label={locale.format_month_year(shown_month)}
+                                    aria-pressed={is_current.to_string()}
+                                >
+                                    { locale.month_names_short[number as usize - 1].clone() }
+                                </button>
                             }
                         }).collect::<Html>()
                     }
                 </div>
-                <div class="calendar-days">
-                    // Days grid would be rendered here
-                    // This is a simplified version
-                    { "Calendar days grid" }
+            </div>
+        }
+    };
+
+    let render_year_picker = || -> Html {
+        let years = decade_years(*picker_year);
+        let label = format!("{} – {}", years[1], years[10]);
+        html! {
+            <div class="calendar-month calendar-picker">
+                { render_caption(label, true, true, format!("{}-0", *caption_id)) }
+                <div class="calendar-picker-grid" role="group" aria-label={locale.years_label.clone()}>
+                    {
+                        years.iter().enumerate().map(|(index, &year)| {
+                            let is_current = year == month.year();
+                            let onclick = {
+                                let picker_year = picker_year.clone();
+                                let view = view.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    picker_year.set(year);
+                                    view.set(CalendarView::Months);
+                                })
+                            };
+                            html! {
+                                <button
+                                    type="button"
+                                    key={year}
+                                    class={classes!(
+                                        "calendar-picker-cell",
+                                        is_current.then_some("selected"),
+                                        (index == 0 || index == years.len() - 1).then_some("outside"),
+                                    )}
+                                    {onclick}
+                                    disabled={is_year_outside(year, min_date, max_date)}
+                                    aria-pressed={is_current.to_string()}
+                                >
+                                    { year }
+                                </button>
+                            }
+                        }).collect::<Html>()
+                    }
                 </div>
             </div>
+        }
+    };
+
+    html! {
+        <div
+            class={classes}
+            ref={calendar_ref}
+            role="application"
+            aria-label={locale.calendar_label.clone()}
+            lang={locale.code.clone()}
+            dir={locale.rtl.then_some("rtl")}
+        >
+            <div class="calendar-months">
+                {
+                    match *view {
+                        CalendarView::Days => (0..number_of_months as usize).map(render_month).collect::<Html>(),
+                        CalendarView::Months => render_month_picker(),
+                        CalendarView::Years => render_year_picker(),
+                    }
+                }
+            </div>
         </div>
     }
 }
 
+/// First day of the week of the grid, given by its index or else by the locale
+fn week_start(first_day_of_week: Option<u8>, locale: &Locale) -> Weekday {
+    first_day_of_week.map_or(locale.first_day_of_week, weekday_from_index)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_calendar_mode_single() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap();
         let props = CalendarProps {
             mode: CalendarMode::Single,
             selected: None,
             onselect: None,
+            value: Some(date),
+            default_value: None,
+            on_change: None,
+            values: None,
+            default_values: vec![],
+            on_values_change: None,
+            range: None,
+            default_range: None,
+            on_range_change: None,
+            min_range_days: None,
+            max_range_days: None,
             min_date: None,
             max_date: None,
             disabled_dates: vec![],
+            disabled: None,
+            modifiers: HashMap::new(),
+            render_day: None,
             show_week_numbers: false,
-            first_day_of_week: 0,
+            first_day_of_week: None,
+            number_of_months: 1,
             class: Classes::new(),
         };
 
         assert_eq!(props.mode, CalendarMode::Single);
+        assert_eq!(props.value, Some(date));
     }
 
     #[test]
     fn test_calendar_mode_multiple() {
+        let first = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap();
+        let second = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
         let props = CalendarProps {
             mode: CalendarMode::Multiple,
             selected: None,
             onselect: None,
+            value: None,
+            default_value: None,
+            on_change: None,
+            values: Some(toggle_date(&[first], second)),
+            default_values: vec![],
+            on_values_change: None,
+            range: None,
+            default_range: None,
+            on_range_change: None,
+            min_range_days: None,
+            max_range_days: None,
             min_date: None,
             max_date: None,
             disabled_dates: vec![],
+            disabled: None,
+            modifiers: HashMap::new(),
+            render_day: None,
             show_week_numbers: false,
-            first_day_of_week: 0,
+            first_day_of_week: None,
+            number_of_months: 1,
             class: Classes::new(),
         };
 
         assert_eq!(props.mode, CalendarMode::Multiple);
+        assert_eq!(props.values, Some(vec![second, first]));
     }
 
     #[test]
     fn test_calendar_mode_range() {
+        let start = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
         let props = CalendarProps {
             mode: CalendarMode::Range,
             selected: None,
             onselect: None,
+            value: None,
+            default_value: None,
+            on_change: None,
+            values: None,
+            default_values: vec![],
+            on_values_change: None,
+            range: Some(DateRange::new(end, start)),
+            default_range: None,
+            on_range_change: None,
+            min_range_days: None,
+            max_range_days: None,
             min_date: None,
             max_date: None,
             disabled_dates: vec![],
+            disabled: None,
+            modifiers: HashMap::new(),
+            render_day: None,
             show_week_numbers: false,
-            first_day_of_week: 0,
+            first_day_of_week: None,
+            number_of_months: 1,
             class: Classes::new(),
         };
 
         assert_eq!(props.mode, CalendarMode::Range);
+        assert_eq!(props.range, Some(DateRange { start, end }));
     }
 
     #[test]
@@ -263,15 +918,39 @@ mod tests {
             mode: CalendarMode::Single,
             selected: None,
             onselect: None,
+            value: None,
+            default_value: None,
+            on_change: None,
+            values: None,
+            default_values: vec![],
+            on_values_change: None,
+            range: None,
+            default_range: None,
+            on_range_change: None,
+            min_range_days: None,
+            max_range_days: None,
             min_date: None,
             max_date: None,
             disabled_dates: vec![],
+            disabled: None,
+            modifiers: HashMap::new(),
+            render_day: None,
             show_week_numbers: true,
-            first_day_of_week: 0,
+            first_day_of_week: None,
+            number_of_months: 1,
             class: Classes::new(),
         };
 
         assert!(props.show_week_numbers);
+
+        // The week number column holds the ISO week of each row
+        let month = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+        let numbers: Vec<u32> =
+            month_weeks(month, week_start(props.first_day_of_week, &Locale::en()))
+                .iter()
+                .map(|week| week.number)
+                .collect();
+        assert_eq!(numbers, vec![49, 50, 51, 52, 1]);
     }
 
     #[test]
@@ -280,14 +959,47 @@ mod tests {
             mode: CalendarMode::Single,
             selected: None,
             onselect: None,
+            value: None,
+            default_value: None,
+            on_change: None,
+            values: None,
+            default_values: vec![],
+            on_values_change: None,
+            range: None,
+            default_range: None,
+            on_range_change: None,
+            min_range_days: None,
+            max_range_days: None,
             min_date: None,
             max_date: None,
             disabled_dates: vec![],
+            disabled: None,
+            modifiers: HashMap::new(),
+            render_day: None,
             show_week_numbers: false,
-            first_day_of_week: 1,
+            first_day_of_week: Some(1),
+            number_of_months: 1,
             class: Classes::new(),
         };
 
-        assert_eq!(props.first_day_of_week, 1);
+        assert_eq!(props.first_day_of_week, Some(1));
+
+        // Columns start on Monday, whatever the locale
+        let first_day = week_start(props.first_day_of_week, &Locale::en());
+        let columns = weekdays(first_day);
+        assert_eq!(columns[0], Weekday::Mon);
+        assert_eq!(columns[6], Weekday::Sun);
+        let month = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let weeks = month_weeks(month, first_day);
+        assert!(
+            weeks
+                .iter()
+                .all(|week| week.days[0].weekday() == Weekday::Mon)
+        );
+        assert_eq!(weeks[0].days[2], month);
+
+        // Without the prop, the locale decides
+        assert_eq!(week_start(None, &Locale::de()), Weekday::Mon);
+        assert_eq!(week_start(None, &Locale::en()), Weekday::Sun);
     }
 }
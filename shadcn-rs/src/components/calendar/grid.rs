@@ -0,0 +1,242 @@
+//! Date arithmetic for the calendar month grid
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+/// A week row of the month grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarWeek {
+    /// ISO 8601 week number
+    pub number: u32,
+    /// The seven days of the row, starting with the first day of the week
+    pub days: Vec<NaiveDate>,
+}
+
+/// Parse an ISO date (`YYYY-MM-DD`)
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+/// Format a date as ISO (`YYYY-MM-DD`)
+pub fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// Weekday for a `first_day_of_week` index (0 = Sunday, 1 = Monday, etc.)
+pub fn weekday_from_index(index: u8) -> Weekday {
+    (0..index % 7).fold(Weekday::Sun, |day, _| day.succ())
+}
+
+/// The seven weekdays in display order, starting with `first_day_of_week`
+pub fn weekdays(first_day_of_week: Weekday) -> Vec<Weekday> {
+    std::iter::successors(Some(first_day_of_week), |day| Some(day.succ()))
+        .take(7)
+        .collect()
+}
+
+/// First day of the month of a date
+pub fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// First day of the month `months` after (or, if negative, before) the month of a date
+pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let start = month_start(date);
+    let shifted = if months >= 0 {
+        start.checked_add_months(Months::new(months.unsigned_abs()))
+    } else {
+        start.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
+    shifted.unwrap_or(start)
+}
+
+/// Last day of the month of a date
+pub fn month_end(date: NaiveDate) -> NaiveDate {
+    add_months(date, 1).pred_opt().unwrap_or(date)
+}
+
+/// The weeks shown for a month: every week containing a day of the month,
+/// padded with the days of the adjacent months
+///
+/// Week numbers are the ISO week of each row's Thursday, which is the ISO
+/// week of most of its days whatever the first day of the week.
+pub fn month_weeks(month: NaiveDate, first_day_of_week: Weekday) -> Vec<CalendarWeek> {
+    let first = month_start(month);
+    let last = month_end(month);
+    let offset =
+        (7 + first.weekday().num_days_from_monday() - first_day_of_week.num_days_from_monday()) % 7;
+    let start = first - Days::new(u64::from(offset));
+
+    std::iter::successors(Some(start), |week| week.checked_add_days(Days::new(7)))
+        .take_while(|week| *week <= last)
+        .map(|week| {
+            let days: Vec<NaiveDate> = week.iter_days().take(7).collect();
+            let number = days
+                .iter()
+                .find(|day| day.weekday() == Weekday::Thu)
+                .map_or(0, |day| day.iso_week().week());
+            CalendarWeek { number, days }
+        })
+        .collect()
+}
+
+/// Returns `true` if a date is before `min`, after `max` or one of the `disabled` dates
+pub fn is_date_disabled(
+    date: NaiveDate,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    disabled: &[NaiveDate],
+) -> bool {
+    min.is_some_and(|min| date < min)
+        || max.is_some_and(|max| date > max)
+        || disabled.contains(&date)
+}
+
+/// First displayed month that brings a date into view when `count` months
+/// are shown from `first`
+///
+/// Dates before the first month become the first month, dates after the
+/// last month become the last month, and shown dates leave `first` as is.
+pub fn reveal_month(first: NaiveDate, count: u32, date: NaiveDate) -> NaiveDate {
+    let first = month_start(first);
+    let last_offset = count.max(1) as i32 - 1;
+    let month = month_start(date);
+    if month < first {
+        month
+    } else if month > add_months(first, last_offset) {
+        add_months(month, -last_offset)
+    } else {
+        first
+    }
+}
+
+/// Returns `true` if no day of the month of a date is between `min` and `max`
+pub fn is_month_outside(month: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> bool {
+    min.is_some_and(|min| month_end(month) < min) || max.is_some_and(|max| month_start(month) > max)
+}
+
+/// Returns `true` if no day of a year is between `min` and `max`
+pub fn is_year_outside(year: i32, min: Option<NaiveDate>, max: Option<NaiveDate>) -> bool {
+    min.is_some_and(|min| year < min.year()) || max.is_some_and(|max| year > max.year())
+}
+
+/// Years of the decade grid: the decade of a year, with the last year of
+/// the previous decade and the first year of the next one
+pub fn decade_years(year: i32) -> Vec<i32> {
+    let start = year - year.rem_euclid(10);
+    (start - 1..=start + 10).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_format_date() {
+        assert_eq!(parse_date("2024-02-29"), Some(date(2024, 2, 29)));
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("02/29/2024"), None);
+        assert_eq!(format_date(date(2024, 3, 5)), "2024-03-05");
+    }
+
+    #[test]
+    fn test_weekdays() {
+        assert_eq!(weekday_from_index(0), Weekday::Sun);
+        assert_eq!(weekday_from_index(1), Weekday::Mon);
+        assert_eq!(weekday_from_index(13), Weekday::Sat);
+        assert_eq!(weekdays(Weekday::Mon)[6], Weekday::Sun);
+        assert_eq!(weekdays(Weekday::Sun)[1], Weekday::Mon);
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(add_months(date(2024, 1, 31), 1), date(2024, 2, 1));
+        assert_eq!(add_months(date(2024, 1, 15), -1), date(2023, 12, 1));
+        assert_eq!(add_months(date(2024, 11, 3), 14), date(2026, 1, 1));
+        assert_eq!(month_end(date(2024, 2, 10)), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn test_month_weeks_sunday_start() {
+        // February 2024 starts on a Thursday
+        let weeks = month_weeks(date(2024, 2, 14), Weekday::Sun);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0].days[0], date(2024, 1, 28));
+        assert_eq!(weeks[4].days[6], date(2024, 3, 2));
+        assert!(weeks.iter().all(|week| week.days.len() == 7));
+        assert_eq!(weeks[0].number, 5);
+    }
+
+    #[test]
+    fn test_month_weeks_monday_start() {
+        let weeks = month_weeks(date(2024, 2, 1), Weekday::Mon);
+        assert_eq!(weeks[0].days[0], date(2024, 1, 29));
+        assert_eq!(weeks[0].days[6], date(2024, 2, 4));
+        assert_eq!(weeks[0].number, 5);
+
+        let weeks = month_weeks(date(2024, 3, 1), Weekday::Mon);
+        assert_eq!(weeks.len(), 5);
+        // Six rows when the month starts late in the week and is long
+        let weeks = month_weeks(date(2024, 9, 1), Weekday::Mon);
+        assert_eq!(weeks.len(), 6);
+        assert_eq!(weeks[5].days[0], date(2024, 9, 30));
+    }
+
+    #[test]
+    fn test_month_weeks_iso_year_boundary() {
+        // 2020-12-28 belongs to ISO week 53 of 2020
+        let weeks = month_weeks(date(2021, 1, 1), Weekday::Mon);
+        assert_eq!(weeks[0].days[0], date(2020, 12, 28));
+        assert_eq!(weeks[0].number, 53);
+        assert_eq!(weeks[1].number, 1);
+    }
+
+    #[test]
+    fn test_reveal_month() {
+        let first = date(2024, 3, 1);
+        assert_eq!(reveal_month(first, 2, date(2024, 4, 30)), first);
+        assert_eq!(reveal_month(first, 2, date(2024, 5, 1)), date(2024, 4, 1));
+        assert_eq!(reveal_month(first, 2, date(2024, 2, 29)), date(2024, 2, 1));
+        assert_eq!(reveal_month(first, 1, date(2025, 1, 9)), date(2025, 1, 1));
+        assert_eq!(reveal_month(first, 0, date(2024, 3, 9)), first);
+    }
+
+    #[test]
+    fn test_month_and_year_outside() {
+        let min = Some(date(2024, 3, 15));
+        let max = Some(date(2025, 6, 1));
+
+        assert!(is_month_outside(date(2024, 2, 1), min, max));
+        assert!(!is_month_outside(date(2024, 3, 1), min, max));
+        assert!(!is_month_outside(date(2025, 6, 1), min, max));
+        assert!(is_month_outside(date(2025, 7, 1), min, max));
+        assert!(is_year_outside(2023, min, max));
+        assert!(!is_year_outside(2025, min, max));
+        assert!(is_year_outside(2026, min, max));
+    }
+
+    #[test]
+    fn test_decade_years() {
+        let years = decade_years(2024);
+        assert_eq!(years.len(), 12);
+        assert_eq!(years[0], 2019);
+        assert_eq!(years[11], 2030);
+        assert_eq!(decade_years(-5)[0], -11);
+    }
+
+    #[test]
+    fn test_is_date_disabled() {
+        let min = Some(date(2024, 1, 10));
+        let max = Some(date(2024, 1, 20));
+        let disabled = [date(2024, 1, 15)];
+
+        assert!(is_date_disabled(date(2024, 1, 9), min, max, &disabled));
+        assert!(!is_date_disabled(date(2024, 1, 10), min, max, &disabled));
+        assert!(is_date_disabled(date(2024, 1, 15), min, max, &disabled));
+        assert!(is_date_disabled(date(2024, 1, 21), min, max, &disabled));
+        assert!(!is_date_disabled(date(2030, 1, 1), None, None, &[]));
+    }
+}
@@ -0,0 +1,117 @@
+//! Keyboard navigation of the calendar date grid
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+/// Date reached from a focused date with a navigation key
+///
+/// Follows the WAI-ARIA date grid pattern: arrows move by day and week,
+/// `PageUp`/`PageDown` by month (by year with `shift`) keeping the day of
+/// the month where possible, and `Home`/`End` to the first and last day of
+/// the week. Returns `None` for other keys.
+pub fn navigate_date(
+    date: NaiveDate,
+    key: &str,
+    shift: bool,
+    first_day_of_week: Weekday,
+) -> Option<NaiveDate> {
+    let months = if shift { 12 } else { 1 };
+    let days_into_week =
+        (7 + date.weekday().num_days_from_monday() - first_day_of_week.num_days_from_monday()) % 7;
+    match key {
+        "ArrowLeft" => date.checked_sub_days(Days::new(1)),
+        "ArrowRight" => date.checked_add_days(Days::new(1)),
+        "ArrowUp" => date.checked_sub_days(Days::new(7)),
+        "ArrowDown" => date.checked_add_days(Days::new(7)),
+        "PageUp" => date.checked_sub_months(Months::new(months)),
+        "PageDown" => date.checked_add_months(Months::new(months)),
+        "Home" => date.checked_sub_days(Days::new(u64::from(days_into_week))),
+        "End" => date.checked_add_days(Days::new(u64::from(6 - days_into_week))),
+        _ => None,
+    }
+}
+
+/// Focus the day button of a date in the calendar
+pub(crate) fn focus_day(calendar_ref: &NodeRef, date: &str) {
+    let day = calendar_ref.cast::<Element>().and_then(|calendar| {
+        calendar
+            .query_selector(&format!(".calendar-day[data-date=\"{}\"]", date))
+            .ok()
+            .flatten()
+    });
+    if let Some(day) = day.and_then(|day| day.dyn_into::<HtmlElement>().ok()) {
+        let _ = day.focus();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_navigate_by_day_and_week() {
+        let from = date(2, 29);
+        assert_eq!(
+            navigate_date(from, "ArrowRight", false, Weekday::Sun),
+            Some(date(3, 1))
+        );
+        assert_eq!(
+            navigate_date(from, "ArrowLeft", false, Weekday::Sun),
+            Some(date(2, 28))
+        );
+        assert_eq!(
+            navigate_date(from, "ArrowUp", false, Weekday::Sun),
+            Some(date(2, 22))
+        );
+        assert_eq!(
+            navigate_date(from, "ArrowDown", false, Weekday::Sun),
+            Some(date(3, 7))
+        );
+        assert_eq!(navigate_date(from, "Tab", false, Weekday::Sun), None);
+    }
+
+    #[test]
+    fn test_navigate_by_month_and_year() {
+        let from = date(1, 31);
+        assert_eq!(
+            navigate_date(from, "PageDown", false, Weekday::Sun),
+            Some(date(2, 29))
+        );
+        assert_eq!(
+            navigate_date(from, "PageUp", false, Weekday::Sun),
+            NaiveDate::from_ymd_opt(2023, 12, 31)
+        );
+        assert_eq!(
+            navigate_date(date(2, 29), "PageDown", true, Weekday::Sun),
+            NaiveDate::from_ymd_opt(2025, 2, 28)
+        );
+    }
+
+    #[test]
+    fn test_navigate_to_week_boundaries() {
+        // Wednesday, May 15 2024
+        let from = date(5, 15);
+        assert_eq!(
+            navigate_date(from, "Home", false, Weekday::Sun),
+            Some(date(5, 12))
+        );
+        assert_eq!(
+            navigate_date(from, "End", false, Weekday::Sun),
+            Some(date(5, 18))
+        );
+        assert_eq!(
+            navigate_date(from, "Home", false, Weekday::Mon),
+            Some(date(5, 13))
+        );
+        assert_eq!(
+            navigate_date(from, "End", false, Weekday::Mon),
+            Some(date(5, 19))
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Selection values of the multiple and range calendar modes
+
+use chrono::NaiveDate;
+
+/// An inclusive range of dates, `start` on or before `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    /// First day of the range
+    pub start: NaiveDate,
+    /// Last day of the range
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Create a range between two dates, in either order
+    pub fn new(a: NaiveDate, b: NaiveDate) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Returns `true` if the date is within the range, bounds included
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Number of days in the range, bounds included
+    pub fn days(&self) -> u32 {
+        (self.end - self.start).num_days() as u32 + 1
+    }
+}
+
+/// Position of a day within a selected or previewed range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangePosition {
+    /// First day of the range
+    Start,
+    /// Day between the first and last days
+    Middle,
+    /// Last day of the range
+    End,
+    /// First and last day of a one-day range
+    Single,
+}
+
+impl RangePosition {
+    /// Position of a date in a range, `None` outside of it
+    pub fn of(range: &DateRange, date: NaiveDate) -> Option<Self> {
+        if !range.contains(date) {
+            return None;
+        }
+        Some(match (date == range.start, date == range.end) {
+            (true, true) => RangePosition::Single,
+            (true, false) => RangePosition::Start,
+            (false, true) => RangePosition::End,
+            (false, false) => RangePosition::Middle,
+        })
+    }
+
+    /// CSS classes of a day at this position
+    pub fn classes(&self) -> &'static str {
+        match self {
+            RangePosition::Start => "range-start",
+            RangePosition::Middle => "range-middle",
+            RangePosition::End => "range-end",
+            RangePosition::Single => "range-start range-end",
+        }
+    }
+}
+
+/// Returns `true` if a range length (in days, bounds included) is within
+/// `min_days` and `max_days`
+pub fn is_range_length_allowed(
+    range: &DateRange,
+    min_days: Option<u32>,
+    max_days: Option<u32>,
+) -> bool {
+    let days = range.days();
+    min_days.is_none_or(|min| days >= min) && max_days.is_none_or(|max| days <= max)
+}
+
+/// Add a date to a sorted selection, or remove it if already selected
+pub fn toggle_date(selected: &[NaiveDate], date: NaiveDate) -> Vec<NaiveDate> {
+    let mut dates = selected.to_vec();
+    match dates.binary_search(&date) {
+        Ok(index) => {
+            dates.remove(index);
+        }
+        Err(index) => dates.insert(index, date),
+    }
+    dates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_date_range() {
+        let range = DateRange::new(date(3, 10), date(3, 2));
+        assert_eq!(range.start, date(3, 2));
+        assert_eq!(range.end, date(3, 10));
+        assert_eq!(range.days(), 9);
+        assert!(range.contains(date(3, 2)));
+        assert!(range.contains(date(3, 10)));
+        assert!(!range.contains(date(3, 11)));
+        assert_eq!(DateRange::new(date(2, 28), date(3, 1)).days(), 3);
+    }
+
+    #[test]
+    fn test_range_position() {
+        let range = DateRange::new(date(1, 5), date(1, 7));
+        assert_eq!(RangePosition::of(&range, date(1, 4)), None);
+        assert_eq!(
+            RangePosition::of(&range, date(1, 5)),
+            Some(RangePosition::Start)
+        );
+        assert_eq!(
+            RangePosition::of(&range, date(1, 6)),
+            Some(RangePosition::Middle)
+        );
+        assert_eq!(
+            RangePosition::of(&range, date(1, 7)),
+            Some(RangePosition::End)
+        );
+
+        let day = DateRange::new(date(1, 5), date(1, 5));
+        assert_eq!(
+            RangePosition::of(&day, date(1, 5)),
+            Some(RangePosition::Single)
+        );
+        assert_eq!(RangePosition::Single.classes(), "range-start range-end");
+    }
+
+    #[test]
+    fn test_range_length_allowed() {
+        let week = DateRange::new(date(1, 1), date(1, 7));
+        assert!(is_range_length_allowed(&week, None, None));
+        assert!(is_range_length_allowed(&week, Some(7), Some(7)));
+        assert!(!is_range_length_allowed(&week, Some(8), None));
+        assert!(!is_range_length_allowed(&week, None, Some(6)));
+    }
+
+    #[test]
+    fn test_range_length_around_anchor() {
+        // Days on both sides of the first click are limited the same way
+        let anchor = date(1, 10);
+        let allowed = |day| is_range_length_allowed(&DateRange::new(anchor, day), Some(3), Some(5));
+        assert!(!allowed(date(1, 11)));
+        assert!(allowed(date(1, 12)));
+        assert!(allowed(date(1, 14)));
+        assert!(!allowed(date(1, 15)));
+        assert!(!allowed(date(1, 9)));
+        assert!(allowed(date(1, 8)));
+        assert!(allowed(date(1, 6)));
+        assert!(!allowed(date(1, 5)));
+    }
+
+    #[test]
+    fn test_toggle_date() {
+        let selected = toggle_date(&[], date(1, 9));
+        let selected = toggle_date(&selected, date(1, 3));
+        assert_eq!(selected, vec![date(1, 3), date(1, 9)]);
+        assert_eq!(toggle_date(&selected, date(1, 9)), vec![date(1, 3)]);
+    }
+}
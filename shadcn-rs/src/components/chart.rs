@@ -2,6 +2,10 @@
 //!
 //! SVG-based charting system with multiple chart types.
 //!
+//! The scales laying out the axes ([`LinearScale`], [`LogScale`],
+//! [`BandScale`] and [`TimeScale`]) are plain values without a browser
+//! dependency, reusable for custom charts.
+//!
 //! # Examples
 //!
 //! ```rust,no_run
@@ -27,10 +31,43 @@
 //! }
 //! ```
 
+mod geometry;
+mod render;
+mod scales;
+mod series;
+mod ticks;
+
+pub use geometry::{
+    PlotArea, arc_path, area_path, band_path, line_path, pie_angles, polar, segments, svg_number,
+};
+pub use scales::{
+    BandScale, LinearScale, LogScale, TimeInterval, TimeScale, pad_domain, tick_labels,
+};
+pub use series::{ChartSeries, align_series, categories, stack};
+pub use ticks::{format_tick, nice_number, nice_ticks};
+
+use render::{
+    CartesianPlot, MarkBox, PieLayout, PiePlot, hit_mark, render_axes, render_bars, render_hover,
+    render_lines, render_pie,
+};
+use scales::format_value;
+
+use std::collections::HashSet;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
 use yew::prelude::*;
 
+use crate::components::aspect_ratio::AspectRatio;
+use crate::components::tooltip::TooltipContent;
+use crate::hooks::use_element_size;
+
 /// Chart type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ChartType {
     /// Bar chart
     Bar,
@@ -53,6 +90,58 @@ pub struct ChartData {
     pub value: f64,
 }
 
+/// A data point of a chart, as passed to `on_point_click`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartPoint {
+    /// Index of the series in `series`, 0 for `data`
+    pub series: usize,
+    /// Name of the series, empty for `data`
+    pub name: AttrValue,
+    /// Label of the point, its category
+    pub label: String,
+    /// Value of the point
+    pub value: f64,
+}
+
+/// Default colors, the chart palette of the theme
+const DEFAULT_COLORS: [&str; 5] = [
+    "hsl(var(--color-chart-1))",
+    "hsl(var(--color-chart-2))",
+    "hsl(var(--color-chart-3))",
+    "hsl(var(--color-chart-4))",
+    "hsl(var(--color-chart-5))",
+];
+
+/// Number of ticks aimed for on the value axis
+const TICK_COUNT: usize = 5;
+
+/// Color of item `index`, cycling through the given colors or, without
+/// any, the chart palette of the theme (`--color-chart-1` to `-5`)
+pub fn chart_color(colors: Option<&[AttrValue]>, index: usize) -> AttrValue {
+    match colors.filter(|colors| !colors.is_empty()) {
+        Some(colors) => colors[index % colors.len()].clone(),
+        None => AttrValue::Static(DEFAULT_COLORS[index % DEFAULT_COLORS.len()]),
+    }
+}
+
+/// Ticks of the value axis, which always includes zero
+pub fn value_ticks(values: &[f64]) -> Vec<f64> {
+    let min = values.iter().copied().fold(0.0, f64::min);
+    let max = values.iter().copied().fold(0.0, f64::max);
+    nice_ticks(min, max, TICK_COUNT)
+}
+
+/// Size of a chart `width` wide, with the height given by the aspect ratio
+/// (width / height) or else `height`
+pub fn chart_size(width: f64, aspect_ratio: Option<f64>, height: u32) -> (u32, u32) {
+    let width = width.round().max(1.0);
+    let height = match aspect_ratio.filter(|ratio| *ratio > 0.0 && ratio.is_finite()) {
+        Some(ratio) => (width / ratio).round().max(1.0) as u32,
+        None => height,
+    };
+    (width as u32, height)
+}
+
 /// Chart component properties
 #[derive(Properties, PartialEq, Clone)]
 pub struct ChartProps {
@@ -60,17 +149,35 @@ pub struct ChartProps {
     #[prop_or(ChartType::Bar)]
     pub chart_type: ChartType,
 
-    /// Chart data
+    /// Chart data, a single series of points
+    #[prop_or_default]
     pub data: Vec<ChartData>,
 
-    /// Chart width in pixels
+    /// Named series of points, used instead of `data` when not empty
+    #[prop_or_default]
+    pub series: Vec<ChartSeries>,
+
+    /// Stack the series of bar and area charts instead of grouping or
+    /// overlaying them
+    #[prop_or(false)]
+    pub stacked: bool,
+
+    /// Chart width in pixels, the initial width of a responsive chart
     #[prop_or(500)]
     pub width: u32,
 
-    /// Chart height in pixels
+    /// Chart height in pixels, unless given by `aspect_ratio`
     #[prop_or(300)]
     pub height: u32,
 
+    /// Fill the width of the container and redraw as it resizes
+    #[prop_or(false)]
+    pub responsive: bool,
+
+    /// Ratio of width to height, giving the height from the width
+    #[prop_or_default]
+    pub aspect_ratio: Option<f64>,
+
     /// Show legend
     #[prop_or(true)]
     pub show_legend: bool,
@@ -83,10 +190,27 @@ pub struct ChartProps {
     #[prop_or(true)]
     pub show_axis: bool,
 
+    /// Show the value of each data point on the chart
+    #[prop_or(true)]
+    pub show_values: bool,
+
+    /// Show a tooltip with the values at the hovered category or slice
+    #[prop_or(true)]
+    pub show_tooltip: bool,
+
     /// Color scheme (CSS colors)
     #[prop_or_default]
     pub colors: Option<Vec<AttrValue>>,
 
+    /// Formats the values of the axis ticks, data labels and legend,
+    /// such as currencies or percentages
+    #[prop_or_default]
+    pub format_value: Option<Callback<f64, AttrValue>>,
+
+    /// Called with the data point of a clicked bar, point or slice
+    #[prop_or_default]
+    pub on_point_click: Option<Callback<ChartPoint>>,
+
     /// Additional CSS classes
     #[prop_or_default]
     pub class: Classes,
@@ -94,27 +218,85 @@ pub struct ChartProps {
 
 /// Chart component
 ///
-/// Renders various chart types with SVG.
+/// Renders various chart types with SVG. Bar, line and area charts plot
+/// the data along the horizontal axis, against a value axis with round
+/// ticks that always includes zero. Pie and donut charts draw one slice
+/// per data point, sized by its share of the total; negative values get
+/// no slice.
+///
+/// With `series`, bar, line and area charts plot several named series on
+/// the categories of their points, in order of first appearance. Bars are
+/// grouped side by side, or stacked with `stacked`; lines are drawn one per
+/// series, broken where a series has no point, and `stacked` areas pile up
+/// on each other. The legend then lists the series. Pie and donut charts
+/// use the first series. `data` is a shorthand for a single unnamed series.
+///
+/// Each series takes its own color or the colors in turn. With `data`, bars
+/// and slices take the colors in turn, while the line and area take the
+/// first one. Without `colors`, the chart palette of the theme is used.
+///
+/// Values are labelled with up to two decimals, or by `format_value`.
+///
+/// A `responsive` chart fills the width of its container and is redrawn at
+/// the new size whenever the container resizes, keeping `height` or, with
+/// `aspect_ratio`, the ratio of its sides. An `aspect_ratio` also sets the
+/// height of a chart of fixed `width`.
+///
+/// Hovering a category shows a tooltip with the value of every series
+/// there, over a highlighted band for bars or a crosshair for lines and
+/// areas; hovering a slice highlights it. Clicking a legend entry hides or
+/// shows its series (or point), and clicking a mark emits it to
+/// `on_point_click`.
 ///
 /// # Accessibility
 /// - ARIA role="img"
 /// - Descriptive aria-label
+/// - Legend entries are toggle buttons with aria-pressed
 /// - Accessible data table alternative recommended
 #[function_component(Chart)]
 pub fn chart(props: &ChartProps) -> Html {
     let ChartProps {
         chart_type,
         data,
+        series,
+        stacked,
         width,
         height,
+        responsive,
+        aspect_ratio,
         show_legend,
-        show_grid: _,
-        show_axis: _,
-        colors: _,
+        show_grid,
+        show_axis,
+        show_values,
+        show_tooltip,
+        colors,
+        format_value: format,
+        on_point_click,
         class,
     } = props.clone();
 
-    let classes: Classes = vec![Classes::from("chart"), class].into_iter().collect();
+    let hovered = use_state_eq(|| None::<usize>);
+    // Legend entries are hidden by series name or point label, which
+    // outlive changes of the data and of the chart type
+    let hidden = use_state(HashSet::<AttrValue>::new);
+    let plot_ref = use_node_ref();
+    let measured = use_element_size(plot_ref.clone(), responsive);
+
+    // A responsive chart is drawn at the measured width of its container,
+    // so that text and strokes keep their size as it reflows
+    let available_width = match measured {
+        Some((measured_width, _)) if responsive && measured_width > 0.0 => measured_width,
+        _ => f64::from(width),
+    };
+    let (width, height) = chart_size(available_width, aspect_ratio, height);
+
+    let classes: Classes = vec![
+        Classes::from("chart"),
+        Classes::from(responsive.then_some("chart-responsive")),
+        class,
+    ]
+    .into_iter()
+    .collect();
 
     let chart_type_str = match chart_type {
         ChartType::Bar => "Bar Chart",
@@ -124,57 +306,523 @@ pub fn chart(props: &ChartProps) -> Html {
         ChartType::Donut => "Donut Chart",
     };
 
-    let aria_label = format!("{} with {} data points", chart_type_str, data.len());
+    let colors = colors.as_deref();
+    let format = format.as_ref();
+    // Plain data points make a single series, colored point by point
+    let per_point = series.is_empty();
+    let series = if per_point {
+        vec![ChartSeries::new("", data)]
+    } else {
+        series
+    };
+    let series_colors: Vec<AttrValue> = series
+        .iter()
+        .enumerate()
+        .map(|(index, series)| {
+            series
+                .color
+                .clone()
+                .unwrap_or_else(|| chart_color(colors, index))
+        })
+        .collect();
 
-    html! {
-        <div class={classes}>
-            <svg
-                class="chart-svg"
-                width={width.to_string()}
-                height={height.to_string()}
-                role="img"
-                aria-label={aria_label}
+    // The legend lists the points of a single series or of a pie, and the
+    // series otherwise
+    let is_pie = matches!(chart_type, ChartType::Pie | ChartType::Donut);
+    let legend_points = per_point || is_pie;
+    let legend = legend_entries(
+        &chart_type,
+        &series,
+        &series_colors,
+        colors,
+        legend_points,
+        format,
+    );
+
+    // Hidden legend entries leave the chart, the others keep their colors
+    let VisibleSeries {
+        ids: series_ids,
+        series,
+        colors: series_colors,
+        point_colors,
+    } = visible_series(series, series_colors, colors, legend_points, &hidden);
+    let categories = categories(&series);
+
+    let aria_label = if per_point {
+        format!("{} with {} data points", chart_type_str, categories.len())
+    } else {
+        format!(
+            "{} with {} series over {} categories",
+            chart_type_str,
+            series.len(),
+            categories.len()
+        )
+    };
+
+    let (width_f, height_f) = (f64::from(width), f64::from(height));
+    // Tooltips are placed in percentages of the chart, which hold when the
+    // SVG is scaled down to fit
+    let tooltip_at = |(x, y): (f64, f64), label: &str, rows: Vec<TooltipRow>| {
+        chart_tooltip(x / width_f * 100.0, y / height_f * 100.0, label, rows)
+    };
+    let (plot, tooltip) = if categories.is_empty() {
+        let empty = html! {
+            <text
+                class="chart-empty"
+                x={svg_number(width_f / 2.0)}
+                y={svg_number(height_f / 2.0)}
+                text-anchor="middle"
             >
+                { "No data" }
+            </text>
+        };
+        (empty, None)
+    } else if is_pie {
+        let values: Vec<f64> = series[0].points.iter().map(|item| item.value).collect();
+        let slice_colors = point_colors.unwrap_or_default();
+        let active = (*hovered).filter(|index| *index < values.len());
+        let plot = PiePlot {
+            layout: PieLayout::new(width_f, height_f, chart_type == ChartType::Donut),
+            values: &values,
+            colors: &slice_colors,
+            show_values,
+            format,
+        };
+        let on_hover = {
+            let hovered = hovered.clone();
+            Callback::from(move |index: Option<usize>| hovered.set(index))
+        };
+        let on_click = on_point_click.as_ref().map(|on_point_click| {
+            let (id, name) = (series_ids[0], series[0].name.clone());
+            let points = series[0].points.clone();
+            on_point_click.reform(move |index: usize| ChartPoint {
+                series: id,
+                name: name.clone(),
+                label: points[index].label.clone(),
+                value: points[index].value,
+            })
+        });
+        let tooltip = active.filter(|_| show_tooltip).map(|index| {
+            let (start, end) = pie_angles(&values)[index];
+            let point = &series[0].points[index];
+            let rows = vec![TooltipRow {
+                color: slice_colors[index].clone(),
+                name: None,
+                value: format_value(format, point.value),
+            }];
+            tooltip_at(plot.layout.label_point(start, end), &point.label, rows)
+        });
+        (
+            render_pie(&plot, active, &on_hover, on_click.as_ref()),
+            tooltip,
+        )
+    } else {
+        let values = align_series(&series, &categories);
+        let stacks = stacked.then(|| stack(&values));
+        let bounds: Vec<f64> = match &stacks {
+            Some(stacks) => stacks
+                .iter()
+                .flatten()
+                .flat_map(|(bottom, top)| [*bottom, *top])
+                .collect(),
+            None => values.iter().flatten().flatten().copied().collect(),
+        };
+        let ticks = value_ticks(&bounds);
+        let bars = chart_type == ChartType::Bar;
+        let plot = CartesianPlot {
+            area: PlotArea::new(width_f, height_f, show_axis),
+            ticks: &ticks,
+            categories: &categories,
+            values: &values,
+            stacks: stacks.as_deref(),
+            colors: &series_colors,
+            show_values,
+            format,
+        };
+        let active = (*hovered).filter(|index| *index < categories.len());
+        let marks = if bars {
+            render_bars(&plot, point_colors.as_deref())
+        } else {
+            render_lines(&plot, chart_type == ChartType::Area)
+        };
+
+        let area = plot.area;
+        let band_scale = plot.band_scale();
+        let onmousemove = {
+            let hovered = hovered.clone();
+            Callback::from(move |e: MouseEvent| {
+                hovered.set(plot_position(&e, &area).and_then(|(x, _)| band_scale.index_at(x)));
+            })
+        };
+        let onmouseleave = {
+            let hovered = hovered.clone();
+            Callback::from(move |_: MouseEvent| hovered.set(None))
+        };
+        let onclick = on_point_click.as_ref().map(|on_point_click| {
+            // Marks of every category, with the points they stand for
+            let targets: Vec<Vec<(MarkBox, ChartPoint)>> = (0..categories.len())
+                .map(|index| {
+                    category_points(
+                        plot.mark_boxes(index, bars),
+                        index,
+                        &series_ids,
+                        &series,
+                        &categories,
+                        &values,
+                    )
+                })
+                .collect();
+            let on_point_click = on_point_click.clone();
+            Callback::from(move |e: MouseEvent| {
+                let Some((x, y)) = plot_position(&e, &area) else {
+                    return;
+                };
+                let Some(targets) = band_scale.index_at(x).map(|index| &targets[index]) else {
+                    return;
+                };
+                if let Some(point) = point_at(targets, x, y) {
+                    on_point_click.emit(point);
+                }
+            })
+        });
+        let overlay_classes = classes!(
+            "chart-overlay",
+            on_point_click.is_some().then_some("clickable")
+        );
+
+        let tooltip = active.filter(|_| show_tooltip).map(|index| {
+            let marks = plot.mark_boxes(index, bars);
+            let top = marks
+                .iter()
+                .map(|mark| mark.top)
+                .fold(area.bottom(), f64::min);
+            // Only bars take the colors point by point, like the legend
+            let rows = category_rows(
+                &marks,
+                index,
+                &values,
+                &series,
+                &series_colors,
+                point_colors.as_deref().filter(|_| bars),
+                format,
+            );
+            tooltip_at((plot.center(index), top), &categories[index], rows)
+        });
+
+        let plot = html! {
+            <>
+                { render_axes(&plot, show_grid, show_axis) }
+                if let Some(index) = active {
+                    { render_hover(&plot, index, bars) }
+                }
+                { marks }
                 <rect
-                    x="0"
-                    y="0"
-                    width={width.to_string()}
-                    height={height.to_string()}
+                    class={overlay_classes}
+                    x={svg_number(area.left)}
+                    y={svg_number(area.top)}
+                    width={svg_number(area.width)}
+                    height={svg_number(area.height)}
                     fill="transparent"
-                    stroke="#e0e0e0"
-                    stroke-width="1"
+                    {onmousemove}
+                    {onmouseleave}
+                    {onclick}
                 />
-                <text
-                    x={(width / 2).to_string()}
-                    y={(height / 2).to_string()}
-                    text-anchor="middle"
-                    fill="#666"
-                >
-                    { format!("{} - {} data points", chart_type_str, data.len()) }
-                </text>
-            </svg>
-            if show_legend {
-                <div class="chart-legend">
-                    {
-                        data.iter().enumerate().map(|(idx, item)| {
-                            html! {
-                                <div key={idx} class="chart-legend-item">
-                                    <span class="chart-legend-marker" />
-                                    <span class="chart-legend-label">
-                                        { &item.label }
-                                        { ": " }
-                                        { item.value }
-                                    </span>
-                                </div>
-                            }
-                        }).collect::<Html>()
+            </>
+        };
+        (plot, tooltip)
+    };
+
+    let legend = legend
+        .into_iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let is_hidden = hidden.contains(&entry.key);
+            let onclick = {
+                let hidden = hidden.clone();
+                let key = entry.key;
+                Callback::from(move |_: MouseEvent| {
+                    let mut next = (*hidden).clone();
+                    if !next.remove(&key) {
+                        next.insert(key.clone());
                     }
-                </div>
+                    hidden.set(next);
+                })
+            };
+            legend_item(idx, entry.color, entry.label, is_hidden, onclick)
+        })
+        .collect::<Html>();
+
+    let svg = html! {
+        <svg
+            class="chart-svg"
+            width={width.to_string()}
+            height={height.to_string()}
+            viewBox={format!("0 0 {} {}", width, height)}
+            role="img"
+            aria-label={aria_label}
+        >
+            { plot }
+        </svg>
+    };
+
+    html! {
+        <div class={classes}>
+            <div class="chart-plot" ref={plot_ref}>
+                // The aspect ratio box holds the height of a responsive chart
+                // while it is measured
+                if let Some(ratio) = aspect_ratio.filter(|_| responsive) {
+                    <AspectRatio {ratio} class="chart-aspect-ratio">{ svg }</AspectRatio>
+                } else {
+                    { svg }
+                }
+                { tooltip }
+            </div>
+            if show_legend {
+                <div class="chart-legend">{ legend }</div>
             }
         </div>
     }
 }
 
+/// Position of the pointer over the plot area, in SVG user units
+fn plot_position(event: &MouseEvent, area: &PlotArea) -> Option<(f64, f64)> {
+    let target: Element = event.current_target()?.dyn_into().ok()?;
+    let rect = target.get_bounding_client_rect();
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return None;
+    }
+    let x = area.left + (f64::from(event.client_x()) - rect.left()) / rect.width() * area.width;
+    let y = area.top + (f64::from(event.client_y()) - rect.top()) / rect.height() * area.height;
+    Some((x, y))
+}
+
+/// Entry of the legend, hidden and shown again by its key
+#[derive(Debug, Clone, PartialEq)]
+struct LegendEntry {
+    /// Name of the series or label of the point
+    key: AttrValue,
+    color: AttrValue,
+    label: String,
+}
+
+/// Legend of a chart: the points of its first series with `points`, its
+/// series otherwise
+fn legend_entries(
+    chart_type: &ChartType,
+    series: &[ChartSeries],
+    series_colors: &[AttrValue],
+    colors: Option<&[AttrValue]>,
+    points: bool,
+    format: Option<&Callback<f64, AttrValue>>,
+) -> Vec<LegendEntry> {
+    if !points {
+        return series
+            .iter()
+            .zip(series_colors)
+            .map(|(series, color)| LegendEntry {
+                key: series.name.clone(),
+                color: color.clone(),
+                label: series.name.to_string(),
+            })
+            .collect();
+    }
+    series[0]
+        .points
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let color = match chart_type {
+                ChartType::Line | ChartType::Area => series_colors[0].clone(),
+                _ => chart_color(colors, idx),
+            };
+            LegendEntry {
+                key: AttrValue::from(item.label.clone()),
+                color,
+                label: format!("{}: {}", item.label, format_value(format, item.value)),
+            }
+        })
+        .collect()
+}
+
+/// Series of a chart left once the hidden legend entries are taken out
+#[derive(Debug, PartialEq)]
+struct VisibleSeries {
+    /// Index of each series among all of them
+    ids: Vec<usize>,
+    series: Vec<ChartSeries>,
+    colors: Vec<AttrValue>,
+    /// Colors of the points of a legend listing points
+    point_colors: Option<Vec<AttrValue>>,
+}
+
+/// Take the series, or with `points` the points of the first series, whose
+/// legend entry is hidden out of the chart
+fn visible_series(
+    series: Vec<ChartSeries>,
+    series_colors: Vec<AttrValue>,
+    colors: Option<&[AttrValue]>,
+    points: bool,
+    hidden: &HashSet<AttrValue>,
+) -> VisibleSeries {
+    if !points {
+        let (ids, series): (Vec<usize>, Vec<ChartSeries>) = series
+            .into_iter()
+            .enumerate()
+            .filter(|(_, series)| !hidden.contains(&series.name))
+            .unzip();
+        let colors = ids.iter().map(|idx| series_colors[*idx].clone()).collect();
+        return VisibleSeries {
+            ids,
+            series,
+            colors,
+            point_colors: None,
+        };
+    }
+    let (indices, points): (Vec<usize>, Vec<ChartData>) = series[0]
+        .points
+        .iter()
+        .cloned()
+        .enumerate()
+        .filter(|(_, item)| !hidden.contains(item.label.as_str()))
+        .unzip();
+    let point_colors = indices
+        .iter()
+        .map(|idx| chart_color(colors, *idx))
+        .collect();
+    VisibleSeries {
+        ids: vec![0],
+        series: vec![ChartSeries {
+            points,
+            ..series[0].clone()
+        }],
+        colors: vec![series_colors[0].clone()],
+        point_colors: Some(point_colors),
+    }
+}
+
+/// Points of the marks of a category, with the series they belong to
+/// numbered by `ids`
+fn category_points(
+    marks: Vec<MarkBox>,
+    index: usize,
+    ids: &[usize],
+    series: &[ChartSeries],
+    categories: &[String],
+    values: &[Vec<Option<f64>>],
+) -> Vec<(MarkBox, ChartPoint)> {
+    marks
+        .into_iter()
+        .filter_map(|mark| {
+            let point = ChartPoint {
+                series: ids[mark.series],
+                name: series[mark.series].name.clone(),
+                label: categories[index].clone(),
+                value: values[mark.series][index]?,
+            };
+            Some((mark, point))
+        })
+        .collect()
+}
+
+/// Point of the mark under a position, or nearest to it, among the marks
+/// of a category
+fn point_at(targets: &[(MarkBox, ChartPoint)], x: f64, y: f64) -> Option<ChartPoint> {
+    let marks: Vec<MarkBox> = targets.iter().map(|(mark, _)| *mark).collect();
+    let mark = hit_mark(&marks, x, y)?;
+    targets
+        .iter()
+        .find(|(target, _)| target.series == mark.series)
+        .map(|(_, point)| point.clone())
+}
+
+/// Tooltip rows of a category, one per series with a value there, colored
+/// by the series or, given `point_colors`, by the point
+fn category_rows(
+    marks: &[MarkBox],
+    index: usize,
+    values: &[Vec<Option<f64>>],
+    series: &[ChartSeries],
+    series_colors: &[AttrValue],
+    point_colors: Option<&[AttrValue]>,
+    format: Option<&Callback<f64, AttrValue>>,
+) -> Vec<TooltipRow> {
+    marks
+        .iter()
+        .filter_map(|mark| {
+            let value = values[mark.series][index]?;
+            let color = match point_colors {
+                Some(point_colors) => point_colors[index].clone(),
+                None => series_colors[mark.series].clone(),
+            };
+            let name = &series[mark.series].name;
+            Some(TooltipRow {
+                color,
+                name: (!name.is_empty()).then(|| name.clone()),
+                value: format_value(format, value),
+            })
+        })
+        .collect()
+}
+
+/// Line of a chart tooltip: a series (or point) and its value
+#[derive(Debug, PartialEq)]
+struct TooltipRow {
+    color: AttrValue,
+    name: Option<AttrValue>,
+    value: AttrValue,
+}
+
+/// Tooltip above a point of the chart, given in percentages of its size
+fn chart_tooltip(x: f64, y: f64, label: &str, rows: Vec<TooltipRow>) -> Html {
+    let style = format!("left: {}%; top: {}%;", svg_number(x), svg_number(y));
+    html! {
+        <div class="chart-tooltip" {style}>
+            <TooltipContent class="chart-tooltip-content">
+                <div class="chart-tooltip-label">{ label.to_string() }</div>
+                {
+                    rows.into_iter().map(|row| html! {
+                        <div class="chart-tooltip-row">
+                            <span
+                                class="chart-legend-marker"
+                                style={format!("background-color: {}", row.color)}
+                            />
+                            if let Some(name) = row.name {
+                                <span class="chart-tooltip-name">{ name }</span>
+                            }
+                            <span class="chart-tooltip-value">{ row.value }</span>
+                        </div>
+                    }).collect::<Html>()
+                }
+            </TooltipContent>
+        </div>
+    }
+}
+
+/// Entry of the legend, a color marker and its label, toggling its series
+fn legend_item(
+    key: usize,
+    color: AttrValue,
+    label: String,
+    hidden: bool,
+    onclick: Callback<MouseEvent>,
+) -> Html {
+    html! {
+        <button
+            {key}
+            type="button"
+            class={classes!("chart-legend-item", hidden.then_some("hidden"))}
+            aria-pressed={(!hidden).to_string()}
+            {onclick}
+        >
+            <span
+                class="chart-legend-marker"
+                style={format!("background-color: {}", color)}
+            />
+            <span class="chart-legend-label">{ label }</span>
+        </button>
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,12 +837,20 @@ mod tests {
         let props = ChartProps {
             chart_type: ChartType::Bar,
             data,
+            series: vec![],
+            stacked: false,
             width: 500,
             height: 300,
+            responsive: false,
+            aspect_ratio: None,
             show_legend: true,
             show_grid: true,
             show_axis: true,
+            show_values: true,
+            show_tooltip: true,
             colors: None,
+            format_value: None,
+            on_point_click: None,
             class: Classes::new(),
         };
 
@@ -213,12 +869,20 @@ mod tests {
         let props = ChartProps {
             chart_type: ChartType::Line,
             data,
+            series: vec![],
+            stacked: false,
             width: 500,
             height: 300,
+            responsive: false,
+            aspect_ratio: None,
             show_legend: true,
             show_grid: true,
             show_axis: true,
+            show_values: true,
+            show_tooltip: true,
             colors: None,
+            format_value: None,
+            on_point_click: None,
             class: Classes::new(),
         };
 
@@ -241,12 +905,20 @@ mod tests {
         let props = ChartProps {
             chart_type: ChartType::Pie,
             data: data.clone(),
+            series: vec![],
+            stacked: false,
             width: 500,
             height: 300,
+            responsive: false,
+            aspect_ratio: None,
             show_legend: true,
             show_grid: true,
             show_axis: true,
+            show_values: false,
+            show_tooltip: true,
             colors: None,
+            format_value: None,
+            on_point_click: None,
             class: Classes::new(),
         };
 
@@ -254,6 +926,239 @@ mod tests {
         assert_eq!(props.data.len(), 2);
     }
 
+    fn colors(names: &[&'static str]) -> Vec<AttrValue> {
+        names.iter().copied().map(AttrValue::from).collect()
+    }
+
+    fn mark(series: usize, top: f64) -> MarkBox {
+        MarkBox {
+            series,
+            left: 10.0,
+            right: 10.0,
+            top,
+            bottom: top,
+        }
+    }
+
+    #[test]
+    fn test_legend_lists_series() {
+        let series = vec![
+            ChartSeries::from_pairs("Desktop", [("Jan", 186.0), ("Feb", 305.0)]),
+            ChartSeries::from_pairs("Mobile", [("Jan", 80.0)]).color("#2563eb"),
+        ];
+        let series_colors = colors(&["red", "#2563eb"]);
+        let legend = legend_entries(&ChartType::Area, &series, &series_colors, None, false, None);
+        assert_eq!(
+            legend,
+            vec![
+                LegendEntry {
+                    key: AttrValue::from("Desktop"),
+                    color: AttrValue::from("red"),
+                    label: String::from("Desktop"),
+                },
+                LegendEntry {
+                    key: AttrValue::from("Mobile"),
+                    color: AttrValue::from("#2563eb"),
+                    label: String::from("Mobile"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_legend_lists_points() {
+        let series = vec![ChartSeries::from_pairs("", [("Jan", 1.5), ("Feb", 2.0)])];
+        let palette = colors(&["red", "green"]);
+        let series_colors = colors(&["red"]);
+        let bars = legend_entries(
+            &ChartType::Bar,
+            &series,
+            &series_colors,
+            Some(&palette),
+            true,
+            None,
+        );
+        assert_eq!(bars[1].key, "Feb");
+        assert_eq!(bars[1].color, "green");
+        assert_eq!(bars[0].label, "Jan: 1.5");
+
+        // The line of a single series takes one color, and so does its legend
+        let line = legend_entries(
+            &ChartType::Line,
+            &series,
+            &series_colors,
+            Some(&palette),
+            true,
+            None,
+        );
+        assert_eq!(line[1].color, "red");
+    }
+
+    #[test]
+    fn test_visible_series_hidden_by_key() {
+        let series = vec![
+            ChartSeries::from_pairs("Desktop", [("Jan", 186.0)]),
+            ChartSeries::from_pairs("Mobile", [("Jan", 80.0)]),
+            ChartSeries::from_pairs("Tablet", [("Jan", 40.0)]),
+        ];
+        let hidden = HashSet::from([AttrValue::from("Mobile")]);
+        let visible = visible_series(
+            series,
+            colors(&["red", "green", "blue"]),
+            None,
+            false,
+            &hidden,
+        );
+        assert_eq!(visible.ids, vec![0, 2]);
+        assert_eq!(visible.colors, colors(&["red", "blue"]));
+        assert_eq!(visible.series[1].name, "Tablet");
+        assert_eq!(visible.point_colors, None);
+    }
+
+    #[test]
+    fn test_visible_points_keep_their_colors() {
+        let series = vec![ChartSeries::from_pairs(
+            "",
+            [("Jan", 1.0), ("Feb", 2.0), ("Mar", 3.0)],
+        )];
+        let palette = colors(&["red", "green", "blue"]);
+        let hidden = HashSet::from([AttrValue::from("Feb")]);
+        let visible = visible_series(series, colors(&["red"]), Some(&palette), true, &hidden);
+        let labels: Vec<&str> = visible.series[0]
+            .points
+            .iter()
+            .map(|point| point.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["Jan", "Mar"]);
+        assert_eq!(visible.point_colors, Some(colors(&["red", "blue"])));
+    }
+
+    #[test]
+    fn test_category_rows() {
+        let series = vec![
+            ChartSeries::from_pairs("Desktop", [("Jan", 186.0), ("Feb", 305.0)]),
+            ChartSeries::from_pairs("Mobile", [("Feb", 200.0)]),
+        ];
+        let values = vec![vec![Some(186.0), Some(305.0)], vec![None, Some(200.0)]];
+        let series_colors = colors(&["red", "blue"]);
+        let rows = category_rows(
+            &[mark(0, 50.0), mark(1, 80.0)],
+            1,
+            &values,
+            &series,
+            &series_colors,
+            None,
+            None,
+        );
+        assert_eq!(
+            rows,
+            vec![
+                TooltipRow {
+                    color: AttrValue::from("red"),
+                    name: Some(AttrValue::from("Desktop")),
+                    value: AttrValue::from("305"),
+                },
+                TooltipRow {
+                    color: AttrValue::from("blue"),
+                    name: Some(AttrValue::from("Mobile")),
+                    value: AttrValue::from("200"),
+                },
+            ]
+        );
+
+        // A category without a value has no row
+        let rows = category_rows(
+            &[mark(0, 50.0), mark(1, 80.0)],
+            0,
+            &values,
+            &series,
+            &series_colors,
+            None,
+            None,
+        );
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[test]
+    fn test_category_rows_of_data() {
+        // Unnamed data is colored point by point only when given the colors
+        // of the points, as for bars
+        let series = vec![ChartSeries::from_pairs("", [("Jan", 1.0), ("Feb", 2.0)])];
+        let values = vec![vec![Some(1.0), Some(2.0)]];
+        let line = category_rows(
+            &[mark(0, 50.0)],
+            1,
+            &values,
+            &series,
+            &colors(&["red"]),
+            None,
+            None,
+        );
+        assert_eq!(line[0].color, "red");
+        assert_eq!(line[0].name, None);
+
+        let point_colors = colors(&["red", "green"]);
+        let bars = category_rows(
+            &[mark(0, 50.0)],
+            1,
+            &values,
+            &series,
+            &colors(&["red"]),
+            Some(&point_colors),
+            None,
+        );
+        assert_eq!(bars[0].color, "green");
+    }
+
+    #[test]
+    fn test_clicked_point() {
+        // The second series is shown alone, the first one being hidden
+        let series = vec![ChartSeries::from_pairs(
+            "Mobile",
+            [("Jan", 80.0), ("Feb", 200.0)],
+        )];
+        let categories = vec![String::from("Jan"), String::from("Feb")];
+        let values = vec![vec![Some(80.0), Some(200.0)]];
+        let targets = category_points(vec![mark(0, 40.0)], 1, &[1], &series, &categories, &values);
+        let point = ChartPoint {
+            series: 1,
+            name: AttrValue::from("Mobile"),
+            label: String::from("Feb"),
+            value: 200.0,
+        };
+        assert_eq!(point_at(&targets, 10.0, 40.0), Some(point.clone()));
+        assert_eq!(point_at(&targets, 10.0, 90.0), Some(point));
+        assert_eq!(point_at(&[], 10.0, 40.0), None);
+    }
+
+    #[test]
+    fn test_clicked_bar() {
+        let series = vec![
+            ChartSeries::from_pairs("Desktop", [("Jan", 186.0)]),
+            ChartSeries::from_pairs("Mobile", [("Jan", 80.0)]),
+        ];
+        let categories = vec![String::from("Jan")];
+        let values = vec![vec![Some(186.0)], vec![Some(80.0)]];
+        let bar = |series, left| MarkBox {
+            series,
+            left,
+            right: left + 10.0,
+            top: 20.0,
+            bottom: 100.0,
+        };
+        let targets = category_points(
+            vec![bar(0, 0.0), bar(1, 10.0)],
+            0,
+            &[0, 1],
+            &series,
+            &categories,
+            &values,
+        );
+        let clicked = point_at(&targets, 15.0, 60.0).unwrap();
+        assert_eq!(clicked.name, "Mobile");
+        assert_eq!(clicked.value, 80.0);
+    }
+
     #[test]
     fn test_chart_data() {
         let data = ChartData {
@@ -270,4 +1175,33 @@ mod tests {
         assert_eq!(ChartType::Bar, ChartType::Bar);
         assert_ne!(ChartType::Bar, ChartType::Line);
     }
+
+    #[test]
+    fn test_chart_color() {
+        let colors = vec![AttrValue::from("red"), AttrValue::from("blue")];
+        assert_eq!(chart_color(Some(&colors), 3), AttrValue::from("blue"));
+        assert_eq!(
+            chart_color(None, 1),
+            AttrValue::from("hsl(var(--color-chart-2))")
+        );
+        assert_eq!(
+            chart_color(Some(&[]), 5),
+            AttrValue::from("hsl(var(--color-chart-1))")
+        );
+    }
+
+    #[test]
+    fn test_chart_size() {
+        assert_eq!(chart_size(640.0, None, 300), (640, 300));
+        assert_eq!(chart_size(640.4, Some(16.0 / 9.0), 300), (640, 360));
+        assert_eq!(chart_size(500.0, Some(0.0), 300), (500, 300));
+        assert_eq!(chart_size(0.0, Some(2.0), 300), (1, 1));
+    }
+
+    #[test]
+    fn test_value_ticks_include_zero() {
+        assert_eq!(value_ticks(&[186.0, 305.0, 73.0]).first(), Some(&0.0));
+        assert_eq!(value_ticks(&[-5.0, -20.0]).last(), Some(&0.0));
+        assert!(value_ticks(&[]).contains(&0.0));
+    }
 }
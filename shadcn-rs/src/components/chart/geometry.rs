@@ -0,0 +1,283 @@
+//! SVG geometry of the chart marks
+
+use std::f64::consts::TAU;
+
+use super::scales::{BandScale, LinearScale};
+
+/// Rectangle of the plot, inside the axes, in SVG user units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlotArea {
+    /// Left edge
+    pub left: f64,
+    /// Top edge
+    pub top: f64,
+    /// Width
+    pub width: f64,
+    /// Height
+    pub height: f64,
+}
+
+impl PlotArea {
+    /// Plot area of a chart, leaving room for the axis labels when `with_axis`
+    pub fn new(width: f64, height: f64, with_axis: bool) -> Self {
+        let (left, bottom) = if with_axis {
+            (48.0, 28.0)
+        } else {
+            (12.0, 12.0)
+        };
+        let (top, right) = (16.0, 16.0);
+        Self {
+            left,
+            top,
+            width: (width - left - right).max(0.0),
+            height: (height - top - bottom).max(0.0),
+        }
+    }
+
+    /// Right edge
+    pub fn right(&self) -> f64 {
+        self.left + self.width
+    }
+
+    /// Bottom edge
+    pub fn bottom(&self) -> f64 {
+        self.top + self.height
+    }
+
+    /// Vertical position of a value on an axis from `min` (bottom) to `max` (top)
+    pub fn y(&self, value: f64, min: f64, max: f64) -> f64 {
+        LinearScale::new((min, max), (self.bottom(), self.top)).scale(value)
+    }
+
+    /// Horizontal span `(start, width)` of band `index` of `count` equal bands
+    pub fn band(&self, index: usize, count: usize) -> (f64, f64) {
+        BandScale::new(count, (self.left, self.right())).band(index)
+    }
+}
+
+/// A number for SVG attributes and paths, with at most two decimals
+pub fn svg_number(value: f64) -> String {
+    super::ticks::format_tick(value)
+}
+
+/// Path through points with straight segments
+pub fn line_path(points: &[(f64, f64)]) -> String {
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, (x, y))| {
+            let command = if index == 0 { "M" } else { "L" };
+            format!("{}{},{}", command, svg_number(*x), svg_number(*y))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Closed path between the line through points and a horizontal baseline
+pub fn area_path(points: &[(f64, f64)], baseline: f64) -> String {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return String::new();
+    };
+    format!(
+        "{} L{},{} L{},{} Z",
+        line_path(points),
+        svg_number(last.0),
+        svg_number(baseline),
+        svg_number(first.0),
+        svg_number(baseline)
+    )
+}
+
+/// Closed path of the band between an upper and a lower line, such as a
+/// stacked area, both given left to right
+pub fn band_path(upper: &[(f64, f64)], lower: &[(f64, f64)]) -> String {
+    if upper.is_empty() {
+        return String::new();
+    }
+    let lower: Vec<String> = lower
+        .iter()
+        .rev()
+        .map(|(x, y)| format!(" L{},{}", svg_number(*x), svg_number(*y)))
+        .collect();
+    format!("{}{} Z", line_path(upper), lower.concat())
+}
+
+/// Runs of consecutive present points, split at the missing ones
+pub fn segments(points: &[Option<(f64, f64)>]) -> Vec<Vec<(f64, f64)>> {
+    points
+        .split(Option::is_none)
+        .filter(|run| !run.is_empty())
+        .map(|run| run.iter().flatten().copied().collect())
+        .collect()
+}
+
+/// Start and end angles (radians, clockwise from the top) of the slices
+/// of a pie, proportional to the values
+///
+/// Negative values count as zero; without a positive value every slice is
+/// empty.
+pub fn pie_angles(values: &[f64]) -> Vec<(f64, f64)> {
+    let total: f64 = values.iter().map(|value| value.max(0.0)).sum();
+    let mut angle = 0.0;
+    values
+        .iter()
+        .map(|value| {
+            let sweep = if total > 0.0 {
+                value.max(0.0) / total * TAU
+            } else {
+                0.0
+            };
+            let slice = (angle, angle + sweep);
+            angle += sweep;
+            slice
+        })
+        .collect()
+}
+
+/// Point at an angle (radians, clockwise from the top) on a circle
+pub fn polar(cx: f64, cy: f64, radius: f64, angle: f64) -> (f64, f64) {
+    (cx + radius * angle.sin(), cy - radius * angle.cos())
+}
+
+/// Path of a pie slice, or a donut segment when `inner_radius` is positive
+pub fn arc_path(cx: f64, cy: f64, radius: f64, inner_radius: f64, start: f64, end: f64) -> String {
+    // An arc cannot start and end at the same point, so a full circle is
+    // drawn as two halves
+    if end - start >= TAU - 1e-9 {
+        let middle = start + TAU / 2.0;
+        let outer = arc_segment(cx, cy, radius, start, middle, true);
+        let outer_rest = arc_segment(cx, cy, radius, middle, end, true);
+        let (sx, sy) = polar(cx, cy, radius, start);
+        if inner_radius <= 0.0 {
+            return format!(
+                "M{},{} {} {} Z",
+                svg_number(sx),
+                svg_number(sy),
+                outer,
+                outer_rest
+            );
+        }
+        let (ix, iy) = polar(cx, cy, inner_radius, start);
+        return format!(
+            "M{},{} {} {} Z M{},{} {} {} Z",
+            svg_number(sx),
+            svg_number(sy),
+            outer,
+            outer_rest,
+            svg_number(ix),
+            svg_number(iy),
+            arc_segment(cx, cy, inner_radius, end, middle, false),
+            arc_segment(cx, cy, inner_radius, middle, start, false)
+        );
+    }
+
+    let (sx, sy) = polar(cx, cy, radius, start);
+    let outer = arc_segment(cx, cy, radius, start, end, true);
+    if inner_radius <= 0.0 {
+        return format!(
+            "M{},{} {} L{},{} Z",
+            svg_number(sx),
+            svg_number(sy),
+            outer,
+            svg_number(cx),
+            svg_number(cy)
+        );
+    }
+    let (ex, ey) = polar(cx, cy, inner_radius, end);
+    format!(
+        "M{},{} {} L{},{} {} Z",
+        svg_number(sx),
+        svg_number(sy),
+        outer,
+        svg_number(ex),
+        svg_number(ey),
+        arc_segment(cx, cy, inner_radius, end, start, false)
+    )
+}
+
+/// Arc command from the point at `from` to the point at `to`
+fn arc_segment(cx: f64, cy: f64, radius: f64, from: f64, to: f64, clockwise: bool) -> String {
+    let (x, y) = polar(cx, cy, radius, to);
+    let large_arc = u8::from((to - from).abs() > TAU / 2.0);
+    format!(
+        "A{},{} 0 {} {} {},{}",
+        svg_number(radius),
+        svg_number(radius),
+        large_arc,
+        u8::from(clockwise),
+        svg_number(x),
+        svg_number(y)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plot_area() {
+        let area = PlotArea::new(500.0, 300.0, true);
+        assert_eq!(area.left, 48.0);
+        assert_eq!(area.width, 436.0);
+        assert_eq!(area.bottom(), 272.0);
+        assert_eq!(area.y(0.0, 0.0, 100.0), 272.0);
+        assert_eq!(area.y(100.0, 0.0, 100.0), 16.0);
+        assert_eq!(area.band(1, 4), (157.0, 109.0));
+    }
+
+    #[test]
+    fn test_line_and_area_paths() {
+        let points = [(0.0, 10.0), (5.5, 2.25), (10.0, 4.0)];
+        assert_eq!(line_path(&points), "M0,10 L5.5,2.25 L10,4");
+        assert_eq!(
+            area_path(&points, 20.0),
+            "M0,10 L5.5,2.25 L10,4 L10,20 L0,20 Z"
+        );
+        assert_eq!(area_path(&[], 20.0), "");
+    }
+
+    #[test]
+    fn test_band_path_and_segments() {
+        let upper = [(0.0, 2.0), (10.0, 4.0)];
+        let lower = [(0.0, 8.0), (10.0, 9.0)];
+        assert_eq!(band_path(&upper, &lower), "M0,2 L10,4 L10,9 L0,8 Z");
+        assert_eq!(
+            segments(&[
+                Some((0.0, 1.0)),
+                None,
+                Some((2.0, 3.0)),
+                Some((3.0, 4.0)),
+                None
+            ]),
+            vec![vec![(0.0, 1.0)], vec![(2.0, 3.0), (3.0, 4.0)]]
+        );
+    }
+
+    #[test]
+    fn test_pie_angles() {
+        let angles = pie_angles(&[1.0, 3.0, -2.0]);
+        assert_eq!(angles[0], (0.0, TAU / 4.0));
+        assert_eq!(angles[1], (TAU / 4.0, TAU));
+        assert_eq!(angles[2], (TAU, TAU));
+        assert_eq!(pie_angles(&[0.0]), vec![(0.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_arc_path() {
+        // Quarter slice of a pie centred at (50, 50)
+        assert_eq!(
+            arc_path(50.0, 50.0, 40.0, 0.0, 0.0, TAU / 4.0),
+            "M50,10 A40,40 0 0 1 90,50 L50,50 Z"
+        );
+        // Same quarter of a donut
+        assert_eq!(
+            arc_path(50.0, 50.0, 40.0, 20.0, 0.0, TAU / 4.0),
+            "M50,10 A40,40 0 0 1 90,50 L70,50 A20,20 0 0 0 50,30 Z"
+        );
+        // Full circle in two halves
+        assert_eq!(
+            arc_path(50.0, 50.0, 40.0, 0.0, 0.0, TAU),
+            "M50,10 A40,40 0 0 1 50,90 A40,40 0 0 1 50,10 Z"
+        );
+    }
+}
@@ -0,0 +1,571 @@
+//! SVG marks of each chart type
+
+use yew::prelude::*;
+
+use super::geometry::{
+    PlotArea, arc_path, area_path, band_path, line_path, pie_angles, polar, segments, svg_number,
+};
+use super::scales::{BandScale, LinearScale, format_value};
+
+/// Series values aligned on the categories of a bar, line or area chart
+pub(crate) struct CartesianPlot<'a> {
+    /// Plot rectangle
+    pub area: PlotArea,
+    /// Value axis ticks, from the bottom to the top of the axis
+    pub ticks: &'a [f64],
+    /// Category labels of the horizontal axis
+    pub categories: &'a [String],
+    /// Values of each series for each category
+    pub values: &'a [Vec<Option<f64>>],
+    /// Bottom and top of each segment when the series are stacked
+    pub stacks: Option<&'a [Vec<(f64, f64)>]>,
+    /// Color of each series
+    pub colors: &'a [AttrValue],
+    /// Show the values next to the marks
+    pub show_values: bool,
+    /// Formats the tick and data values
+    pub format: Option<&'a Callback<f64, AttrValue>>,
+}
+
+impl CartesianPlot<'_> {
+    /// Scale of the value axis, from the first tick at the bottom of the
+    /// plot to the last one at its top
+    pub fn value_scale(&self) -> LinearScale {
+        let domain = (self.ticks[0], self.ticks[self.ticks.len() - 1]);
+        LinearScale::new(domain, (self.area.bottom(), self.area.top))
+    }
+
+    /// Scale of the category axis, one band per category
+    pub fn band_scale(&self) -> BandScale {
+        BandScale::new(self.categories.len(), (self.area.left, self.area.right()))
+    }
+
+    fn y(&self, value: f64) -> f64 {
+        self.value_scale().scale(value)
+    }
+
+    /// Horizontal span of the band of a category
+    fn band(&self, index: usize) -> (f64, f64) {
+        self.band_scale().band(index)
+    }
+
+    fn label(&self, value: f64) -> AttrValue {
+        format_value(self.format, value)
+    }
+
+    /// Middle of the band of a category
+    pub fn center(&self, index: usize) -> f64 {
+        self.band_scale().center(index)
+    }
+
+    /// Rectangle of the bar of a series at a category
+    ///
+    /// Series share 80% of the band side by side, each bar leaving a small
+    /// gap, or stack on each other in 70% of it.
+    fn bar_box(&self, series: usize, index: usize, value: f64) -> MarkBox {
+        let (x, band) = self.band(index);
+        let ((left, width), (bottom, top)) = match self.stacks {
+            Some(stacks) => ((x + band * 0.15, band * 0.7), stacks[series][index]),
+            None => {
+                let slot = band * 0.8 / self.values.len().max(1) as f64;
+                let left = x + band * 0.1 + slot * series as f64 + slot * 0.05;
+                ((left, slot * 0.9), (value.min(0.0), value.max(0.0)))
+            }
+        };
+        MarkBox {
+            series,
+            left,
+            right: left + width,
+            top: self.y(top),
+            bottom: self.y(bottom),
+        }
+    }
+
+    /// Point of a line at a category: its value, or the top of its segment
+    /// when stacked
+    fn point(&self, series: usize, index: usize) -> Option<(f64, f64)> {
+        let value = match self.stacks {
+            Some(stacks) => stacks[series][index].1,
+            None => self.values[series][index]?,
+        };
+        Some((self.center(index), self.y(value)))
+    }
+
+    /// Marks of the series that have a value at a category
+    pub fn mark_boxes(&self, index: usize, bars: bool) -> Vec<MarkBox> {
+        self.values
+            .iter()
+            .enumerate()
+            .filter_map(|(series, values)| {
+                let value = values.get(index).copied().flatten()?;
+                if bars {
+                    return Some(self.bar_box(series, index, value));
+                }
+                let (x, y) = self.point(series, index)?;
+                Some(MarkBox {
+                    series,
+                    left: x,
+                    right: x,
+                    top: y,
+                    bottom: y,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Rectangle covered by the mark of a series at a category, a bar or a
+/// single point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct MarkBox {
+    /// Index of the series
+    pub series: usize,
+    /// Left edge
+    pub left: f64,
+    /// Right edge
+    pub right: f64,
+    /// Top edge
+    pub top: f64,
+    /// Bottom edge
+    pub bottom: f64,
+}
+
+/// The mark under a position, or the one nearest to it vertically
+///
+/// Bars side by side are told apart by the horizontal position first.
+pub(crate) fn hit_mark(marks: &[MarkBox], x: f64, y: f64) -> Option<MarkBox> {
+    let under_x: Vec<&MarkBox> = marks
+        .iter()
+        .filter(|mark| mark.left < mark.right && mark.left <= x && x <= mark.right)
+        .collect();
+    let candidates = if under_x.is_empty() {
+        marks.iter().collect()
+    } else {
+        under_x
+    };
+    let distance = |mark: &MarkBox| (mark.top - y).max(y - mark.bottom).max(0.0);
+    candidates
+        .into_iter()
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .copied()
+}
+
+/// Highlight of the hovered category: its band behind bars, or a vertical
+/// crosshair through the points of lines and areas
+pub(crate) fn render_hover(plot: &CartesianPlot, index: usize, bars: bool) -> Html {
+    let area = &plot.area;
+    if bars {
+        let (x, band) = plot.band(index);
+        return html! {
+            <rect
+                class="chart-hover-band"
+                x={svg_number(x)}
+                y={svg_number(area.top)}
+                width={svg_number(band)}
+                height={svg_number(area.height)}
+            />
+        };
+    }
+    let x = svg_number(plot.center(index));
+    html! {
+        <line
+            class="chart-crosshair"
+            x1={x.clone()}
+            x2={x}
+            y1={svg_number(area.top)}
+            y2={svg_number(area.bottom())}
+        />
+    }
+}
+
+/// Gridlines at the value ticks, and the labels of both axes
+pub(crate) fn render_axes(plot: &CartesianPlot, show_grid: bool, show_axis: bool) -> Html {
+    let area = &plot.area;
+    let grid = show_grid.then(|| {
+        plot.ticks
+            .iter()
+            .map(|tick| {
+                let y = svg_number(plot.y(*tick));
+                html! {
+                    <line
+                        class="chart-grid-line"
+                        x1={svg_number(area.left)}
+                        x2={svg_number(area.right())}
+                        y1={y.clone()}
+                        y2={y}
+                    />
+                }
+            })
+            .collect::<Html>()
+    });
+    let axis = show_axis.then(|| {
+        let value_labels = plot.ticks.iter().map(|tick| {
+            html! {
+                <text
+                    class="chart-axis-label"
+                    x={svg_number(area.left - 8.0)}
+                    y={svg_number(plot.y(*tick))}
+                    dy="0.32em"
+                    text-anchor="end"
+                >
+                    { plot.label(*tick) }
+                </text>
+            }
+        });
+        let category_labels = plot.categories.iter().enumerate().map(|(index, label)| {
+            let (x, band) = plot.band(index);
+            html! {
+                <text
+                    class="chart-axis-label"
+                    x={svg_number(x + band / 2.0)}
+                    y={svg_number(area.bottom() + 18.0)}
+                    text-anchor="middle"
+                >
+                    { label.clone() }
+                </text>
+            }
+        });
+        html! {
+            <g class="chart-axis">
+                <line
+                    class="chart-axis-line"
+                    x1={svg_number(area.left)}
+                    x2={svg_number(area.right())}
+                    y1={svg_number(area.bottom())}
+                    y2={svg_number(area.bottom())}
+                />
+                { for value_labels }
+                { for category_labels }
+            </g>
+        }
+    });
+    html! {
+        <>
+            <g class="chart-grid">{ grid }</g>
+            { axis }
+        </>
+    }
+}
+
+/// Bars side by side in each category, or stacked when the plot has stacks
+///
+/// With `point_colors`, each category takes its own color instead of the
+/// color of its series.
+pub(crate) fn render_bars(plot: &CartesianPlot, point_colors: Option<&[AttrValue]>) -> Html {
+    let bars = plot.values.iter().enumerate().flat_map(|(series, values)| {
+        values
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, value)| value.map(|value| (series, index, value)))
+    });
+    bars.map(|(series, index, value)| {
+        let MarkBox {
+            left,
+            right,
+            top: y_top,
+            bottom: y_bottom,
+            ..
+        } = plot.bar_box(series, index, value);
+        let width = right - left;
+        let color = point_colors
+            .map(|colors| colors[index % colors.len()].clone())
+            .unwrap_or_else(|| plot.colors[series].clone());
+        let label = plot.show_values.then(|| {
+            let (label_y, class) = match plot.stacks {
+                // Segments thinner than the label go without
+                Some(_) if y_bottom - y_top < 14.0 => return html! {},
+                Some(_) => (
+                    (y_top + y_bottom) / 2.0,
+                    "chart-value-label chart-value-label-inside",
+                ),
+                None if value < 0.0 => (y_bottom + 14.0, "chart-value-label"),
+                None => (y_top - 6.0, "chart-value-label"),
+            };
+            html! {
+                <text
+                    {class}
+                    x={svg_number(left + width / 2.0)}
+                    y={svg_number(label_y)}
+                    dy={plot.stacks.is_some().then_some("0.32em")}
+                    text-anchor="middle"
+                >
+                    { plot.label(value) }
+                </text>
+            }
+        });
+        html! {
+            <g class="chart-bar" key={format!("{}-{}", series, index)}>
+                <rect
+                    x={svg_number(left)}
+                    y={svg_number(y_top)}
+                    width={svg_number(width)}
+                    height={svg_number(y_bottom - y_top)}
+                    rx="4"
+                    fill={color}
+                />
+                { label }
+            </g>
+        }
+    })
+    .collect()
+}
+
+/// A line per series through its values, broken where values are missing
+///
+/// With `filled`, the area under each line is filled down to zero, or
+/// between the series and the one below it when the plot has stacks.
+pub(crate) fn render_lines(plot: &CartesianPlot, filled: bool) -> Html {
+    plot.values
+        .iter()
+        .enumerate()
+        .map(|(series, values)| {
+            let color = plot.colors[series].clone();
+            let points: Vec<Option<(f64, f64)>> = (0..values.len())
+                .map(|index| plot.point(series, index))
+                .collect();
+            let fill = filled.then(|| match plot.stacks {
+                Some(stacks) => {
+                    let upper: Vec<(f64, f64)> = points.iter().flatten().copied().collect();
+                    let lower: Vec<(f64, f64)> = stacks[series]
+                        .iter()
+                        .enumerate()
+                        .map(|(index, (bottom, _))| (plot.center(index), plot.y(*bottom)))
+                        .collect();
+                    html! {
+                        <path
+                            class="chart-area-fill"
+                            d={band_path(&upper, &lower)}
+                            fill={color.clone()}
+                            fill-opacity="0.4"
+                        />
+                    }
+                }
+                None => segments(&points)
+                    .iter()
+                    .map(|run| {
+                        html! {
+                            <path
+                                class="chart-area-fill"
+                                d={area_path(run, plot.y(0.0))}
+                                fill={color.clone()}
+                                fill-opacity="0.25"
+                            />
+                        }
+                    })
+                    .collect::<Html>(),
+            });
+            let lines = segments(&points)
+                .iter()
+                .map(|run| {
+                    html! {
+                        <path d={line_path(run)} fill="none" stroke={color.clone()} stroke-width="2" />
+                    }
+                })
+                .collect::<Html>();
+            let markers = points
+                .iter()
+                .zip(values)
+                .enumerate()
+                .filter_map(|(index, (point, value))| Some((index, (*point)?, (*value)?)))
+                .map(|(index, (x, y), value)| {
+                    html! {
+                        <g key={index}>
+                            <circle
+                                class="chart-point"
+                                cx={svg_number(x)}
+                                cy={svg_number(y)}
+                                r="3"
+                                fill={color.clone()}
+                            />
+                            if plot.show_values {
+                                <text
+                                    class="chart-value-label"
+                                    x={svg_number(x)}
+                                    y={svg_number(y - 8.0)}
+                                    text-anchor="middle"
+                                >
+                                    { plot.label(value) }
+                                </text>
+                            }
+                        </g>
+                    }
+                })
+                .collect::<Html>();
+            html! {
+                <g class="chart-line" key={series}>
+                    { fill }
+                    { lines }
+                    { markers }
+                </g>
+            }
+        })
+        .collect()
+}
+
+/// Center and radii of a pie or donut chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PieLayout {
+    /// Horizontal center
+    pub cx: f64,
+    /// Vertical center
+    pub cy: f64,
+    /// Outer radius
+    pub radius: f64,
+    /// Radius of the hole of a donut, zero for a pie
+    pub inner_radius: f64,
+}
+
+impl PieLayout {
+    /// Largest pie or donut fitting the chart
+    pub fn new(width: f64, height: f64, donut: bool) -> Self {
+        let radius = (width.min(height) / 2.0 - 8.0).max(0.0);
+        Self {
+            cx: width / 2.0,
+            cy: height / 2.0,
+            radius,
+            inner_radius: if donut { radius * 0.6 } else { 0.0 },
+        }
+    }
+
+    /// Point in the middle of a slice, where its label goes
+    pub fn label_point(&self, start: f64, end: f64) -> (f64, f64) {
+        let radius = if self.inner_radius > 0.0 {
+            (self.radius + self.inner_radius) / 2.0
+        } else {
+            self.radius * 0.65
+        };
+        polar(self.cx, self.cy, radius, (start + end) / 2.0)
+    }
+}
+
+/// Slices of a pie or donut chart
+pub(crate) struct PiePlot<'a> {
+    /// Center and radii
+    pub layout: PieLayout,
+    /// Value of each slice
+    pub values: &'a [f64],
+    /// Color of each slice
+    pub colors: &'a [AttrValue],
+    /// Show the values on the slices
+    pub show_values: bool,
+    /// Formats the values
+    pub format: Option<&'a Callback<f64, AttrValue>>,
+}
+
+/// Slices of a pie, or segments of a donut with the total in its hole
+///
+/// Hovering a slice emits its index to `on_hover`, and `None` on leaving
+/// it; the `active` slice stands out from the others.
+pub(crate) fn render_pie(
+    plot: &PiePlot,
+    active: Option<usize>,
+    on_hover: &Callback<Option<usize>>,
+    on_click: Option<&Callback<usize>>,
+) -> Html {
+    let PieLayout {
+        cx,
+        cy,
+        radius,
+        inner_radius,
+    } = plot.layout;
+    let total: f64 = plot.values.iter().map(|value| value.max(0.0)).sum();
+    let classes = classes!(
+        "chart-pie",
+        active.is_some().then_some("has-active"),
+        on_click.is_some().then_some("clickable")
+    );
+    html! {
+        <g class={classes}>
+            {
+                pie_angles(plot.values).into_iter().zip(plot.values).enumerate()
+                    .filter(|(_, ((start, end), _))| end > start)
+                    .map(|(index, ((start, end), value))| {
+                        let (label_x, label_y) = plot.layout.label_point(start, end);
+                        let onmouseenter = on_hover.reform(move |_: MouseEvent| Some(index));
+                        let onmouseleave = on_hover.reform(|_: MouseEvent| None);
+                        let onclick = on_click.map(|on_click| on_click.reform(move |_: MouseEvent| index));
+                        let classes = classes!("chart-slice", (active == Some(index)).then_some("active"));
+                        html! {
+                            <g class={classes} key={index} {onmouseenter} {onmouseleave} {onclick}>
+                                <path
+                                    d={arc_path(cx, cy, radius, inner_radius, start, end)}
+                                    fill={plot.colors[index % plot.colors.len()].clone()}
+                                />
+                                // Slices too thin for their label go without
+                                if plot.show_values && end - start > 0.25 {
+                                    <text
+                                        class="chart-slice-label"
+                                        x={svg_number(label_x)}
+                                        y={svg_number(label_y)}
+                                        dy="0.32em"
+                                        text-anchor="middle"
+                                    >
+                                        { format_value(plot.format, *value) }
+                                    </text>
+                                }
+                            </g>
+                        }
+                    })
+                    .collect::<Html>()
+            }
+            if inner_radius > 0.0 {
+                <text
+                    class="chart-donut-total"
+                    x={svg_number(cx)}
+                    y={svg_number(cy)}
+                    dy="0.32em"
+                    text-anchor="middle"
+                >
+                    { format_value(plot.format, total) }
+                </text>
+            }
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mark(series: usize, left: f64, right: f64, top: f64, bottom: f64) -> MarkBox {
+        MarkBox {
+            series,
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    #[test]
+    fn test_hit_mark() {
+        // Grouped bars are picked by the horizontal position
+        let bars = [
+            mark(0, 10.0, 20.0, 50.0, 100.0),
+            mark(1, 20.0, 30.0, 80.0, 100.0),
+        ];
+        assert_eq!(hit_mark(&bars, 25.0, 10.0).map(|m| m.series), Some(1));
+        // Points of lines by the nearest vertically
+        let points = [
+            mark(0, 15.0, 15.0, 40.0, 40.0),
+            mark(1, 15.0, 15.0, 70.0, 70.0),
+        ];
+        assert_eq!(hit_mark(&points, 14.0, 60.0).map(|m| m.series), Some(1));
+        // Stacked segments by the one containing the position
+        let stacked = [
+            mark(0, 10.0, 30.0, 60.0, 100.0),
+            mark(1, 10.0, 30.0, 20.0, 60.0),
+        ];
+        assert_eq!(hit_mark(&stacked, 20.0, 30.0).map(|m| m.series), Some(1));
+        assert_eq!(hit_mark(&[], 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_pie_layout() {
+        let layout = PieLayout::new(400.0, 300.0, true);
+        assert_eq!((layout.cx, layout.cy), (200.0, 150.0));
+        assert_eq!(layout.radius, 142.0);
+        assert!((layout.inner_radius - 85.2).abs() < 1e-9);
+        assert_eq!(PieLayout::new(400.0, 300.0, false).inner_radius, 0.0);
+    }
+}
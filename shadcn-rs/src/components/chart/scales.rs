@@ -0,0 +1,692 @@
+//! Scales mapping data values to positions along an axis
+//!
+//! A scale maps a domain of data values onto a range of positions, in SVG
+//! user units, and picks the ticks of its axis. Ranges may run backwards,
+//! such as a value axis from the bottom of the plot up to its top.
+
+use chrono::{Datelike, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+use yew::prelude::*;
+
+use super::ticks::{format_tick, nice_number, nice_ticks};
+
+/// Widen a domain by a fraction of its span on each side
+///
+/// An empty domain is widened by the fraction of its value instead, or by
+/// the fraction itself around zero.
+pub fn pad_domain(min: f64, max: f64, fraction: f64) -> (f64, f64) {
+    let span = (max - min).abs();
+    let pad = if span > 0.0 {
+        span * fraction
+    } else if min != 0.0 {
+        min.abs() * fraction
+    } else {
+        fraction
+    };
+    (min.min(max) - pad, min.max(max) + pad)
+}
+
+/// Labels of tick values, by `format` or else [`format_tick`]
+pub fn tick_labels(ticks: &[f64], format: Option<&Callback<f64, AttrValue>>) -> Vec<AttrValue> {
+    ticks
+        .iter()
+        .map(|tick| format_value(format, *tick))
+        .collect()
+}
+
+/// Label of a value, by `format` or else [`format_tick`]
+pub(crate) fn format_value(format: Option<&Callback<f64, AttrValue>>, value: f64) -> AttrValue {
+    match format {
+        Some(format) => format.emit(value),
+        None => AttrValue::from(format_tick(value)),
+    }
+}
+
+/// Maps the values of a continuous domain proportionally onto the range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearScale {
+    /// Values at the start and end of the axis
+    pub domain: (f64, f64),
+    /// Positions of the start and end of the axis
+    pub range: (f64, f64),
+}
+
+impl LinearScale {
+    /// Create a scale from a domain and a range
+    pub fn new(domain: (f64, f64), range: (f64, f64)) -> Self {
+        Self { domain, range }
+    }
+
+    /// Extend the domain to round values, with about `count` ticks
+    pub fn nice(self, count: usize) -> Self {
+        let ticks = nice_ticks(self.domain.0, self.domain.1, count);
+        let domain = (ticks[0], ticks[ticks.len() - 1]);
+        Self { domain, ..self }
+    }
+
+    /// Widen the domain by a fraction of its span on each side
+    pub fn padded(self, fraction: f64) -> Self {
+        let domain = pad_domain(self.domain.0, self.domain.1, fraction);
+        Self { domain, ..self }
+    }
+
+    /// Position of a value; an empty domain maps everything to the start
+    pub fn scale(&self, value: f64) -> f64 {
+        let ((d0, d1), (r0, r1)) = (self.domain, self.range);
+        if d0 == d1 {
+            return r0;
+        }
+        r0 + (value - d0) / (d1 - d0) * (r1 - r0)
+    }
+
+    /// Value at a position
+    pub fn invert(&self, position: f64) -> f64 {
+        let ((d0, d1), (r0, r1)) = (self.domain, self.range);
+        if r0 == r1 {
+            return d0;
+        }
+        d0 + (position - r0) / (r1 - r0) * (d1 - d0)
+    }
+
+    /// Round tick values inside the domain, about `count` of them
+    pub fn ticks(&self, count: usize) -> Vec<f64> {
+        let (min, max) = (
+            self.domain.0.min(self.domain.1),
+            self.domain.0.max(self.domain.1),
+        );
+        let epsilon = (max - min) * 1e-9;
+        nice_ticks(min, max, count)
+            .into_iter()
+            .filter(|tick| *tick >= min - epsilon && *tick <= max + epsilon)
+            .collect()
+    }
+}
+
+/// Maps positive values onto the range by their logarithm, so that each
+/// power of the base takes the same length
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogScale {
+    /// Values at the start and end of the axis, both positive
+    pub domain: (f64, f64),
+    /// Positions of the start and end of the axis
+    pub range: (f64, f64),
+    /// Base of the logarithm
+    pub base: f64,
+}
+
+impl LogScale {
+    /// Create a base 10 scale from a domain and a range
+    pub fn new(domain: (f64, f64), range: (f64, f64)) -> Self {
+        Self {
+            domain,
+            range,
+            base: 10.0,
+        }
+    }
+
+    /// Set the base of the logarithm
+    ///
+    /// Bases that are not finite and greater than 1 are ignored.
+    pub fn base(mut self, base: f64) -> Self {
+        if valid_base(base) {
+            self.base = base;
+        }
+        self
+    }
+
+    /// Extend the domain to powers of the base
+    pub fn nice(self) -> Self {
+        let (d0, d1) = self.log_domain();
+        let base = self.log_base();
+        let domain = (base.powf(d0.floor()), base.powf(d1.ceil()));
+        Self { domain, ..self }
+    }
+
+    /// Position of a value, `None` for values that have no logarithm
+    pub fn scale(&self, value: f64) -> Option<f64> {
+        if value <= 0.0 || !value.is_finite() {
+            return None;
+        }
+        let ((d0, d1), (r0, r1)) = (self.log_domain(), self.range);
+        if d0 == d1 {
+            return Some(r0);
+        }
+        Some(r0 + (self.log(value) - d0) / (d1 - d0) * (r1 - r0))
+    }
+
+    /// Value at a position
+    pub fn invert(&self, position: f64) -> f64 {
+        let ((d0, d1), (r0, r1)) = (self.log_domain(), self.range);
+        if r0 == r1 {
+            return self.domain.0;
+        }
+        self.log_base()
+            .powf(d0 + (position - r0) / (r1 - r0) * (d1 - d0))
+    }
+
+    /// Powers of the base inside the domain
+    ///
+    /// A base 10 domain of fewer than three powers also gets ticks at two
+    /// and five times each power.
+    pub fn ticks(&self) -> Vec<f64> {
+        let (d0, d1) = self.log_domain();
+        let (min, max) = (d0.min(d1), d0.max(d1));
+        if !min.is_finite() || !max.is_finite() {
+            return Vec::new();
+        }
+        let base = self.log_base();
+        let multiples: &[f64] = if base == 10.0 && max - min < 3.0 {
+            &[1.0, 2.0, 5.0]
+        } else {
+            &[1.0]
+        };
+        let (low, high) = (
+            self.domain.0.min(self.domain.1),
+            self.domain.0.max(self.domain.1),
+        );
+        let epsilon = 1e-9;
+        (min.floor() as i32..=max.ceil() as i32)
+            .flat_map(|exponent| {
+                let power = base.powi(exponent);
+                multiples.iter().map(move |multiple| multiple * power)
+            })
+            .filter(|tick| *tick >= low * (1.0 - epsilon) && *tick <= high * (1.0 + epsilon))
+            .collect()
+    }
+
+    fn log_domain(&self) -> (f64, f64) {
+        (
+            self.log(self.domain.0.max(f64::MIN_POSITIVE)),
+            self.log(self.domain.1.max(f64::MIN_POSITIVE)),
+        )
+    }
+
+    /// The base, or 10 when the `base` field was set to an invalid value
+    fn log_base(&self) -> f64 {
+        if valid_base(self.base) {
+            self.base
+        } else {
+            10.0
+        }
+    }
+
+    /// Logarithm in the base, exact at powers of 10 and 2
+    fn log(&self, value: f64) -> f64 {
+        match self.log_base() {
+            10.0 => value.log10(),
+            2.0 => value.log2(),
+            base => value.log(base),
+        }
+    }
+}
+
+/// A logarithm base must be finite and greater than 1
+fn valid_base(base: f64) -> bool {
+    base.is_finite() && base > 1.0
+}
+
+/// Splits the range into equal bands, one per category, with optional
+/// padding between the bands and at both ends
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandScale {
+    /// Number of categories
+    pub count: usize,
+    /// Positions of the start and end of the axis
+    pub range: (f64, f64),
+    /// Padding between bands, as a fraction of a step (band and padding)
+    pub padding_inner: f64,
+    /// Padding at both ends, as a fraction of a step
+    pub padding_outer: f64,
+}
+
+impl BandScale {
+    /// Create a scale of `count` bands without padding
+    pub fn new(count: usize, range: (f64, f64)) -> Self {
+        Self {
+            count,
+            range,
+            padding_inner: 0.0,
+            padding_outer: 0.0,
+        }
+    }
+
+    /// Set the padding between bands and at both ends
+    pub fn padding(mut self, inner: f64, outer: f64) -> Self {
+        self.padding_inner = inner.clamp(0.0, 1.0);
+        self.padding_outer = outer.max(0.0);
+        self
+    }
+
+    /// Distance from the start of a band to the start of the next one
+    pub fn step(&self) -> f64 {
+        let steps = self.count.max(1) as f64 - self.padding_inner + 2.0 * self.padding_outer;
+        (self.range.1 - self.range.0) / steps
+    }
+
+    /// Width of each band
+    pub fn bandwidth(&self) -> f64 {
+        self.step() * (1.0 - self.padding_inner)
+    }
+
+    /// Start and width of band `index`
+    pub fn band(&self, index: usize) -> (f64, f64) {
+        let step = self.step();
+        let start = self.range.0 + step * (self.padding_outer + index as f64);
+        (start, self.bandwidth())
+    }
+
+    /// Middle of band `index`
+    pub fn center(&self, index: usize) -> f64 {
+        let (start, width) = self.band(index);
+        start + width / 2.0
+    }
+
+    /// Band at a position, the nearest one within the range
+    pub fn index_at(&self, position: f64) -> Option<usize> {
+        let (r0, r1) = (
+            self.range.0.min(self.range.1),
+            self.range.0.max(self.range.1),
+        );
+        if self.count == 0 || position < r0 || position > r1 {
+            return None;
+        }
+        let step = self.step();
+        let offset = (position - self.range.0) / step - self.padding_outer;
+        Some((offset.floor().max(0.0) as usize).min(self.count - 1))
+    }
+}
+
+/// Spacing of the ticks of a time axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum TimeInterval {
+    /// Every given number of minutes
+    Minutes(u32),
+    /// Every given number of hours
+    Hours(u32),
+    /// Every given number of days
+    Days(u32),
+    /// Every given number of weeks, starting on Monday
+    Weeks(u32),
+    /// Every given number of months
+    Months(u32),
+    /// Every given number of years
+    Years(u32),
+}
+
+/// Intervals picked from for time axes, from the shortest
+const TIME_INTERVALS: [TimeInterval; 17] = [
+    TimeInterval::Minutes(1),
+    TimeInterval::Minutes(5),
+    TimeInterval::Minutes(15),
+    TimeInterval::Minutes(30),
+    TimeInterval::Hours(1),
+    TimeInterval::Hours(3),
+    TimeInterval::Hours(6),
+    TimeInterval::Hours(12),
+    TimeInterval::Days(1),
+    TimeInterval::Days(2),
+    TimeInterval::Weeks(1),
+    TimeInterval::Weeks(2),
+    TimeInterval::Months(1),
+    TimeInterval::Months(3),
+    TimeInterval::Months(6),
+    TimeInterval::Years(1),
+    TimeInterval::Years(2),
+];
+
+impl TimeInterval {
+    /// The shortest interval giving at most about `count` ticks over `span`
+    pub fn for_span(span: TimeDelta, count: usize) -> Self {
+        let target = span.num_seconds().abs() as f64 / count.max(1) as f64;
+        TIME_INTERVALS
+            .into_iter()
+            .find(|interval| interval.approx_seconds() >= target)
+            .unwrap_or_else(|| {
+                let years = target / TimeInterval::Years(1).approx_seconds();
+                TimeInterval::Years(nice_number(years, false) as u32)
+            })
+    }
+
+    /// Average length in seconds
+    fn approx_seconds(&self) -> f64 {
+        const DAY: f64 = 86_400.0;
+        match *self {
+            TimeInterval::Minutes(n) => f64::from(n) * 60.0,
+            TimeInterval::Hours(n) => f64::from(n) * 3_600.0,
+            TimeInterval::Days(n) => f64::from(n) * DAY,
+            TimeInterval::Weeks(n) => f64::from(n) * 7.0 * DAY,
+            TimeInterval::Months(n) => f64::from(n) * 30.44 * DAY,
+            TimeInterval::Years(n) => f64::from(n) * 365.25 * DAY,
+        }
+    }
+
+    /// Start of the interval containing `time`
+    ///
+    /// Minutes and hours are counted from midnight, days from the first of
+    /// the month, months from January and years from year zero; weeks start
+    /// on Monday.
+    pub fn floor(&self, time: NaiveDateTime) -> NaiveDateTime {
+        let date = time.date();
+        let midnight = |date: NaiveDate| date.and_time(NaiveTime::MIN);
+        let align = |value: u32, step: u32| value - value % step.max(1);
+        match *self {
+            TimeInterval::Minutes(n) => {
+                let minutes = align(time.hour() * 60 + time.minute(), n);
+                midnight(date) + TimeDelta::minutes(i64::from(minutes))
+            }
+            TimeInterval::Hours(n) => {
+                midnight(date) + TimeDelta::hours(i64::from(align(time.hour(), n)))
+            }
+            TimeInterval::Days(n) => {
+                let day = align(date.day0(), n) + 1;
+                midnight(date.with_day(day).unwrap_or(date))
+            }
+            TimeInterval::Weeks(_) => {
+                let monday =
+                    date - TimeDelta::days(i64::from(date.weekday().num_days_from_monday()));
+                midnight(monday)
+            }
+            TimeInterval::Months(n) => {
+                let month = align(date.month0(), n) + 1;
+                midnight(NaiveDate::from_ymd_opt(date.year(), month, 1).unwrap_or(date))
+            }
+            TimeInterval::Years(n) => {
+                let year = date.year() - date.year().rem_euclid(n.max(1) as i32);
+                midnight(NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(date))
+            }
+        }
+    }
+
+    /// The time one interval after `time`
+    pub fn offset(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        match *self {
+            TimeInterval::Minutes(n) => time.checked_add_signed(TimeDelta::minutes(i64::from(n))),
+            TimeInterval::Hours(n) => time.checked_add_signed(TimeDelta::hours(i64::from(n))),
+            TimeInterval::Days(n) => time.checked_add_signed(TimeDelta::days(i64::from(n))),
+            TimeInterval::Weeks(n) => time.checked_add_signed(TimeDelta::weeks(i64::from(n))),
+            TimeInterval::Months(n) => time.checked_add_months(Months::new(n)),
+            TimeInterval::Years(n) => time.checked_add_months(Months::new(n.saturating_mul(12))),
+        }
+    }
+
+    /// `chrono` format of tick labels at this interval, such as
+    /// `"%b %-d"` ("Mar 4") for days
+    pub fn label_format(&self) -> &'static str {
+        match self {
+            TimeInterval::Minutes(_) | TimeInterval::Hours(_) => "%H:%M",
+            TimeInterval::Days(_) | TimeInterval::Weeks(_) => "%b %-d",
+            TimeInterval::Months(_) => "%b %Y",
+            TimeInterval::Years(_) => "%Y",
+        }
+    }
+}
+
+/// Maps dates and times proportionally onto the range, with ticks at
+/// calendar intervals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeScale {
+    /// Times at the start and end of the axis
+    pub domain: (NaiveDateTime, NaiveDateTime),
+    /// Positions of the start and end of the axis
+    pub range: (f64, f64),
+}
+
+impl TimeScale {
+    /// Create a scale from a domain and a range
+    pub fn new(domain: (NaiveDateTime, NaiveDateTime), range: (f64, f64)) -> Self {
+        Self { domain, range }
+    }
+
+    /// Create a scale over whole days, from the start of the first day to
+    /// the end of the last one
+    pub fn from_dates(first: NaiveDate, last: NaiveDate, range: (f64, f64)) -> Self {
+        let end = last.succ_opt().unwrap_or(last);
+        Self::new(
+            (first.and_time(NaiveTime::MIN), end.and_time(NaiveTime::MIN)),
+            range,
+        )
+    }
+
+    /// Widen the domain by a fraction of its span on each side
+    ///
+    /// A side that would pass the limits of `NaiveDateTime` keeps its bound.
+    pub fn padded(self, fraction: f64) -> Self {
+        let span = (self.domain.1 - self.domain.0).num_milliseconds() as f64;
+        let pad = TimeDelta::try_milliseconds((span * fraction) as i64).unwrap_or_default();
+        let domain = (
+            self.domain
+                .0
+                .checked_sub_signed(pad)
+                .unwrap_or(self.domain.0),
+            self.domain
+                .1
+                .checked_add_signed(pad)
+                .unwrap_or(self.domain.1),
+        );
+        Self { domain, ..self }
+    }
+
+    /// Position of a time
+    pub fn scale(&self, time: NaiveDateTime) -> f64 {
+        self.linear().scale(self.millis(time))
+    }
+
+    /// Time at a position
+    pub fn invert(&self, position: f64) -> NaiveDateTime {
+        let millis = self.linear().invert(position);
+        self.domain.0 + TimeDelta::milliseconds(millis.round() as i64)
+    }
+
+    /// Interval of about `count` ticks over the domain
+    pub fn interval(&self, count: usize) -> TimeInterval {
+        TimeInterval::for_span(self.domain.1 - self.domain.0, count)
+    }
+
+    /// Ticks at the starts of intervals inside the domain, about `count`
+    /// of them
+    pub fn ticks(&self, count: usize) -> Vec<NaiveDateTime> {
+        let interval = self.interval(count);
+        let (start, end) = (
+            self.domain.0.min(self.domain.1),
+            self.domain.0.max(self.domain.1),
+        );
+        let mut ticks = Vec::new();
+        let mut tick = Some(interval.floor(start));
+        while let Some(time) = tick
+            && time <= end
+        {
+            if time >= start {
+                ticks.push(time);
+            }
+            tick = interval.offset(time);
+        }
+        ticks
+    }
+
+    /// Labels of the ticks for about `count` ticks, by `format` or else in
+    /// the label format of their interval
+    pub fn tick_labels(
+        &self,
+        count: usize,
+        format: Option<&Callback<NaiveDateTime, AttrValue>>,
+    ) -> Vec<AttrValue> {
+        let pattern = self.interval(count).label_format();
+        self.ticks(count)
+            .into_iter()
+            .map(|tick| match format {
+                Some(format) => format.emit(tick),
+                None => AttrValue::from(tick.format(pattern).to_string()),
+            })
+            .collect()
+    }
+
+    fn linear(&self) -> LinearScale {
+        let span = self.millis(self.domain.1);
+        LinearScale::new((0.0, span), self.range)
+    }
+
+    /// Milliseconds from the start of the domain
+    fn millis(&self, time: NaiveDateTime) -> f64 {
+        (time - self.domain.0).num_milliseconds() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_pad_domain() {
+        assert_eq!(pad_domain(0.0, 100.0, 0.1), (-10.0, 110.0));
+        assert_eq!(pad_domain(50.0, 50.0, 0.1), (45.0, 55.0));
+        assert_eq!(pad_domain(0.0, 0.0, 0.5), (-0.5, 0.5));
+    }
+
+    #[test]
+    fn test_linear_scale() {
+        let scale = LinearScale::new((0.0, 305.0), (272.0, 16.0)).nice(5);
+        assert_eq!(scale.domain, (0.0, 400.0));
+        assert_eq!(scale.scale(0.0), 272.0);
+        assert_eq!(scale.scale(400.0), 16.0);
+        assert_eq!(scale.invert(144.0), 200.0);
+        assert_eq!(scale.ticks(5), vec![0.0, 100.0, 200.0, 300.0, 400.0]);
+        assert_eq!(LinearScale::new((5.0, 5.0), (0.0, 10.0)).scale(7.0), 0.0);
+    }
+
+    #[test]
+    fn test_log_scale() {
+        let scale = LogScale::new((1.0, 1000.0), (0.0, 300.0));
+        assert!((scale.scale(100.0).unwrap() - 200.0).abs() < 1e-9);
+        assert_eq!(scale.scale(0.0), None);
+        assert!((scale.invert(100.0) - 10.0).abs() < 1e-9);
+        assert_eq!(scale.ticks(), vec![1.0, 10.0, 100.0, 1000.0]);
+        let scale = LogScale::new((3.0, 70.0), (0.0, 100.0)).nice();
+        assert_eq!(scale.domain, (1.0, 100.0));
+        assert_eq!(scale.ticks(), vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0]);
+    }
+
+    #[test]
+    fn test_log_scale_invalid_base() {
+        for base in [1.0, 0.5, -2.0, f64::NAN, f64::INFINITY] {
+            let scale = LogScale::new((1.0, 100.0), (0.0, 100.0)).base(base);
+            assert_eq!(scale.base, 10.0);
+        }
+
+        let scale = LogScale {
+            base: 1.0,
+            ..LogScale::new((1.0, 100.0), (0.0, 100.0))
+        };
+        assert!((scale.scale(10.0).unwrap() - 50.0).abs() < 1e-9);
+        assert_eq!(scale.ticks(), vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0]);
+    }
+
+    #[test]
+    fn test_band_scale() {
+        let scale = BandScale::new(4, (48.0, 484.0));
+        assert_eq!(scale.band(1), (157.0, 109.0));
+        assert_eq!(scale.center(0), 102.5);
+        let scale = BandScale::new(3, (0.0, 100.0)).padding(0.5, 0.25);
+        assert_eq!(scale.step(), 100.0 / 3.0);
+        assert!((scale.band(0).0 - 100.0 / 12.0).abs() < 1e-9);
+        assert!((scale.bandwidth() - 100.0 / 6.0).abs() < 1e-9);
+        assert_eq!(scale.index_at(0.0), Some(0));
+        assert_eq!(scale.index_at(50.0), Some(1));
+        assert_eq!(scale.index_at(100.0), Some(2));
+        assert_eq!(scale.index_at(101.0), None);
+    }
+
+    #[test]
+    fn test_time_interval() {
+        let day = TimeDelta::days(1);
+        assert_eq!(TimeInterval::for_span(day, 6), TimeInterval::Hours(6));
+        assert_eq!(TimeInterval::for_span(day * 7, 7), TimeInterval::Days(1));
+        assert_eq!(TimeInterval::for_span(day * 60, 6), TimeInterval::Weeks(2));
+        assert_eq!(
+            TimeInterval::for_span(day * 365, 5),
+            TimeInterval::Months(3)
+        );
+        assert_eq!(
+            TimeInterval::for_span(day * 3650, 5),
+            TimeInterval::Years(2)
+        );
+        assert_eq!(
+            TimeInterval::for_span(day * 36500, 5),
+            TimeInterval::Years(20)
+        );
+
+        let time = at(2024, 3, 14, 15, 47);
+        assert_eq!(
+            TimeInterval::Minutes(15).floor(time),
+            at(2024, 3, 14, 15, 45)
+        );
+        assert_eq!(TimeInterval::Hours(6).floor(time), at(2024, 3, 14, 12, 0));
+        // Thursday 14 March 2024, the week starts on Monday the 11th
+        assert_eq!(TimeInterval::Weeks(1).floor(time), at(2024, 3, 11, 0, 0));
+        assert_eq!(TimeInterval::Months(3).floor(time), at(2024, 1, 1, 0, 0));
+        assert_eq!(
+            TimeInterval::Months(1).offset(at(2024, 1, 31, 0, 0)),
+            Some(at(2024, 2, 29, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_time_scale() {
+        let first = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let last = NaiveDate::from_ymd_opt(2024, 3, 7).unwrap();
+        let scale = TimeScale::from_dates(first, last, (0.0, 700.0));
+        assert_eq!(scale.scale(at(2024, 3, 2, 0, 0)), 100.0);
+        assert_eq!(scale.invert(350.0), at(2024, 3, 4, 12, 0));
+        assert_eq!(scale.ticks(7).len(), 8);
+        assert_eq!(scale.tick_labels(7, None)[0], AttrValue::from("Mar 1"));
+
+        let format =
+            Callback::from(|time: NaiveDateTime| AttrValue::from(time.format("%a").to_string()));
+        assert_eq!(
+            scale.tick_labels(7, Some(&format))[0],
+            AttrValue::from("Fri")
+        );
+    }
+
+    #[test]
+    fn test_time_scale_padded() {
+        let scale = TimeScale::new((at(2024, 3, 1, 0, 0), at(2024, 3, 11, 0, 0)), (0.0, 1.0));
+        assert_eq!(
+            scale.padded(0.1).domain,
+            (at(2024, 2, 29, 0, 0), at(2024, 3, 12, 0, 0))
+        );
+
+        // Padding past the limits of `NaiveDateTime` keeps the bound
+        let day = TimeDelta::days(1);
+        let scale = TimeScale::new((NaiveDateTime::MIN, NaiveDateTime::MIN + day), (0.0, 1.0));
+        assert_eq!(
+            scale.padded(0.5).domain,
+            (NaiveDateTime::MIN, NaiveDateTime::MIN + day + day / 2)
+        );
+        let scale = TimeScale::new((NaiveDateTime::MAX - day, NaiveDateTime::MAX), (0.0, 1.0));
+        assert_eq!(scale.padded(0.5).domain.1, NaiveDateTime::MAX);
+    }
+
+    #[test]
+    fn test_tick_labels() {
+        let format = Callback::from(|value: f64| AttrValue::from(format!("${}", value)));
+        assert_eq!(
+            tick_labels(&[0.0, 2.5], None),
+            vec![AttrValue::from("0"), AttrValue::from("2.5")]
+        );
+        assert_eq!(
+            tick_labels(&[10.0], Some(&format)),
+            vec![AttrValue::from("$10")]
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Named series of data points and their alignment on shared categories
+
+use yew::prelude::*;
+
+use super::ChartData;
+
+/// A named series of data points, such as one line of a line chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    /// Name shown in the legend
+    pub name: AttrValue,
+    /// Color of the series (CSS color), defaults to the chart colors in turn
+    pub color: Option<AttrValue>,
+    /// Data points, labelled with their category
+    pub points: Vec<ChartData>,
+}
+
+impl ChartSeries {
+    /// Create a series from a name and its data points
+    pub fn new(name: impl Into<AttrValue>, points: Vec<ChartData>) -> Self {
+        Self {
+            name: name.into(),
+            color: None,
+            points,
+        }
+    }
+
+    /// Create a series from a name and `(label, value)` pairs
+    pub fn from_pairs<L: Into<String>>(
+        name: impl Into<AttrValue>,
+        pairs: impl IntoIterator<Item = (L, f64)>,
+    ) -> Self {
+        let points = pairs
+            .into_iter()
+            .map(|(label, value)| ChartData {
+                label: label.into(),
+                value,
+            })
+            .collect();
+        Self::new(name, points)
+    }
+
+    /// Set the color of the series
+    pub fn color(mut self, color: impl Into<AttrValue>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Value of the point of a category, if the series has one
+    pub fn value(&self, category: &str) -> Option<f64> {
+        self.points
+            .iter()
+            .find(|point| point.label == category)
+            .map(|point| point.value)
+    }
+}
+
+/// Categories of the horizontal axis: the labels of all points, in order
+/// of first appearance across the series
+pub fn categories(series: &[ChartSeries]) -> Vec<String> {
+    let mut categories: Vec<String> = Vec::new();
+    for point in series.iter().flat_map(|series| &series.points) {
+        if !categories.contains(&point.label) {
+            categories.push(point.label.clone());
+        }
+    }
+    categories
+}
+
+/// Values of each series for each category, `None` where a series has no
+/// point for a category
+pub fn align_series(series: &[ChartSeries], categories: &[String]) -> Vec<Vec<Option<f64>>> {
+    series
+        .iter()
+        .map(|series| {
+            categories
+                .iter()
+                .map(|category| series.value(category))
+                .collect()
+        })
+        .collect()
+}
+
+/// Bottom and top of each series' segment in stacks of the aligned values
+///
+/// Positive values stack up from zero and negative values down from zero,
+/// in series order. Missing values make empty segments.
+pub fn stack(values: &[Vec<Option<f64>>]) -> Vec<Vec<(f64, f64)>> {
+    let count = values.iter().map(Vec::len).max().unwrap_or(0);
+    let mut positive = vec![0.0; count];
+    let mut negative = vec![0.0; count];
+    values
+        .iter()
+        .map(|series| {
+            (0..count)
+                .map(|index| {
+                    let value = series.get(index).copied().flatten().unwrap_or(0.0);
+                    let end = if value < 0.0 {
+                        &mut negative[index]
+                    } else {
+                        &mut positive[index]
+                    };
+                    let segment = (*end, *end + value);
+                    *end += value;
+                    (segment.0.min(segment.1), segment.0.max(segment.1))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> Vec<ChartSeries> {
+        vec![
+            ChartSeries::from_pairs("Desktop", [("Jan", 186.0), ("Feb", 305.0)]),
+            ChartSeries::from_pairs("Mobile", [("Feb", 200.0), ("Mar", -20.0), ("Jan", 80.0)])
+                .color("#2563eb"),
+        ]
+    }
+
+    #[test]
+    fn test_categories_and_alignment() {
+        let series = series();
+        let categories = categories(&series);
+        assert_eq!(categories, vec!["Jan", "Feb", "Mar"]);
+        assert_eq!(
+            align_series(&series, &categories),
+            vec![
+                vec![Some(186.0), Some(305.0), None],
+                vec![Some(80.0), Some(200.0), Some(-20.0)],
+            ]
+        );
+        assert_eq!(series[1].color, Some(AttrValue::from("#2563eb")));
+    }
+
+    #[test]
+    fn test_stack() {
+        let values = vec![
+            vec![Some(10.0), Some(-5.0), None],
+            vec![Some(20.0), Some(-5.0), Some(7.0)],
+            vec![Some(-3.0), Some(4.0), Some(1.0)],
+        ];
+        let stacks = stack(&values);
+        assert_eq!(stacks[0], vec![(0.0, 10.0), (-5.0, 0.0), (0.0, 0.0)]);
+        assert_eq!(stacks[1], vec![(10.0, 30.0), (-10.0, -5.0), (0.0, 7.0)]);
+        assert_eq!(stacks[2], vec![(-3.0, 0.0), (0.0, 4.0), (7.0, 8.0)]);
+    }
+}
@@ -0,0 +1,102 @@
+//! Axis ticks at round values
+
+/// A round number close to `value`: 1, 2 or 5 times a power of ten
+///
+/// With `round`, the closest such number; otherwise the smallest one not
+/// below `value`.
+pub fn nice_number(value: f64, round: bool) -> f64 {
+    if value <= 0.0 || !value.is_finite() {
+        return 1.0;
+    }
+    let exponent = value.log10().floor();
+    let power = 10f64.powf(exponent);
+    let fraction = value / power;
+    let nice = if round {
+        match fraction {
+            f if f < 1.5 => 1.0,
+            f if f < 3.0 => 2.0,
+            f if f < 7.0 => 5.0,
+            _ => 10.0,
+        }
+    } else {
+        match fraction {
+            f if f <= 1.0 => 1.0,
+            f if f <= 2.0 => 2.0,
+            f if f <= 5.0 => 5.0,
+            _ => 10.0,
+        }
+    };
+    nice * power
+}
+
+/// Evenly spaced round tick values covering `min` to `max`, about `count`
+/// of them
+///
+/// The first tick is at or below `min` and the last at or above `max`, so
+/// the ticks also give the domain of the axis. An empty domain is widened
+/// around its value.
+pub fn nice_ticks(min: f64, max: f64, count: usize) -> Vec<f64> {
+    let (mut min, mut max) = (min.min(max), min.max(max));
+    if !min.is_finite() || !max.is_finite() {
+        return vec![0.0, 1.0];
+    }
+    if min == max {
+        let pad = if min == 0.0 { 1.0 } else { min.abs() * 0.1 };
+        min -= pad;
+        max += pad;
+    }
+    let range = nice_number(max - min, false);
+    let step = nice_number(range / count.max(2).saturating_sub(1) as f64, true);
+    let start = (min / step).floor();
+    let end = (max / step).ceil();
+    let decimals = (-step.log10().floor()).max(0.0) as i32;
+    let scale = 10f64.powi(decimals);
+    (start as i64..=end as i64)
+        .map(|index| (index as f64 * step * scale).round() / scale)
+        .collect()
+}
+
+/// Label of a tick or data value, without trailing zeros
+pub fn format_tick(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nice_number() {
+        assert_eq!(nice_number(0.7, false), 1.0);
+        assert_eq!(nice_number(130.0, false), 200.0);
+        assert_eq!(nice_number(130.0, true), 100.0);
+        assert_eq!(nice_number(4.2, true), 5.0);
+        assert_eq!(nice_number(0.0, true), 1.0);
+    }
+
+    #[test]
+    fn test_nice_ticks() {
+        assert_eq!(
+            nice_ticks(0.0, 305.0, 5),
+            vec![0.0, 100.0, 200.0, 300.0, 400.0]
+        );
+        assert_eq!(nice_ticks(0.0, 1.0, 5), vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+        assert_eq!(nice_ticks(-12.0, 18.0, 4), vec![-20.0, 0.0, 20.0]);
+        assert_eq!(nice_ticks(0.0, 0.0, 5).first(), Some(&-1.0));
+        assert_eq!(nice_ticks(f64::INFINITY, 3.0, 5), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_format_tick() {
+        assert_eq!(format_tick(200.0), "200");
+        assert_eq!(format_tick(0.25), "0.25");
+        assert_eq!(format_tick(1.5), "1.5");
+        assert_eq!(format_tick(-0.0001), "0");
+    }
+}
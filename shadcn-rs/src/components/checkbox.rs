@@ -81,6 +81,10 @@ pub struct CheckboxProps {
     #[prop_or_default]
     pub onchange: Option<Callback<Event>>,
 
+    /// Click event handler (e.g. to read modifier keys)
+    #[prop_or_default]
+    pub onclick: Option<Callback<MouseEvent>>,
+
     /// Focus event handler
     #[prop_or_default]
     pub onfocus: Option<Callback<FocusEvent>>,
@@ -144,6 +148,7 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
         value,
         id,
         onchange,
+        onclick,
         onfocus,
         onblur,
         aria_label,
@@ -207,6 +212,7 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
             value={value}
             id={id}
             onchange={onchange}
+            onclick={onclick}
             onfocus={onfocus}
             onblur={onblur}
             aria-label={aria_label}
@@ -236,6 +242,7 @@ mod tests {
             value: None,
             id: None,
             onchange: None,
+            onclick: None,
             onfocus: None,
             onblur: None,
             aria_label: None,
@@ -266,6 +273,7 @@ mod tests {
             value: None,
             id: None,
             onchange: None,
+            onclick: None,
             onfocus: None,
             onblur: None,
             aria_label: None,
@@ -293,6 +301,7 @@ mod tests {
             value: None,
             id: None,
             onchange: None,
+            onclick: None,
             onfocus: None,
             onblur: None,
             aria_label: None,
@@ -320,6 +329,7 @@ mod tests {
             value: None,
             id: None,
             onchange: None,
+            onclick: None,
             onfocus: None,
             onblur: None,
             aria_label: None,
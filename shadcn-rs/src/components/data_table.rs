@@ -1,6 +1,7 @@
 //! Data Table component
 //!
-//! Full-featured data table with sorting, filtering, pagination, and selection.
+//! Full-featured data table with sorting, filtering, pagination, selection,
+//! inline cell editing, row grouping, expandable rows and export.
 //!
 //! # Examples
 //!
@@ -17,14 +18,24 @@
 //!
 //! #[function_component(App)]
 //! fn app() -> Html {
-//!     let data = vec![
-//!         User { id: 1, name: "John".to_string(), email: "john@example.com".to_string() },
-//!         User { id: 2, name: "Jane".to_string(), email: "jane@example.com".to_string() },
+//!     // Shared, so rows are only recomputed when the data changes
+//!     let data = use_memo((), |_| {
+//!         vec![
+//!             User { id: 1, name: "John".to_string(), email: "john@example.com".to_string() },
+//!             User { id: 2, name: "Jane".to_string(), email: "jane@example.com".to_string() },
+//!         ]
+//!     });
+//!
+//!     let columns = vec![
+//!         DataTableColumn::new("name", "Name", |u: User| u.name),
+//!         DataTableColumn::new("email", "Email", |u: User| u.email)
+//!             .cell(|u: User| html! { <a href={format!("mailto:{}", u.email)}>{ u.email }</a> }),
 //!     ];
 //!
 //!     html! {
 //!         <DataTable<User>
 //!             data={data}
+//!             columns={columns}
 //!             sortable=true
 //!             selectable=true
 //!         />
@@ -32,43 +43,131 @@
 //! }
 //! ```
 
-use yew::prelude::*;
+mod body;
+mod column;
+mod edit;
+mod export;
+mod filter;
+mod group;
+mod layout;
+mod pagination;
+mod selection;
+mod sort;
+mod virtualize;
 
-/// Sort direction
-#[derive(Debug, Clone, PartialEq)]
-pub enum SortDirection {
-    /// Ascending order
-    Ascending,
-    /// Descending order
-    Descending,
-}
+pub use column::{DataTableColumn, DataTableValue};
+pub use edit::{DataTableCellEdit, DataTableEditor, next_editable_cell};
+pub use export::{DataTableExport, DataTableExportFormat, export_rows};
+pub use filter::{
+    DataTableColumnFilter, DataTableFilter, DataTableFilterState, DataTableFilterValue,
+    filter_rows, matches_filter,
+};
+pub use group::{DataTableAggregate, RowGroup, group_rows};
+pub use layout::{
+    ColumnLayout, ColumnPin, DEFAULT_PINNED_WIDTH, DataTableState, MIN_COLUMN_WIDTH, layout_columns,
+};
+pub use pagination::{PageItem, clamp_page, page_bounds, page_count, page_items, page_summary};
+pub use selection::{
+    SelectionMode, all_selected, select_range, selection_status, some_selected, toggle_all,
+    toggle_row,
+};
+pub use sort::{DataTableSort, SortDirection, compare_values, next_sort_state, sort_rows};
+pub use virtualize::{row_offsets, spacer_heights, visible_rows};
 
-/// Selection mode
-#[derive(Debug, Clone, PartialEq)]
-pub enum SelectionMode {
-    /// No selection
-    None,
-    /// Single row selection
-    Single,
-    /// Multiple row selection
-    Multiple,
-}
+use crate::components::button::Button;
+use crate::components::calendar::{format_date, parse_date};
+use crate::components::checkbox::Checkbox;
+use crate::components::date_picker::DatePicker;
+use crate::components::dropdown_menu::{
+    DropdownMenu, DropdownMenuCheckboxItem, DropdownMenuContent, DropdownMenuItem,
+    DropdownMenuLabel, DropdownMenuRadioGroup, DropdownMenuRadioItem, DropdownMenuSeparator,
+    DropdownMenuTrigger,
+};
+use crate::components::form::{FormMessage, FormMessageType};
+use crate::components::input::Input;
+use crate::components::native_select::{NativeSelect, NativeSelectOption};
+use crate::components::pagination::{
+    Pagination, PaginationContent, PaginationEllipsis, PaginationItem, PaginationLink,
+    PaginationNext, PaginationPrevious,
+};
+use crate::components::scroll_area::{ScrollArea, ScrollDirection};
+use crate::components::select::Select;
+use crate::hooks::use_controllable_state;
+use crate::types::{Alignment, Size, Variant};
+use crate::utils::generate_id;
+use body::{BodyOptions, BodyRow, GroupHeader, body_rows};
+use chrono::NaiveDate;
+use edit::{EditingCell, focus_cell, focus_editor};
+use export::{copy_to_clipboard, download};
+use gloo::events::EventListener;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use virtualize::{measure_row_heights, use_visible_rows};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, HtmlInputElement, HtmlSelectElement, Node};
+use yew::prelude::*;
 
 /// Data table component properties
 #[derive(Properties, PartialEq)]
 pub struct DataTableProps<T: Clone + PartialEq + 'static> {
     /// Data to display
-    pub data: Vec<T>,
+    ///
+    /// Shared so that filtering, sorting and the body rows are only
+    /// recomputed when a different `Rc` is passed.
+    pub data: Rc<Vec<T>>,
+
+    /// Column definitions
+    ///
+    /// Rows are recomputed when the column ids change; give a column a new
+    /// id if its accessor changes.
+    #[prop_or_default]
+    pub columns: Vec<DataTableColumn<T>>,
 
     /// Enable sorting
     #[prop_or(false)]
     pub sortable: bool,
 
-    /// Enable filtering
+    /// Controlled sort state, ordered by priority
+    #[prop_or_default]
+    pub sort_state: Option<Vec<DataTableSort>>,
+
+    /// Initial sort state (uncontrolled)
+    #[prop_or_default]
+    pub default_sort: Vec<DataTableSort>,
+
+    /// Sort change handler
+    #[prop_or_default]
+    pub on_sort_change: Option<Callback<Vec<DataTableSort>>>,
+
+    /// Skip client-side sorting (rows are already sorted, e.g. by a server)
+    #[prop_or(false)]
+    pub manual_sorting: bool,
+
+    /// Enable filtering (global search and per-column filter inputs)
     #[prop_or(false)]
     pub filterable: bool,
 
-    /// Enable selection
+    /// Controlled filter state
+    #[prop_or_default]
+    pub filter_state: Option<DataTableFilterState>,
+
+    /// Initial filter state (uncontrolled)
+    #[prop_or_default]
+    pub default_filter: DataTableFilterState,
+
+    /// Filter change handler
+    #[prop_or_default]
+    pub on_filter_change: Option<Callback<DataTableFilterState>>,
+
+    /// Skip client-side filtering (rows are already filtered, e.g. by a server)
+    #[prop_or(false)]
+    pub manual_filtering: bool,
+
+    /// Placeholder of the global search input
+    #[prop_or(AttrValue::from("Search..."))]
+    pub search_placeholder: AttrValue,
+
+    /// Enable selection (multiple selection unless `selection_mode` is set)
     #[prop_or(false)]
     pub selectable: bool,
 
@@ -76,31 +175,982 @@ pub struct DataTableProps<T: Clone + PartialEq + 'static> {
     #[prop_or(SelectionMode::None)]
     pub selection_mode: SelectionMode,
 
-    /// Selected row indices
+    /// Unique id of a row, used to key selection (defaults to the row index)
+    #[prop_or_default]
+    pub row_id: Option<Callback<T, AttrValue>>,
+
+    /// Controlled selected row ids
+    #[prop_or_default]
+    pub selected: Option<Vec<AttrValue>>,
+
+    /// Initially selected row ids (uncontrolled)
     #[prop_or_default]
-    pub selected: Option<Vec<usize>>,
+    pub default_selected: Vec<AttrValue>,
 
-    /// Selection change handler
+    /// Selection change handler, called with the selected row ids
     #[prop_or_default]
-    pub on_selection_change: Option<Callback<Vec<usize>>>,
+    pub on_selection_change: Option<Callback<Vec<AttrValue>>>,
+
+    /// Enable inline editing of the columns that have an editor
+    #[prop_or(false)]
+    pub editable: bool,
+
+    /// Cell edit handler, called with each valid committed edit
+    #[prop_or_default]
+    pub on_cell_edit: Option<Callback<DataTableCellEdit>>,
+
+    /// Ids of the columns to group the rows by, outermost first
+    ///
+    /// Groups and their aggregates cover all filtered rows. With pagination
+    /// a page holds `page_size` data rows or collapsed groups, and a group
+    /// split across pages repeats its header.
+    #[prop_or_default]
+    pub group_by: Vec<AttrValue>,
+
+    /// Controlled keys of the collapsed groups (see [`RowGroup::key`])
+    #[prop_or_default]
+    pub collapsed_groups: Option<Vec<AttrValue>>,
+
+    /// Initially collapsed group keys (uncontrolled)
+    #[prop_or_default]
+    pub default_collapsed_groups: Vec<AttrValue>,
+
+    /// Collapsed groups change handler
+    #[prop_or_default]
+    pub on_collapsed_groups_change: Option<Callback<Vec<AttrValue>>>,
+
+    /// Render function for the detail panel shown below an expanded row
+    #[prop_or_default]
+    pub render_detail: Option<Callback<T, Html>>,
+
+    /// Child rows of a row for tree data, shown below the row when expanded
+    #[prop_or_default]
+    pub row_children: Option<Callback<T, Vec<T>>>,
+
+    /// Whether a row has child rows, shown as an expand toggle
+    ///
+    /// With this check `row_children` only runs for expanded rows; without
+    /// it, `row_children` runs for every row to find out.
+    #[prop_or_default]
+    pub has_children: Option<Callback<T, bool>>,
+
+    /// Controlled ids of the expanded rows
+    #[prop_or_default]
+    pub expanded: Option<Vec<AttrValue>>,
+
+    /// Initially expanded row ids (uncontrolled)
+    #[prop_or_default]
+    pub default_expanded: Vec<AttrValue>,
+
+    /// Expanded rows change handler
+    #[prop_or_default]
+    pub on_expanded_change: Option<Callback<Vec<AttrValue>>>,
 
     /// Enable pagination
     #[prop_or(false)]
     pub paginated: bool,
 
-    /// Rows per page
+    /// Rows per page (initial page size when uncontrolled)
     #[prop_or(10)]
     pub rows_per_page: usize,
 
+    /// Controlled page size
+    #[prop_or_default]
+    pub page_size: Option<usize>,
+
+    /// Page size change handler
+    #[prop_or_default]
+    pub on_page_size_change: Option<Callback<usize>>,
+
+    /// Page sizes offered in the footer (selector hidden when empty)
+    #[prop_or(vec![10, 20, 50, 100])]
+    pub page_size_options: Vec<usize>,
+
+    /// Controlled zero-based page index
+    #[prop_or_default]
+    pub page: Option<usize>,
+
+    /// Page change handler, called with the zero-based page index
+    #[prop_or_default]
+    pub on_page_change: Option<Callback<usize>>,
+
+    /// Skip client-side pagination (`data` is the current page, e.g. from a server)
+    #[prop_or(false)]
+    pub manual_pagination: bool,
+
+    /// Total number of rows across all pages, used with `manual_pagination`
+    #[prop_or_default]
+    pub total_rows: Option<usize>,
+
+    /// Controlled column layout (order, widths, pinning and visibility)
+    #[prop_or_default]
+    pub column_state: Option<DataTableState>,
+
+    /// Initial column layout (uncontrolled)
+    #[prop_or_default]
+    pub default_column_state: DataTableState,
+
+    /// Column layout change handler
+    #[prop_or_default]
+    pub on_column_state_change: Option<Callback<DataTableState>>,
+
+    /// Allow resizing columns by dragging the header borders
+    #[prop_or(false)]
+    pub resizable_columns: bool,
+
+    /// Allow reordering columns by dragging the headers
+    #[prop_or(false)]
+    pub reorderable_columns: bool,
+
+    /// Show the "Columns" menu in the toolbar, for hiding and pinning columns
+    #[prop_or(false)]
+    pub column_menu: bool,
+
+    /// Show the "Export" menu in the toolbar, and a "Copy" button for the
+    /// selected rows when rows are selectable
+    #[prop_or(false)]
+    pub exportable: bool,
+
+    /// File name of exports, without the extension
+    #[prop_or(AttrValue::from("export"))]
+    pub export_filename: AttrValue,
+
+    /// Export handler, called with the exported text instead of downloading a file
+    #[prop_or_default]
+    pub on_export: Option<Callback<DataTableExport>>,
+
+    /// Only mount the rows visible in the scroll viewport
+    #[prop_or(false)]
+    pub virtualized: bool,
+
+    /// Row height in pixels (the estimate for unmeasured rows with `measure_rows`)
+    #[prop_or(40.0)]
+    pub row_height: f64,
+
+    /// Measure rendered rows instead of assuming `row_height` for all of them
+    #[prop_or(false)]
+    pub measure_rows: bool,
+
+    /// Rows mounted above and below the viewport when virtualized
+    #[prop_or(10)]
+    pub overscan: usize,
+
+    /// Maximum height (CSS value); the table then scrolls inside a `ScrollArea`
+    #[prop_or_default]
+    pub max_height: Option<AttrValue>,
+
+    /// Keep the header visible while scrolling
+    #[prop_or(false)]
+    pub sticky_header: bool,
+
     /// Additional CSS classes
     #[prop_or_default]
     pub class: Classes,
 
-    /// Render function for rows
+    /// Render function for rows (replaces the column cells of each row)
     #[prop_or_default]
     pub render_row: Option<Callback<T, Html>>,
 }
 
+/// CSS class for the text alignment of a column
+fn align_class(align: Alignment) -> &'static str {
+    match align {
+        Alignment::Start | Alignment::Stretch => "data-table-align-start",
+        Alignment::Center => "data-table-align-center",
+        Alignment::End => "data-table-align-end",
+    }
+}
+
+/// Selection mode in effect for the `selectable` and `selection_mode` props
+fn effective_selection_mode(selectable: bool, mode: SelectionMode) -> SelectionMode {
+    match mode {
+        SelectionMode::None if selectable => SelectionMode::Multiple,
+        mode => mode,
+    }
+}
+
+/// Rows of the body shown on the current page
+struct BodyView {
+    /// Positions of the rows in the body, in display order
+    positions: Vec<usize>,
+    /// Ids of the data rows, in display order
+    row_ids: Rc<Vec<AttrValue>>,
+}
+
+/// Memo dependency that compares shared values by identity
+struct SameRc<T>(Rc<T>);
+
+impl<T> PartialEq for SameRc<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Id of a row: the `row_id` accessor value, or the row index
+fn row_key<T: Clone + PartialEq + 'static>(
+    row_id: &Option<Callback<T, AttrValue>>,
+    row: &T,
+    index: usize,
+) -> AttrValue {
+    match row_id {
+        Some(row_id) => row_id.emit(row.clone()),
+        None => AttrValue::from(index.to_string()),
+    }
+}
+
+/// Render an empty row standing in for rows that are not mounted
+fn render_spacer(height: f64, column_count: usize) -> Html {
+    html! {
+        <tr class="data-table-spacer" style={format!("height: {}px;", height)} aria-hidden="true">
+            <td colspan={column_count.max(1).to_string()}></td>
+        </tr>
+    }
+}
+
+/// Render the indentation of a nested row and, for expandable rows, its expand toggle
+///
+/// `expanded` is `None` for rows that cannot be expanded.
+fn render_expander(
+    depth: usize,
+    expanded: Option<bool>,
+    key: &AttrValue,
+    label: String,
+    on_toggle: &Callback<AttrValue>,
+) -> Html {
+    let toggle = expanded.map(|expanded| {
+        let onclick = {
+            let key = key.clone();
+            let on_toggle = on_toggle.clone();
+            // Keep the click from opening the cell editor
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                on_toggle.emit(key.clone());
+            })
+        };
+        html! {
+            <button
+                type="button"
+                class="data-table-expander"
+                aria-expanded={expanded.to_string()}
+                aria-label={label}
+                {onclick}
+            >
+                <span aria-hidden="true">{ "▸" }</span>
+            </button>
+        }
+    });
+    html! {
+        <span class="data-table-indent" style={format!("--data-table-depth: {};", depth)}>
+            if let Some(toggle) = toggle {
+                { toggle }
+            } else {
+                <span class="data-table-expander-placeholder" />
+            }
+        </span>
+    }
+}
+
+/// Render the header row of a group: its label in the first column and
+/// the column aggregates in the others
+fn render_group_row<T: Clone + PartialEq + 'static>(
+    group: &GroupHeader,
+    collapsed: bool,
+    columns: &[DataTableColumn<T>],
+    layouts: &[ColumnLayout],
+    selectable: bool,
+    on_toggle: &Callback<AttrValue>,
+) -> Html {
+    let column = &columns[group.column];
+    let value = group.value.to_string();
+    let label = format!(
+        "{} group {}",
+        if collapsed { "Expand" } else { "Collapse" },
+        value
+    );
+    let key = AttrValue::from(format!("group:{}", group.key));
+    html! {
+        <tr
+            key={key.to_string()}
+            class="data-table-group-row"
+            role="row"
+            aria-level={(group.depth + 1).to_string()}
+            aria-expanded={(!collapsed).to_string()}
+            data-row-id={key}
+        >
+            if selectable {
+                <td class="data-table-select-cell"></td>
+            }
+            {
+                layouts.iter().enumerate().map(|(position, layout)| {
+                    let cell_column = &columns[layout.index];
+                    let content = if position == 0 {
+                        html! {
+                            <>
+                                { render_expander(group.depth, Some(!collapsed), &group.key, label.clone(), on_toggle) }
+                                <span class="data-table-group-label">
+                                    { column.header.clone() }{ ": " }{ value.clone() }
+                                </span>
+                                <span class="data-table-group-count">{ format!("({})", group.count) }</span>
+                            </>
+                        }
+                    } else {
+                        html! {
+                            if let Some(aggregate) = &group.aggregates[layout.index] {
+                                { aggregate.to_string() }
+                            }
+                        }
+                    };
+                    html! {
+                        <td
+                            key={cell_column.id.to_string()}
+                            class={classes!(
+                                "data-table-cell",
+                                align_class(if position == 0 { Alignment::Start } else { cell_column.align }),
+                                layout.classes(),
+                            )}
+                            style={layout.style(cell_column)}
+                            role="gridcell"
+                        >
+                            { content }
+                        </td>
+                    }
+                }).collect::<Html>()
+            }
+        </tr>
+    }
+}
+
+/// Width change of a column per arrow key press on its resize handle, in pixels
+const RESIZE_STEP: f64 = 10.0;
+
+/// An active column resize drag
+#[derive(Clone)]
+struct ColumnResize {
+    column: AttrValue,
+    start_x: f64,
+    start_width: f64,
+}
+
+/// Header callbacks for resizing and reordering columns
+struct ColumnControls {
+    resizable: bool,
+    reorderable: bool,
+    on_resize_start: Callback<(AttrValue, MouseEvent)>,
+    on_resize_key: Callback<(AttrValue, KeyboardEvent)>,
+    on_drag_start: Callback<(AttrValue, DragEvent)>,
+    on_drop: Callback<(AttrValue, DragEvent)>,
+}
+
+/// Rendered width of the header cell containing the event target
+fn header_width(e: &Event) -> Option<f64> {
+    let target: Element = e.target()?.dyn_into().ok()?;
+    let header: HtmlElement = target.closest("th").ok()??.dyn_into().ok()?;
+    Some(f64::from(header.offset_width()))
+}
+
+/// How an inline edit ends
+#[derive(Clone, Copy, PartialEq)]
+enum EditAction {
+    /// Commit the draft (Enter) and focus the cell
+    Commit,
+    /// Commit the draft because focus left the editor
+    Blur,
+    /// Discard the draft (Escape) and focus the cell
+    Cancel,
+    /// Commit the draft (Tab) and edit the next or previous editable cell
+    Move { backwards: bool },
+}
+
+/// Element to focus after an inline edit starts or ends
+enum EditFocus {
+    /// The control of the editor
+    Editor,
+    /// A cell, by row and column id
+    Cell(AttrValue, AttrValue),
+}
+
+/// Callbacks of the inline cell editors, keyed by row and column id
+struct EditControls {
+    on_start: Callback<(AttrValue, AttrValue)>,
+    on_draft: Callback<String>,
+    on_commit: Callback<(AttrValue, AttrValue, String)>,
+    on_key: Callback<(AttrValue, AttrValue, KeyboardEvent)>,
+    on_focus_out: Callback<(AttrValue, AttrValue, FocusEvent)>,
+}
+
+/// Render the editor of a cell, with its validation error
+fn render_editor(
+    editor: &DataTableEditor,
+    row_id: &AttrValue,
+    column_id: &AttrValue,
+    draft: String,
+    error: Option<AttrValue>,
+    error_id: &AttrValue,
+    controls: &EditControls,
+) -> Html {
+    let label = AttrValue::from(format!("Edit {}", column_id));
+    let invalid = error.is_some();
+    let describedby = invalid.then(|| error_id.clone());
+    let cell = (row_id.clone(), column_id.clone());
+
+    let control = match editor {
+        DataTableEditor::Text | DataTableEditor::Number => {
+            let input_type = match editor {
+                DataTableEditor::Number => "number",
+                _ => "text",
+            };
+            html! {
+                <Input
+                    r#type={input_type}
+                    size={Size::Sm}
+                    value={AttrValue::from(draft)}
+                    error={invalid}
+                    aria_label={label}
+                    aria_invalid={invalid.then_some(true)}
+                    aria_describedby={describedby}
+                    oninput={controls.on_draft.reform(|e: InputEvent| input_value(&e))}
+                />
+            }
+        }
+        DataTableEditor::Select(options) => {
+            let onchange = controls
+                .on_draft
+                .reform(|e: Event| e.target_unchecked_into::<HtmlSelectElement>().value());
+            html! {
+                <NativeSelect
+                    size={Size::Sm}
+                    value={AttrValue::from(draft.clone())}
+                    aria_label={label}
+                    aria_invalid={invalid.then_some(true)}
+                    {onchange}
+                >
+                    {
+                        options.iter().map(|option| html! {
+                            <NativeSelectOption
+                                key={option.to_string()}
+                                value={option.clone()}
+                                selected={option.as_str() == draft}
+                            >
+                                { option.clone() }
+                            </NativeSelectOption>
+                        }).collect::<Html>()
+                    }
+                </NativeSelect>
+            }
+        }
+        DataTableEditor::Checkbox => {
+            let onchange = {
+                let (row_id, column_id) = cell.clone();
+                controls.on_commit.reform(move |e: Event| {
+                    let checked = e.target_unchecked_into::<HtmlInputElement>().checked();
+                    (row_id.clone(), column_id.clone(), checked.to_string())
+                })
+            };
+            html! {
+                <Checkbox
+                    checked={draft == "true"}
+                    error={invalid}
+                    aria_label={label}
+                    aria_invalid={invalid.then_some(true)}
+                    aria_describedby={describedby}
+                    {onchange}
+                />
+            }
+        }
+        DataTableEditor::Date => {
+            let on_change = {
+                let (row_id, column_id) = cell.clone();
+                controls.on_commit.reform(move |date: Option<NaiveDate>| {
+                    let date = date.map(format_date).unwrap_or_default();
+                    (row_id.clone(), column_id.clone(), date)
+                })
+            };
+            html! {
+                <DatePicker value={parse_date(&draft)} {on_change} />
+            }
+        }
+    };
+
+    let onkeydown = {
+        let (row_id, column_id) = cell.clone();
+        controls
+            .on_key
+            .reform(move |e: KeyboardEvent| (row_id.clone(), column_id.clone(), e))
+    };
+    let onfocusout = {
+        let (row_id, column_id) = cell;
+        controls
+            .on_focus_out
+            .reform(move |e: FocusEvent| (row_id.clone(), column_id.clone(), e))
+    };
+
+    html! {
+        <div class="data-table-editor" {onkeydown} {onfocusout}>
+            { control }
+            if let Some(error) = error {
+                <FormMessage message_type={FormMessageType::Error} id={error_id.clone()}>
+                    { error }
+                </FormMessage>
+            }
+        </div>
+    }
+}
+
+/// Render the header cell of a column, with sort, resize and reorder controls
+fn render_header_cell<T: Clone + PartialEq + 'static>(
+    column: &DataTableColumn<T>,
+    layout: &ColumnLayout,
+    sortable: bool,
+    sort_state: &[DataTableSort],
+    set_sort_state: &Callback<Vec<DataTableSort>>,
+    controls: &ColumnControls,
+) -> Html {
+    let sortable = sortable && column.sortable;
+    let priority = sort_state
+        .iter()
+        .position(|sort| sort.column == column.id.as_str())
+        .filter(|_| sortable);
+    let direction = priority.map(|index| sort_state[index].direction);
+    let aria_sort = sortable.then(|| direction.map_or("none", |direction| direction.aria_sort()));
+
+    let content = if sortable {
+        let indicator = match direction {
+            Some(SortDirection::Ascending) => "↑",
+            Some(SortDirection::Descending) => "↓",
+            None => "↕",
+        };
+        let onclick = {
+            let sort_state = sort_state.to_vec();
+            let set_sort_state = set_sort_state.clone();
+            let column_id = column.id.clone();
+            Callback::from(move |e: MouseEvent| {
+                set_sort_state.emit(next_sort_state(&sort_state, &column_id, e.shift_key()));
+            })
+        };
+        html! {
+            <button type="button" class="data-table-sort-button" {onclick}>
+                { column.header.clone() }
+                <span class="data-table-sort-indicator" aria-hidden="true">{ indicator }</span>
+                if let (Some(index), true) = (priority, sort_state.len() > 1) {
+                    <span class="data-table-sort-priority" aria-hidden="true">{ index + 1 }</span>
+                }
+            </button>
+        }
+    } else {
+        column.header.clone()
+    };
+
+    let resize_handle = controls.resizable.then(|| {
+        let onmousedown = {
+            let column_id = column.id.clone();
+            controls
+                .on_resize_start
+                .reform(move |e: MouseEvent| (column_id.clone(), e))
+        };
+        let onkeydown = {
+            let column_id = column.id.clone();
+            controls
+                .on_resize_key
+                .reform(move |e: KeyboardEvent| (column_id.clone(), e))
+        };
+        html! {
+            <span
+                class="data-table-resize-handle"
+                role="separator"
+                aria-orientation="vertical"
+                aria-label={format!("Resize {}", column.id)}
+                tabindex="0"
+                {onmousedown}
+                {onkeydown}
+            />
+        }
+    });
+
+    let (ondragstart, ondragover, ondrop) = if controls.reorderable {
+        let column_id = column.id.clone();
+        let on_drag_start = controls
+            .on_drag_start
+            .reform(move |e: DragEvent| (column_id.clone(), e));
+        let column_id = column.id.clone();
+        let on_drop = controls
+            .on_drop
+            .reform(move |e: DragEvent| (column_id.clone(), e));
+        (
+            Some(on_drag_start),
+            Some(Callback::from(|e: DragEvent| e.prevent_default())),
+            Some(on_drop),
+        )
+    } else {
+        (None, None, None)
+    };
+
+    html! {
+        <th
+            key={column.id.to_string()}
+            class={classes!(
+                "data-table-head",
+                align_class(column.align),
+                layout.classes(),
+                direction.is_some().then_some("data-table-head-sorted"),
+                controls.reorderable.then_some("data-table-head-draggable"),
+            )}
+            style={layout.style(column)}
+            role="columnheader"
+            aria-sort={aria_sort}
+            draggable={controls.reorderable.then_some("true")}
+            {ondragstart}
+            {ondragover}
+            {ondrop}
+        >
+            { content }
+            { resize_handle }
+        </th>
+    }
+}
+
+/// Read the value of the input that fired an event
+fn input_value(e: &InputEvent) -> String {
+    e.target_unchecked_into::<HtmlInputElement>().value()
+}
+
+/// Render the filter input of a column for the header filter row
+fn render_filter_cell<T: Clone + PartialEq + 'static>(
+    column: &DataTableColumn<T>,
+    layout: &ColumnLayout,
+    filter_state: &DataTableFilterState,
+    set_filter_state: &Callback<DataTableFilterState>,
+) -> Html {
+    let current = filter_state.column(&column.id);
+    let update = {
+        let filter_state = filter_state.clone();
+        let set_filter_state = set_filter_state.clone();
+        let column_id = column.id.clone();
+        Callback::from(move |value: DataTableFilterValue| {
+            set_filter_state.emit(filter_state.with_column(&column_id, value));
+        })
+    };
+    let label = format!("Filter {}", column.id);
+
+    let content = match &column.filter {
+        None => Html::default(),
+        Some(DataTableFilter::Contains | DataTableFilter::Equals) => {
+            let value = match current {
+                Some(DataTableFilterValue::Text(text)) => text.clone(),
+                _ => String::new(),
+            };
+            let oninput =
+                update.reform(|e: InputEvent| DataTableFilterValue::Text(input_value(&e)));
+            html! {
+                <Input
+                    size={Size::Sm}
+                    value={AttrValue::from(value)}
+                    placeholder="Filter..."
+                    aria_label={AttrValue::from(label)}
+                    {oninput}
+                />
+            }
+        }
+        Some(DataTableFilter::Range) => {
+            let (min, max) = match current {
+                Some(DataTableFilterValue::Range { min, max }) => (*min, *max),
+                _ => (None, None),
+            };
+            let format_bound = |bound: Option<f64>| {
+                AttrValue::from(bound.map(|b| b.to_string()).unwrap_or_default())
+            };
+            let parse_bound = |e: &InputEvent| input_value(e).trim().parse::<f64>().ok();
+            let on_min = update.reform(move |e: InputEvent| DataTableFilterValue::Range {
+                min: parse_bound(&e),
+                max,
+            });
+            let on_max = update.reform(move |e: InputEvent| DataTableFilterValue::Range {
+                min,
+                max: parse_bound(&e),
+            });
+            html! {
+                <div class="data-table-filter-range">
+                    <Input
+                        r#type="number"
+                        size={Size::Sm}
+                        value={format_bound(min)}
+                        placeholder="Min"
+                        aria_label={AttrValue::from(format!("{} minimum", label))}
+                        oninput={on_min}
+                    />
+                    <Input
+                        r#type="number"
+                        size={Size::Sm}
+                        value={format_bound(max)}
+                        placeholder="Max"
+                        aria_label={AttrValue::from(format!("{} maximum", label))}
+                        oninput={on_max}
+                    />
+                </div>
+            }
+        }
+        Some(DataTableFilter::Select(options)) => {
+            let selected: Vec<String> = match current {
+                Some(DataTableFilterValue::Values(values)) => values.clone(),
+                _ => Vec::new(),
+            };
+            let onchange = update.reform(|e: Event| {
+                let select: HtmlSelectElement = e.target_unchecked_into();
+                let options = select.selected_options();
+                let values = (0..options.length())
+                    .filter_map(|i| options.item(i))
+                    .filter_map(|option| option.get_attribute("value"))
+                    .collect();
+                DataTableFilterValue::Values(values)
+            });
+            html! {
+                <Select
+                    multiple={true}
+                    size={Size::Sm}
+                    aria_label={AttrValue::from(label)}
+                    {onchange}
+                >
+                    {
+                        options.iter().map(|option| {
+                            let is_selected = selected.iter().any(|v| v == option.as_str());
+                            html! {
+                                <option key={option.to_string()} value={option.clone()} selected={is_selected}>
+                                    { option.clone() }
+                                </option>
+                            }
+                        }).collect::<Html>()
+                    }
+                </Select>
+            }
+        }
+    };
+
+    html! {
+        <th
+            key={column.id.to_string()}
+            class={classes!("data-table-filter-cell", layout.classes())}
+            style={layout.style(column)}
+        >
+            { content }
+        </th>
+    }
+}
+
+/// Pin actions offered for each column in the "Columns" menu
+const PIN_ACTIONS: [(Option<ColumnPin>, &str); 3] = [
+    (Some(ColumnPin::Left), "Pin left"),
+    (Some(ColumnPin::Right), "Pin right"),
+    (None, "Unpin"),
+];
+
+/// Render the "Columns" menu toggling column visibility and pinning
+fn render_column_menu<T: Clone + PartialEq + 'static>(
+    columns: &[DataTableColumn<T>],
+    column_state: &DataTableState,
+    set_column_state: &Callback<DataTableState>,
+) -> Html {
+    html! {
+        <DropdownMenu>
+            <DropdownMenuTrigger class="data-table-column-menu">
+                <Button variant={Variant::Outline} size={Size::Sm}>{ "Columns" }</Button>
+            </DropdownMenuTrigger>
+            <DropdownMenuContent>
+                <DropdownMenuLabel>{ "Toggle columns" }</DropdownMenuLabel>
+                {
+                    columns.iter().filter(|column| column.hideable).map(|column| {
+                        let on_checked_change = {
+                            let column_state = column_state.clone();
+                            let set_column_state = set_column_state.clone();
+                            let column_id = column.id.clone();
+                            Callback::from(move |checked: bool| {
+                                set_column_state.emit(column_state.with_hidden(&column_id, !checked));
+                            })
+                        };
+                        html! {
+                            <DropdownMenuCheckboxItem
+                                key={column.id.to_string()}
+                                checked={!column_state.is_hidden(&column.id)}
+                                {on_checked_change}
+                            >
+                                { column.header.clone() }
+                            </DropdownMenuCheckboxItem>
+                        }
+                    }).collect::<Html>()
+                }
+                <DropdownMenuSeparator />
+                <DropdownMenuLabel>{ "Pin columns" }</DropdownMenuLabel>
+                {
+                    columns.iter().map(|column| {
+                        let pinned = column_state.pin(&column.id);
+                        html! {
+                            <DropdownMenuRadioGroup
+                                key={column.id.to_string()}
+                                class="data-table-pin-group"
+                            >
+                                <div class="data-table-pin-label">{ column.header.clone() }</div>
+                                {
+                                    PIN_ACTIONS.into_iter().map(|(pin, label)| {
+                                        let on_select = {
+                                            let column_state = column_state.clone();
+                                            let set_column_state = set_column_state.clone();
+                                            let column_id = column.id.clone();
+                                            Callback::from(move |_: AttrValue| {
+                                                set_column_state.emit(column_state.with_pin(&column_id, pin));
+                                            })
+                                        };
+                                        html! {
+                                            <DropdownMenuRadioItem
+                                                key={label}
+                                                value={label}
+                                                selected={pinned == pin}
+                                                {on_select}
+                                            >
+                                                { label }
+                                            </DropdownMenuRadioItem>
+                                        }
+                                    }).collect::<Html>()
+                                }
+                            </DropdownMenuRadioGroup>
+                        }
+                    }).collect::<Html>()
+                }
+            </DropdownMenuContent>
+        </DropdownMenu>
+    }
+}
+
+/// Export requested from the toolbar, run after the next render
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExportRequest {
+    /// Filtered and sorted rows of all pages as a file
+    File(DataTableExportFormat),
+    /// Selected rows of all pages as TSV on the clipboard
+    CopySelected,
+}
+
+/// Render the "Export" menu with an item per format
+fn render_export_menu(on_export: &Callback<ExportRequest>) -> Html {
+    html! {
+        <DropdownMenu>
+            <DropdownMenuTrigger class="data-table-export-menu">
+                <Button variant={Variant::Outline} size={Size::Sm}>{ "Export" }</Button>
+            </DropdownMenuTrigger>
+            <DropdownMenuContent>
+                {
+                    DataTableExportFormat::all().into_iter().map(|format| {
+                        let onclick = on_export.reform(move |_: MouseEvent| ExportRequest::File(format));
+                        html! {
+                            <DropdownMenuItem key={format.extension()} {onclick}>
+                                { format!("Export {}", format.label()) }
+                            </DropdownMenuItem>
+                        }
+                    }).collect::<Html>()
+                }
+            </DropdownMenuContent>
+        </DropdownMenu>
+    }
+}
+
+/// Render the pagination footer: row summary, page size selector and page links
+fn render_pagination(
+    page: usize,
+    page_size: usize,
+    total: usize,
+    page_size_options: &[usize],
+    set_page: &Callback<usize>,
+    set_page_size: &Callback<usize>,
+) -> Html {
+    let count = page_count(total, page_size);
+    let go_to = |target: usize| {
+        let set_page = set_page.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if target != page {
+                set_page.emit(target);
+            }
+        })
+    };
+
+    let mut sizes = page_size_options.to_vec();
+    if !sizes.is_empty() && !sizes.contains(&page_size) {
+        sizes.push(page_size);
+        sizes.sort_unstable();
+    }
+    let on_page_size = {
+        let set_page_size = set_page_size.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(size) = select.value().parse::<usize>() {
+                set_page_size.emit(size);
+            }
+        })
+    };
+
+    html! {
+        <div class="data-table-footer">
+            <div class="data-table-summary" aria-live="polite">
+                { page_summary(page, page_size, total) }
+            </div>
+            if !sizes.is_empty() {
+                <div class="data-table-page-size">
+                    <span>{ "Rows per page" }</span>
+                    <Select
+                        size={Size::Sm}
+                        value={AttrValue::from(page_size.to_string())}
+                        aria_label="Rows per page"
+                        onchange={on_page_size}
+                    >
+                        {
+                            sizes.iter().map(|size| html! {
+                                <option key={*size} value={size.to_string()} selected={*size == page_size}>
+                                    { size }
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </Select>
+                </div>
+            }
+            <Pagination class="data-table-pagination">
+                <PaginationContent>
+                    <PaginationItem>
+                        <PaginationPrevious
+                            href="#"
+                            disabled={page == 0}
+                            onclick={go_to(page.saturating_sub(1))}
+                        />
+                    </PaginationItem>
+                    {
+                        page_items(page, count, 1).into_iter().enumerate().map(|(index, item)| match item {
+                            PageItem::Page(target) => html! {
+                                <PaginationItem key={index}>
+                                    <PaginationLink
+                                        href="#"
+                                        is_active={target == page}
+                                        onclick={go_to(target)}
+                                    >
+                                        { target + 1 }
+                                    </PaginationLink>
+                                </PaginationItem>
+                            },
+                            PageItem::Ellipsis => html! {
+                                <PaginationItem key={index}>
+                                    <PaginationEllipsis />
+                                </PaginationItem>
+                            },
+                        }).collect::<Html>()
+                    }
+                    <PaginationItem>
+                        <PaginationNext
+                            href="#"
+                            disabled={page + 1 >= count}
+                            onclick={go_to((page + 1).min(count - 1))}
+                        />
+                    </PaginationItem>
+                </PaginationContent>
+            </Pagination>
+        </div>
+    }
+}
+
 /// Data table component
 ///
 /// Feature-rich table for displaying and manipulating data.
@@ -113,8 +1163,14 @@ pub struct DataTableProps<T: Clone + PartialEq + 'static> {
 #[function_component(DataTable)]
 pub fn data_table<T: Clone + PartialEq + 'static>(props: &DataTableProps<T>) -> Html {
     let sortable_val = props.sortable;
-    let selectable_val = props.selectable;
+    let filterable_val = props.filterable;
+    let paginated_val = props.paginated;
+    let selection_mode = effective_selection_mode(props.selectable, props.selection_mode);
+    let selectable_val = selection_mode != SelectionMode::None;
+    let virtualized_val = props.virtualized;
+    let editable_val = props.editable;
     let data = &props.data;
+    let columns = &props.columns;
     let class = &props.class;
 
     let classes: Classes = vec![
@@ -129,43 +1185,873 @@ pub fn data_table<T: Clone + PartialEq + 'static>(props: &DataTableProps<T>) ->
         } else {
             Classes::new()
         },
+        if virtualized_val {
+            Classes::from("data-table-virtualized")
+        } else {
+            Classes::new()
+        },
+        if editable_val {
+            Classes::from("data-table-editable")
+        } else {
+            Classes::new()
+        },
+        if props.sticky_header {
+            Classes::from("data-table-sticky-header")
+        } else {
+            Classes::new()
+        },
         class.clone(),
     ]
     .into_iter()
     .collect();
 
-    html! {
-        <div class={classes} role="grid">
-            <table class="data-table-table">
-                <thead class="data-table-header">
-                    <tr>
+    let (sort_state, set_sort_state) = use_controllable_state(
+        props.sort_state.clone(),
+        props.default_sort.clone(),
+        props.on_sort_change.clone(),
+    );
+
+    let (filter_state, set_filter_state) = use_controllable_state(
+        props.filter_state.clone(),
+        props.default_filter.clone(),
+        props.on_filter_change.clone(),
+    );
+
+    let (page, set_page) = use_controllable_state(props.page, 0, props.on_page_change.clone());
+
+    let (page_size, set_page_size) = use_controllable_state(
+        props.page_size,
+        props.rows_per_page,
+        props.on_page_size_change.clone(),
+    );
+    let page_size = page_size.max(1);
+
+    let (selected, set_selected) = use_controllable_state(
+        props.selected.clone(),
+        props.default_selected.clone(),
+        props.on_selection_change.clone(),
+    );
+    // Last row toggled without shift, the start of a shift-click range
+    let anchor = use_state(|| None::<AttrValue>);
+
+    // Changing filters or page size starts again from the first page
+    let set_filter_state = {
+        let set_filter_state = set_filter_state.clone();
+        let set_page = set_page.clone();
+        Callback::from(move |state: DataTableFilterState| {
+            set_filter_state.emit(state);
+            if paginated_val && page != 0 {
+                set_page.emit(0);
+            }
+        })
+    };
+    let set_page_size = {
+        let set_page = set_page.clone();
+        Callback::from(move |size: usize| {
+            set_page_size.emit(size);
+            if page != 0 {
+                set_page.emit(0);
+            }
+        })
+    };
+
+    let export_request = use_state(|| None::<ExportRequest>);
+
+    // Rows, body rows and offsets are memoized on the identity of the data
+    // and the column ids, so a render that only scrolls does work in
+    // proportion to the mounted rows
+    let column_ids: Vec<AttrValue> = columns.iter().map(|column| column.id.clone()).collect();
+    let filter = (filterable_val && !props.manual_filtering).then(|| filter_state.clone());
+    let sort = (sortable_val && !props.manual_sorting).then(|| sort_state.clone());
+    let sorted_rows = use_memo(
+        (SameRc(data.clone()), column_ids.clone(), filter, sort),
+        |(_, _, filter, sort)| {
+            let rows: Vec<usize> = (0..data.len()).collect();
+            let rows = match filter {
+                Some(filter) => filter_rows(data, rows, columns, filter),
+                None => rows,
+            };
+            match sort {
+                Some(sort) => sort_rows(data, rows, columns, sort),
+                None => rows,
+            }
+        },
+    );
+    let export_source = export_request.is_some().then(|| sorted_rows.clone());
+
+    // Groups, expanded detail panels and tree children make up the body rows
+    let (collapsed_groups, set_collapsed_groups) = use_controllable_state(
+        props.collapsed_groups.clone(),
+        props.default_collapsed_groups.clone(),
+        props.on_collapsed_groups_change.clone(),
+    );
+    let (expanded, set_expanded) = use_controllable_state(
+        props.expanded.clone(),
+        props.default_expanded.clone(),
+        props.on_expanded_change.clone(),
+    );
+    let body = use_memo(
+        (
+            SameRc(sorted_rows.clone()),
+            column_ids,
+            props.group_by.clone(),
+            expanded.clone(),
+            collapsed_groups.clone(),
+            props.row_id.clone(),
+            props.row_children.clone(),
+            props.has_children.clone(),
+            props.render_detail.is_some(),
+        ),
+        |(
+            rows,
+            _,
+            group_by,
+            expanded,
+            collapsed_groups,
+            row_id,
+            children,
+            has_children,
+            detail,
+        )| {
+            let groups = group_rows(data, &rows.0, columns, group_by);
+            body_rows(
+                data,
+                &rows.0,
+                &groups,
+                columns,
+                &BodyOptions {
+                    row_id,
+                    children: children.as_ref(),
+                    has_children: has_children.as_ref(),
+                    detail: *detail,
+                    expanded,
+                    collapsed_groups,
+                },
+            )
+        },
+    );
+
+    // Pagination applies to the entries of the body, so groups and their
+    // aggregates cover all rows and a group split across pages keeps its header
+    let total_rows = if props.manual_pagination {
+        props.total_rows.unwrap_or(data.len())
+    } else {
+        body.entry_count()
+    };
+    let page = clamp_page(page, total_rows, page_size);
+    let page_range = (paginated_val && !props.manual_pagination)
+        .then(|| page_bounds(page, page_size, body.entry_count()));
+    let view = use_memo((SameRc(body.clone()), page_range), |(body, page_range)| {
+        let positions = match *page_range {
+            Some((start, end)) => body.0.page(start, end),
+            None => (0..body.0.rows.len()).collect(),
+        };
+        let row_ids = positions
+            .iter()
+            .filter_map(|&position| match &body.0.rows[position] {
+                BodyRow::Data { id, .. } => Some(id.clone()),
+                _ => None,
+            })
+            .collect();
+        BodyView {
+            positions,
+            row_ids: Rc::new(row_ids),
+        }
+    });
+    let nested = body.grouped || props.render_detail.is_some() || props.row_children.is_some();
+    let on_toggle_group = {
+        let collapsed_groups = collapsed_groups.clone();
+        Callback::from(move |key: AttrValue| {
+            set_collapsed_groups.emit(toggle_row(&collapsed_groups, &key, SelectionMode::Multiple));
+        })
+    };
+    let on_toggle_row = {
+        let expanded = expanded.clone();
+        Callback::from(move |id: AttrValue| {
+            set_expanded.emit(toggle_row(&expanded, &id, SelectionMode::Multiple));
+        })
+    };
+
+    let row_ids = view.row_ids.clone();
+
+    let on_select_all = {
+        let selected = selected.clone();
+        let set_selected = set_selected.clone();
+        let row_ids = row_ids.clone();
+        Callback::from(move |_: MouseEvent| {
+            set_selected.emit(toggle_all(&selected, &row_ids));
+        })
+    };
+    let on_select_row = |id: AttrValue| {
+        let selected = selected.clone();
+        let set_selected = set_selected.clone();
+        let anchor = anchor.clone();
+        let row_ids = row_ids.clone();
+        Callback::from(move |e: MouseEvent| {
+            let next = match &*anchor {
+                Some(from) if e.shift_key() && selection_mode == SelectionMode::Multiple => {
+                    select_range(&selected, &row_ids, from, &id)
+                }
+                _ => {
+                    anchor.set(Some(id.clone()));
+                    toggle_row(&selected, &id, selection_mode)
+                }
+            };
+            set_selected.emit(next);
+        })
+    };
+
+    let (column_state, set_column_state) = use_controllable_state(
+        props.column_state.clone(),
+        props.default_column_state.clone(),
+        props.on_column_state_change.clone(),
+    );
+    let layouts = layout_columns(columns, &column_state);
+
+    // Document listeners outlive a render, so they read the latest layout state
+    let latest_columns = use_mut_ref(|| (column_state.clone(), set_column_state.clone()));
+    *latest_columns.borrow_mut() = (column_state.clone(), set_column_state.clone());
+    let resize = use_mut_ref(|| None::<ColumnResize>);
+    {
+        let resize = resize.clone();
+        use_effect_with(props.resizable_columns, move |resizable| {
+            let listeners = resizable.then(|| {
+                let on_move = {
+                    let resize = resize.clone();
+                    EventListener::new(&gloo::utils::document(), "mousemove", move |event| {
+                        let Some(active) = resize.borrow().clone() else {
+                            return;
+                        };
+                        if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                            let width =
+                                active.start_width + f64::from(event.client_x()) - active.start_x;
+                            let (state, set_state) = &*latest_columns.borrow();
+                            set_state.emit(state.with_width(&active.column, width));
+                        }
+                    })
+                };
+                let on_up = EventListener::new(&gloo::utils::document(), "mouseup", move |_| {
+                    resize.borrow_mut().take();
+                });
+                (on_move, on_up)
+            });
+            move || drop(listeners)
+        });
+    }
+
+    let dragging = use_mut_ref(|| None::<AttrValue>);
+    let controls = ColumnControls {
+        resizable: props.resizable_columns,
+        reorderable: props.reorderable_columns,
+        on_resize_start: Callback::from(move |(column, e): (AttrValue, MouseEvent)| {
+            // Keep the header from starting a drag or a text selection
+            e.prevent_default();
+            e.stop_propagation();
+            if let Some(start_width) = header_width(&e) {
+                *resize.borrow_mut() = Some(ColumnResize {
+                    column,
+                    start_x: f64::from(e.client_x()),
+                    start_width,
+                });
+            }
+        }),
+        on_resize_key: {
+            let column_state = column_state.clone();
+            let set_column_state = set_column_state.clone();
+            Callback::from(move |(column, e): (AttrValue, KeyboardEvent)| {
+                let step = match e.key().as_str() {
+                    "ArrowLeft" => -RESIZE_STEP,
+                    "ArrowRight" => RESIZE_STEP,
+                    _ => return,
+                };
+                e.prevent_default();
+                if let Some(width) = column_state.width(&column).or_else(|| header_width(&e)) {
+                    set_column_state.emit(column_state.with_width(&column, width + step));
+                }
+            })
+        },
+        on_drag_start: {
+            let dragging = dragging.clone();
+            Callback::from(move |(column, e): (AttrValue, DragEvent)| {
+                if let Some(transfer) = e.data_transfer() {
+                    transfer.set_effect_allowed("move");
+                    let _ = transfer.set_data("text/plain", &column);
+                }
+                *dragging.borrow_mut() = Some(column);
+            })
+        },
+        on_drop: {
+            let column_state = column_state.clone();
+            let set_column_state = set_column_state.clone();
+            let ids: Vec<AttrValue> = columns.iter().map(|column| column.id.clone()).collect();
+            Callback::from(move |(target, e): (AttrValue, DragEvent)| {
+                e.prevent_default();
+                if let Some(source) = dragging.borrow_mut().take()
+                    && source != target
+                {
+                    set_column_state.emit(column_state.with_moved(&source, &target, &ids));
+                }
+            })
+        },
+    };
+
+    let column_count = layouts.len() + usize::from(selectable_val);
+    let has_column_filters = filterable_val
+        && layouts
+            .iter()
+            .any(|layout| columns[layout.index].filter.is_some());
+    let header_rows = 1 + usize::from(has_column_filters);
+
+    // Virtualization: offsets of all rows, of which only `mounted` are rendered
+    let body_ref = use_node_ref();
+    let row_heights = use_mut_ref(HashMap::<AttrValue, f64>::new);
+    // Bumped whenever a measured height changes
+    let heights_revision = use_mut_ref(|| 0_usize);
+    let measure_rows = virtualized_val && props.measure_rows;
+    let offsets = use_memo(
+        (
+            SameRc(view.clone()),
+            virtualized_val,
+            measure_rows,
+            props.row_height,
+            *heights_revision.borrow(),
+        ),
+        |(view, virtualized, measure_rows, row_height, _)| {
+            if !*virtualized {
+                return Vec::new();
+            }
+            let heights = row_heights.borrow();
+            row_offsets(view.0.positions.iter().map(|&position| {
+                let key = body.rows[position].key();
+                let measured = measure_rows.then(|| heights.get(&key).copied());
+                measured.flatten().unwrap_or(*row_height)
+            }))
+        },
+    );
+    let mounted = use_visible_rows(
+        body_ref.clone(),
+        offsets.clone(),
+        props.overscan,
+        virtualized_val,
+    );
+    {
+        let body_ref = body_ref.clone();
+        let force_update = use_force_update();
+        use_effect(move || {
+            if measure_rows && measure_row_heights(&body_ref, &row_heights) {
+                *heights_revision.borrow_mut() += 1;
+                force_update.force_update();
+            }
+        });
+    }
+    let mounted = if virtualized_val {
+        let end = mounted.end.min(view.positions.len());
+        mounted.start.min(end)..end
+    } else {
+        0..view.positions.len()
+    };
+    let (space_before, space_after) = if virtualized_val {
+        spacer_heights(&offsets, &mounted)
+    } else {
+        (0.0, 0.0)
+    };
+
+    // Inline editing; focus listeners outlive a render, so the edit is mirrored in a ref
+    let editing = use_state(|| None::<EditingCell>);
+    let latest_edit = use_mut_ref(|| None::<EditingCell>);
+    *latest_edit.borrow_mut() = (*editing).clone();
+    let focus_request = use_mut_ref(|| None::<EditFocus>);
+    let error_id = use_state(|| AttrValue::from(generate_id("data-table-edit-error")));
+    {
+        let body_ref = body_ref.clone();
+        let focus_request = focus_request.clone();
+        use_effect(move || match focus_request.borrow_mut().take() {
+            Some(EditFocus::Editor) => focus_editor(&body_ref),
+            Some(EditFocus::Cell(row_id, column_id)) => focus_cell(&body_ref, &row_id, &column_id),
+            None => {}
+        });
+    }
+    let set_edit = {
+        let editing = editing.clone();
+        let latest_edit = latest_edit.clone();
+        Callback::from(move |edit: Option<EditingCell>| {
+            *latest_edit.borrow_mut() = edit.clone();
+            editing.set(edit);
+        })
+    };
+    let finish_edit = {
+        let latest_edit = latest_edit.clone();
+        let focus_request = focus_request.clone();
+        let set_edit = set_edit.clone();
+        let on_cell_edit = props.on_cell_edit.clone();
+        let columns = columns.clone();
+        let row_ids = row_ids.clone();
+        let editable_ids: Vec<AttrValue> = layouts
+            .iter()
+            .map(|layout| &columns[layout.index])
+            .filter(|column| editable_val && column.editor.is_some())
+            .map(|column| column.id.clone())
+            .collect();
+        Callback::from(move |action: EditAction| {
+            let Some(edit) = latest_edit.borrow().clone() else {
+                return;
+            };
+            if action != EditAction::Cancel
+                && let Some(draft) = &edit.draft
+                && let Some(column) = columns.iter().find(|column| column.id == edit.column_id)
+            {
+                match column.parse_edit(draft) {
+                    Ok(value) => {
+                        if let Some(on_cell_edit) = &on_cell_edit {
+                            on_cell_edit.emit(DataTableCellEdit {
+                                row_id: edit.row_id.clone(),
+                                column_id: edit.column_id.clone(),
+                                value,
+                            });
+                        }
+                    }
+                    Err(error) => {
+                        set_edit.emit(Some(EditingCell {
+                            error: Some(error),
+                            ..edit
+                        }));
+                        return;
+                    }
+                }
+            }
+            let next = match action {
+                EditAction::Move { backwards } => next_editable_cell(
+                    &row_ids,
+                    &editable_ids,
+                    &edit.row_id,
+                    &edit.column_id,
+                    backwards,
+                ),
+                _ => None,
+            };
+            *focus_request.borrow_mut() = match (&next, action) {
+                (Some(_), _) => Some(EditFocus::Editor),
+                (None, EditAction::Blur) => None,
+                (None, _) => Some(EditFocus::Cell(edit.row_id, edit.column_id)),
+            };
+            set_edit.emit(next.map(|(row_id, column_id)| EditingCell::new(row_id, column_id)));
+        })
+    };
+    let edit_controls = EditControls {
+        on_start: {
+            let latest_edit = latest_edit.clone();
+            let focus_request = focus_request.clone();
+            let set_edit = set_edit.clone();
+            Callback::from(move |(row_id, column_id): (AttrValue, AttrValue)| {
+                let current = latest_edit.borrow().clone();
+                if current.is_some_and(|edit| edit.is(&row_id, &column_id)) {
+                    return;
+                }
+                *focus_request.borrow_mut() = Some(EditFocus::Editor);
+                set_edit.emit(Some(EditingCell::new(row_id, column_id)));
+            })
+        },
+        on_draft: {
+            let latest_edit = latest_edit.clone();
+            let set_edit = set_edit.clone();
+            Callback::from(move |draft: String| {
+                let current = latest_edit.borrow().clone();
+                if let Some(edit) = current {
+                    set_edit.emit(Some(EditingCell {
+                        draft: Some(draft),
+                        error: None,
+                        ..edit
+                    }));
+                }
+            })
+        },
+        on_commit: {
+            let set_edit = set_edit.clone();
+            let finish_edit = finish_edit.clone();
+            Callback::from(
+                move |(row_id, column_id, draft): (AttrValue, AttrValue, String)| {
+                    set_edit.emit(Some(EditingCell {
+                        draft: Some(draft),
+                        ..EditingCell::new(row_id, column_id)
+                    }));
+                    finish_edit.emit(EditAction::Commit);
+                },
+            )
+        },
+        on_key: {
+            let latest_edit = latest_edit.clone();
+            let finish_edit = finish_edit.clone();
+            Callback::from(
+                move |(row_id, column_id, e): (AttrValue, AttrValue, KeyboardEvent)| {
+                    let current = latest_edit.borrow().clone();
+                    if !current.is_some_and(|edit| edit.is(&row_id, &column_id)) {
+                        return;
+                    }
+                    let on_button = e
+                        .target()
+                        .and_then(|target| target.dyn_into::<Element>().ok())
+                        .is_some_and(|target| target.tag_name() == "BUTTON");
+                    let action = match e.key().as_str() {
+                        // Enter on a button (the date picker trigger) activates it
+                        "Enter" if !on_button => EditAction::Commit,
+                        "Escape" => EditAction::Cancel,
+                        "Tab" => EditAction::Move {
+                            backwards: e.shift_key(),
+                        },
+                        _ => return,
+                    };
+                    e.prevent_default();
+                    e.stop_propagation();
+                    finish_edit.emit(action);
+                },
+            )
+        },
+        on_focus_out: {
+            let latest_edit = latest_edit.clone();
+            let finish_edit = finish_edit.clone();
+            Callback::from(
+                move |(row_id, column_id, e): (AttrValue, AttrValue, FocusEvent)| {
+                    // Focus moving within the editor (e.g. into the date picker) keeps it open
+                    let editor = e.current_target().and_then(|t| t.dyn_into::<Node>().ok());
+                    let next = e.related_target().and_then(|t| t.dyn_into::<Node>().ok());
+                    if let (Some(editor), Some(next)) = (&editor, &next)
+                        && editor.contains(Some(next))
+                    {
+                        return;
+                    }
+                    let current = latest_edit.borrow().clone();
+                    if current.is_some_and(|edit| edit.is(&row_id, &column_id)) {
+                        finish_edit.emit(EditAction::Blur);
+                    }
+                },
+            )
+        },
+    };
+
+    let selected_ids: HashSet<&AttrValue> = selected.iter().collect();
+
+    // Exports read the rows of the render after the request, so the data is not
+    // cloned into toolbar callbacks
+    let export = export_request.zip(export_source).map(|(request, source)| {
+        let content = match request {
+            ExportRequest::File(format) => export_rows(
+                source.iter().map(|&idx| &data[idx]),
+                columns,
+                &column_state,
+                format,
+            ),
+            ExportRequest::CopySelected => export_rows(
+                source
+                    .iter()
+                    .filter(|&&idx| selected_ids.contains(&row_key(&props.row_id, &data[idx], idx)))
+                    .map(|&idx| &data[idx]),
+                columns,
+                &column_state,
+                DataTableExportFormat::Tsv,
+            ),
+        };
+        (request, content)
+    });
+    {
+        let export_request = export_request.clone();
+        let on_export = props.on_export.clone();
+        let export_filename = props.export_filename.clone();
+        use_effect(move || {
+            let Some((request, content)) = export else {
+                return;
+            };
+            match request {
+                ExportRequest::File(format) => {
+                    let filename = format!("{}.{}", export_filename, format.extension());
+                    match &on_export {
+                        Some(on_export) => on_export.emit(DataTableExport {
+                            format,
+                            filename: AttrValue::from(filename),
+                            content,
+                        }),
+                        None => download(&filename, format.mime_type(), &content),
+                    }
+                }
+                ExportRequest::CopySelected => copy_to_clipboard(&content),
+            }
+            export_request.set(None);
+        });
+    }
+    let on_export_request = {
+        let export_request = export_request.clone();
+        Callback::from(move |request: ExportRequest| export_request.set(Some(request)))
+    };
+
+    let footer = paginated_val.then(|| {
+        render_pagination(
+            page,
+            page_size,
+            total_rows,
+            &props.page_size_options,
+            &set_page,
+            &set_page_size,
+        )
+    });
+
+    let on_search = {
+        let filter_state = filter_state.clone();
+        let set_filter_state = set_filter_state.clone();
+        Callback::from(move |e: InputEvent| {
+            set_filter_state.emit(filter_state.with_global(input_value(&e)));
+        })
+    };
+
+    let (all_checked, some_checked) = selection_status(&selected, &row_ids);
+
+    let table = html! {
+        <table
+            class="data-table-table"
+            role={if nested { "treegrid" } else { "grid" }}
+            aria-multiselectable={(selection_mode == SelectionMode::Multiple).then_some("true")}
+            aria-rowcount={virtualized_val.then(|| (view.positions.len() + header_rows).to_string())}
+        >
+            <thead class="data-table-header">
+                <tr>
+                    if selectable_val {
+                        <th class="data-table-select-column">
+                            if selection_mode == SelectionMode::Multiple {
+                                <Checkbox
+                                    checked={all_checked}
+                                    indeterminate={some_checked}
+                                    disabled={row_ids.is_empty()}
+                                    aria_label="Select all"
+                                    onclick={on_select_all}
+                                />
+                            }
+                        </th>
+                    }
+                    {
+                        layouts.iter().map(|layout| {
+                            render_header_cell(
+                                &columns[layout.index],
+                                layout,
+                                sortable_val,
+                                &sort_state,
+                                &set_sort_state,
+                                &controls,
+                            )
+                        }).collect::<Html>()
+                    }
+                </tr>
+                if has_column_filters {
+                    <tr class="data-table-filter-row">
                         if selectable_val {
-                            <th class="data-table-select-column">
-                                <input type="checkbox" aria-label="Select all" />
-                            </th>
+                            <th class="data-table-select-column"></th>
+                        }
+                        {
+                            layouts.iter().map(|layout| {
+                                render_filter_cell(
+                                    &columns[layout.index],
+                                    layout,
+                                    &filter_state,
+                                    &set_filter_state,
+                                )
+                            }).collect::<Html>()
                         }
-                        <th>{ "Column 1" }</th>
-                        <th>{ "Column 2" }</th>
                     </tr>
-                </thead>
-                <tbody class="data-table-body">
-                    {
-                        data.iter().enumerate().map(|(idx, _item)| {
-                            html! {
-                                <tr key={idx} role="row">
-                                    if selectable_val {
-                                        <td class="data-table-select-cell">
-                                            <input type="checkbox" aria-label={format!("Select row {}", idx + 1)} />
+                }
+            </thead>
+            <tbody class="data-table-body" ref={body_ref}>
+                if view.positions.is_empty() {
+                    <tr class="data-table-empty">
+                        <td colspan={column_count.max(1).to_string()}>{ "No results." }</td>
+                    </tr>
+                }
+                if space_before > 0.0 {
+                    { render_spacer(space_before, column_count) }
+                }
+                {
+                    mounted.clone().map(|position| {
+                        let aria_rowindex = virtualized_val.then(|| (position + header_rows + 1).to_string());
+                        let (item, id, depth, number, expandable, is_expanded) = match &body.rows[view.positions[position]] {
+                            BodyRow::Group { group, collapsed } => {
+                                return render_group_row(
+                                    group,
+                                    *collapsed,
+                                    columns,
+                                    &layouts,
+                                    selectable_val,
+                                    &on_toggle_group,
+                                );
+                            }
+                            BodyRow::Detail { row, id } => {
+                                let key = format!("detail:{}", id);
+                                return html! {
+                                    <tr
+                                        key={key.clone()}
+                                        class="data-table-detail-row"
+                                        role="row"
+                                        aria-rowindex={aria_rowindex}
+                                        data-row-id={key}
+                                    >
+                                        <td class="data-table-detail-cell" colspan={column_count.max(1).to_string()}>
+                                            if let Some(render_detail) = &props.render_detail {
+                                                { render_detail.emit(row.get(data).clone()) }
+                                            }
                                         </td>
-                                    }
-                                    <td>{ format!("Row {} - Col 1", idx + 1) }</td>
-                                    <td>{ format!("Row {} - Col 2", idx + 1) }</td>
-                                </tr>
+                                    </tr>
+                                };
                             }
-                        }).collect::<Html>()
+                            BodyRow::Data { row, id, depth, number, expandable, expanded } => {
+                                (row.get(data), id, *depth, *number, *expandable, *expanded)
+                            }
+                        };
+                        let is_selected = selectable_val && selected_ids.contains(id);
+                        let expander = nested.then(|| {
+                            let label = format!("{} row {}", if is_expanded { "Collapse" } else { "Expand" }, number);
+                            render_expander(depth, expandable.then_some(is_expanded), id, label, &on_toggle_row)
+                        });
+                        html! {
+                            <tr
+                                key={id.to_string()}
+                                class={classes!("data-table-row", is_selected.then_some("data-table-row-selected"))}
+                                role="row"
+                                aria-selected={selectable_val.then(|| is_selected.to_string())}
+                                aria-rowindex={aria_rowindex}
+                                aria-level={nested.then(|| (depth + 1).to_string())}
+                                aria-expanded={expandable.then(|| is_expanded.to_string())}
+                                data-row-id={(measure_rows || editable_val).then(|| id.clone())}
+                            >
+                                if selectable_val {
+                                    <td class="data-table-select-cell">
+                                        <Checkbox
+                                            checked={is_selected}
+                                            aria_label={AttrValue::from(format!("Select row {}", number))}
+                                            onclick={on_select_row(id.clone())}
+                                        />
+                                    </td>
+                                }
+                                if let Some(render_row) = &props.render_row {
+                                    { render_row.emit(item.clone()) }
+                                } else {
+                                    {
+                                        layouts.iter().enumerate().map(|(position, layout)| {
+                                            let column = &columns[layout.index];
+                                            let editor = column.editor.as_ref().filter(|_| editable_val);
+                                            let edit = editing
+                                                .as_ref()
+                                                .filter(|edit| editor.is_some() && edit.is(id, &column.id));
+                                            // Checkbox cells are always rendered as their editor
+                                            let is_checkbox = matches!(editor, Some(DataTableEditor::Checkbox));
+                                            let content = match editor {
+                                                Some(editor) if edit.is_some() || is_checkbox => render_editor(
+                                                    editor,
+                                                    id,
+                                                    &column.id,
+                                                    edit.and_then(|edit| edit.draft.clone())
+                                                        .unwrap_or_else(|| editor.draft(&column.value(item))),
+                                                    edit.and_then(|edit| edit.error.clone()),
+                                                    &error_id,
+                                                    &edit_controls,
+                                                ),
+                                                _ => column.render_cell(item),
+                                            };
+                                            // Other editable cells are focusable and open their editor
+                                            // on click, Enter or F2
+                                            let opens_editor = editor.is_some() && !is_checkbox && edit.is_none();
+                                            let onclick = opens_editor.then(|| {
+                                                let cell = (id.clone(), column.id.clone());
+                                                edit_controls.on_start.reform(move |_: MouseEvent| cell.clone())
+                                            });
+                                            let onkeydown = opens_editor.then(|| {
+                                                let cell = (id.clone(), column.id.clone());
+                                                let on_start = edit_controls.on_start.clone();
+                                                Callback::from(move |e: KeyboardEvent| {
+                                                    if matches!(e.key().as_str(), "Enter" | "F2") {
+                                                        e.prevent_default();
+                                                        on_start.emit(cell.clone());
+                                                    }
+                                                })
+                                            });
+                                            html! {
+                                                <td
+                                                    key={column.id.to_string()}
+                                                    class={classes!(
+                                                        "data-table-cell",
+                                                        align_class(column.align),
+                                                        layout.classes(),
+                                                        editor.is_some().then_some("data-table-cell-editable"),
+                                                        edit.is_some().then_some("data-table-cell-editing"),
+                                                    )}
+                                                    style={layout.style(column)}
+                                                    role="gridcell"
+                                                    tabindex={opens_editor.then_some("0")}
+                                                    data-column-id={editor.map(|_| column.id.clone())}
+                                                    {onclick}
+                                                    {onkeydown}
+                                                >
+                                                    if position == 0 {
+                                                        { expander.clone() }
+                                                    }
+                                                    { content }
+                                                </td>
+                                            }
+                                        }).collect::<Html>()
+                                    }
+                                }
+                            </tr>
+                        }
+                    }).collect::<Html>()
+                }
+                if space_after > 0.0 {
+                    { render_spacer(space_after, column_count) }
+                }
+            </tbody>
+        </table>
+    };
+
+    html! {
+        <div class={classes}>
+            if filterable_val || props.column_menu || props.exportable {
+                <div class="data-table-toolbar">
+                    if filterable_val {
+                        <Input
+                            r#type="search"
+                            class="data-table-search"
+                            value={AttrValue::from(filter_state.global.clone())}
+                            placeholder={props.search_placeholder.clone()}
+                            aria_label={props.search_placeholder.clone()}
+                            oninput={on_search}
+                        />
                     }
-                </tbody>
-            </table>
+                    <div class="data-table-toolbar-actions">
+                        if props.exportable && selectable_val {
+                            <Button
+                                variant={Variant::Outline}
+                                size={Size::Sm}
+                                disabled={selected.is_empty()}
+                                onclick={on_export_request.reform(|_: MouseEvent| ExportRequest::CopySelected)}
+                            >
+                                { "Copy" }
+                            </Button>
+                        }
+                        if props.exportable {
+                            { render_export_menu(&on_export_request) }
+                        }
+                        if props.column_menu {
+                            { render_column_menu(columns, &column_state, &set_column_state) }
+                        }
+                    </div>
+                </div>
+            }
+            if let Some(max_height) = props.max_height.clone() {
+                <ScrollArea
+                    class="data-table-scroll"
+                    direction={ScrollDirection::Both}
+                    max_height={max_height}
+                    show_shadow={false}
+                >
+                    { table }
+                </ScrollArea>
+            } else {
+                { table }
+            }
+            { footer }
         </div>
     }
 }
@@ -188,15 +2074,61 @@ mod tests {
         }];
 
         let props = DataTableProps {
-            data,
+            data: Rc::new(data),
+            columns: vec![],
             sortable: false,
+            sort_state: None,
+            default_sort: vec![],
+            on_sort_change: None,
+            manual_sorting: false,
             filterable: false,
+            filter_state: None,
+            default_filter: DataTableFilterState::default(),
+            on_filter_change: None,
+            manual_filtering: false,
+            search_placeholder: AttrValue::from("Search..."),
             selectable: false,
             selection_mode: SelectionMode::None,
+            row_id: None,
             selected: None,
+            default_selected: vec![],
             on_selection_change: None,
+            editable: false,
+            on_cell_edit: None,
+            group_by: vec![],
+            collapsed_groups: None,
+            default_collapsed_groups: vec![],
+            on_collapsed_groups_change: None,
+            render_detail: None,
+            row_children: None,
+            has_children: None,
+            expanded: None,
+            default_expanded: vec![],
+            on_expanded_change: None,
             paginated: false,
             rows_per_page: 10,
+            page_size: None,
+            on_page_size_change: None,
+            page_size_options: vec![10, 20, 50, 100],
+            page: None,
+            on_page_change: None,
+            manual_pagination: false,
+            total_rows: None,
+            column_state: None,
+            default_column_state: DataTableState::default(),
+            on_column_state_change: None,
+            resizable_columns: false,
+            reorderable_columns: false,
+            column_menu: false,
+            exportable: false,
+            export_filename: AttrValue::from("export"),
+            on_export: None,
+            virtualized: false,
+            row_height: 40.0,
+            measure_rows: false,
+            overscan: 10,
+            max_height: None,
+            sticky_header: false,
             class: Classes::new(),
             render_row: None,
         };
@@ -213,20 +2145,70 @@ mod tests {
         }];
 
         let props = DataTableProps {
-            data,
+            data: Rc::new(data),
+            columns: vec![],
             sortable: true,
+            sort_state: Some(vec![DataTableSort::descending("name")]),
+            default_sort: vec![],
+            on_sort_change: None,
+            manual_sorting: false,
             filterable: false,
+            filter_state: None,
+            default_filter: DataTableFilterState::default(),
+            on_filter_change: None,
+            manual_filtering: false,
+            search_placeholder: AttrValue::from("Search..."),
             selectable: false,
             selection_mode: SelectionMode::None,
+            row_id: None,
             selected: None,
+            default_selected: vec![],
             on_selection_change: None,
+            editable: false,
+            on_cell_edit: None,
+            group_by: vec![],
+            collapsed_groups: None,
+            default_collapsed_groups: vec![],
+            on_collapsed_groups_change: None,
+            render_detail: None,
+            row_children: None,
+            has_children: None,
+            expanded: None,
+            default_expanded: vec![],
+            on_expanded_change: None,
             paginated: false,
             rows_per_page: 10,
+            page_size: None,
+            on_page_size_change: None,
+            page_size_options: vec![10, 20, 50, 100],
+            page: None,
+            on_page_change: None,
+            manual_pagination: false,
+            total_rows: None,
+            column_state: None,
+            default_column_state: DataTableState::default(),
+            on_column_state_change: None,
+            resizable_columns: false,
+            reorderable_columns: false,
+            column_menu: false,
+            exportable: false,
+            export_filename: AttrValue::from("export"),
+            on_export: None,
+            virtualized: false,
+            row_height: 40.0,
+            measure_rows: false,
+            overscan: 10,
+            max_height: None,
+            sticky_header: false,
             class: Classes::new(),
             render_row: None,
         };
 
         assert!(props.sortable);
+        assert_eq!(
+            props.sort_state,
+            Some(vec![DataTableSort::descending("name")])
+        );
     }
 
     #[test]
@@ -237,21 +2219,74 @@ mod tests {
         }];
 
         let props = DataTableProps {
-            data,
+            data: Rc::new(data),
+            columns: vec![],
             sortable: false,
+            sort_state: None,
+            default_sort: vec![],
+            on_sort_change: None,
+            manual_sorting: false,
             filterable: false,
+            filter_state: None,
+            default_filter: DataTableFilterState::default(),
+            on_filter_change: None,
+            manual_filtering: false,
+            search_placeholder: AttrValue::from("Search..."),
             selectable: true,
             selection_mode: SelectionMode::Multiple,
-            selected: None,
+            row_id: Some(Callback::from(|row: TestData| {
+                AttrValue::from(row.id.to_string())
+            })),
+            selected: Some(vec![AttrValue::from("1")]),
+            default_selected: vec![],
             on_selection_change: None,
+            editable: false,
+            on_cell_edit: None,
+            group_by: vec![],
+            collapsed_groups: None,
+            default_collapsed_groups: vec![],
+            on_collapsed_groups_change: None,
+            render_detail: None,
+            row_children: None,
+            has_children: None,
+            expanded: None,
+            default_expanded: vec![],
+            on_expanded_change: None,
             paginated: false,
             rows_per_page: 10,
+            page_size: None,
+            on_page_size_change: None,
+            page_size_options: vec![10, 20, 50, 100],
+            page: None,
+            on_page_change: None,
+            manual_pagination: false,
+            total_rows: None,
+            column_state: None,
+            default_column_state: DataTableState::default(),
+            on_column_state_change: None,
+            resizable_columns: false,
+            reorderable_columns: false,
+            column_menu: false,
+            exportable: false,
+            export_filename: AttrValue::from("export"),
+            on_export: None,
+            virtualized: false,
+            row_height: 40.0,
+            measure_rows: false,
+            overscan: 10,
+            max_height: None,
+            sticky_header: false,
             class: Classes::new(),
             render_row: None,
         };
 
         assert!(props.selectable);
         assert_eq!(props.selection_mode, SelectionMode::Multiple);
+        assert_eq!(
+            row_key(&props.row_id, &props.data[0], 0),
+            AttrValue::from("1")
+        );
+        assert_eq!(props.selected, Some(vec![AttrValue::from("1")]));
     }
 
     #[test]
@@ -262,26 +2297,110 @@ mod tests {
         }];
 
         let props = DataTableProps {
-            data,
+            data: Rc::new(data),
+            columns: vec![],
             sortable: false,
+            sort_state: None,
+            default_sort: vec![],
+            on_sort_change: None,
+            manual_sorting: false,
             filterable: false,
+            filter_state: None,
+            default_filter: DataTableFilterState::default(),
+            on_filter_change: None,
+            manual_filtering: false,
+            search_placeholder: AttrValue::from("Search..."),
             selectable: false,
             selection_mode: SelectionMode::None,
+            row_id: None,
             selected: None,
+            default_selected: vec![],
             on_selection_change: None,
+            editable: false,
+            on_cell_edit: None,
+            group_by: vec![],
+            collapsed_groups: None,
+            default_collapsed_groups: vec![],
+            on_collapsed_groups_change: None,
+            render_detail: None,
+            row_children: None,
+            has_children: None,
+            expanded: None,
+            default_expanded: vec![],
+            on_expanded_change: None,
             paginated: true,
             rows_per_page: 20,
+            page_size: None,
+            on_page_size_change: None,
+            page_size_options: vec![10, 20, 50, 100],
+            page: Some(2),
+            on_page_change: None,
+            manual_pagination: true,
+            total_rows: Some(95),
+            column_state: None,
+            default_column_state: DataTableState::default(),
+            on_column_state_change: None,
+            resizable_columns: false,
+            reorderable_columns: false,
+            column_menu: false,
+            exportable: false,
+            export_filename: AttrValue::from("export"),
+            on_export: None,
+            virtualized: false,
+            row_height: 40.0,
+            measure_rows: false,
+            overscan: 10,
+            max_height: None,
+            sticky_header: false,
             class: Classes::new(),
             render_row: None,
         };
 
         assert!(props.paginated);
         assert_eq!(props.rows_per_page, 20);
+        assert_eq!(props.page, Some(2));
+        assert_eq!(
+            page_count(props.total_rows.unwrap(), props.rows_per_page),
+            5
+        );
+    }
+
+    #[test]
+    fn test_effective_selection_mode() {
+        assert_eq!(
+            effective_selection_mode(false, SelectionMode::None),
+            SelectionMode::None
+        );
+        assert_eq!(
+            effective_selection_mode(true, SelectionMode::None),
+            SelectionMode::Multiple
+        );
+        assert_eq!(
+            effective_selection_mode(true, SelectionMode::Single),
+            SelectionMode::Single
+        );
+    }
+
+    #[test]
+    fn test_row_key_defaults_to_index() {
+        let row = TestData {
+            id: 7,
+            name: "Test".to_string(),
+        };
+        assert_eq!(row_key(&None, &row, 3), AttrValue::from("3"));
+    }
+
+    #[test]
+    fn test_align_class() {
+        assert_eq!(align_class(Alignment::Start), "data-table-align-start");
+        assert_eq!(align_class(Alignment::End), "data-table-align-end");
     }
 
     #[test]
     fn test_sort_direction() {
         assert_eq!(SortDirection::Ascending, SortDirection::Ascending);
         assert_ne!(SortDirection::Ascending, SortDirection::Descending);
+        assert_eq!(SortDirection::Ascending.aria_sort(), "ascending");
+        assert_eq!(SortDirection::Descending.aria_sort(), "descending");
     }
 }
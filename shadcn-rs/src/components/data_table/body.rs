@@ -0,0 +1,580 @@
+//! Rows of the data table body: group headers, data rows, tree children
+//! and detail panels
+
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::column::{DataTableColumn, DataTableValue};
+use super::group::RowGroup;
+use super::row_key;
+
+/// A row of the table body
+///
+/// Body rows own their contents, so the body can be memoized across
+/// renders and only the mounted rows are rendered.
+pub(crate) enum BodyRow<T> {
+    /// Group header
+    Group {
+        /// The group
+        group: GroupHeader,
+        /// Whether the rows of the group are hidden
+        collapsed: bool,
+    },
+    /// Data row, or a child row of tree data
+    Data {
+        /// Row value
+        row: RowValue<T>,
+        /// Row id
+        id: AttrValue,
+        /// Nesting level below groups and tree parents
+        depth: usize,
+        /// 1-based position among the data rows of the body
+        number: usize,
+        /// Whether the row has a detail panel or children to show
+        expandable: bool,
+        /// Whether the detail panel and children are shown
+        expanded: bool,
+    },
+    /// Detail panel of an expanded row
+    Detail {
+        /// Row value
+        row: RowValue<T>,
+        /// Row id
+        id: AttrValue,
+    },
+}
+
+impl<T> BodyRow<T> {
+    /// Unique key of the row among the body rows
+    pub fn key(&self) -> AttrValue {
+        match self {
+            BodyRow::Group { group, .. } => AttrValue::from(format!("group:{}", group.key)),
+            BodyRow::Data { id, .. } => id.clone(),
+            BodyRow::Detail { id, .. } => AttrValue::from(format!("detail:{}", id)),
+        }
+    }
+}
+
+/// Value of a body row
+pub(crate) enum RowValue<T> {
+    /// Index into the table data
+    Index(usize),
+    /// Child row of tree data, shared with its detail panel
+    Child(Rc<T>),
+}
+
+impl<T> Clone for RowValue<T> {
+    fn clone(&self) -> Self {
+        match self {
+            RowValue::Index(index) => RowValue::Index(*index),
+            RowValue::Child(row) => RowValue::Child(row.clone()),
+        }
+    }
+}
+
+impl<T> RowValue<T> {
+    /// The row, looked up in `data` for data rows
+    pub fn get<'a>(&'a self, data: &'a [T]) -> &'a T {
+        match self {
+            RowValue::Index(index) => &data[*index],
+            RowValue::Child(row) => row,
+        }
+    }
+}
+
+/// Header of a row group, with its aggregates computed up front
+pub(crate) struct GroupHeader {
+    /// Unique key of the group (see [`RowGroup::key`])
+    pub key: AttrValue,
+    /// Index of the grouped column
+    pub column: usize,
+    /// Shared value of the grouped column
+    pub value: DataTableValue,
+    /// Nesting level, 0 for the outermost groups
+    pub depth: usize,
+    /// Number of rows of the group
+    pub count: usize,
+    /// Aggregate of each column over the rows of the group, by column index
+    pub aggregates: Vec<Option<DataTableValue>>,
+}
+
+impl GroupHeader {
+    fn new<T: Clone + PartialEq + 'static>(
+        group: &RowGroup,
+        data: &[T],
+        columns: &[DataTableColumn<T>],
+    ) -> Self {
+        Self {
+            key: group.key.clone(),
+            column: group.column,
+            value: group.value.clone(),
+            depth: group.depth,
+            count: group.rows.len(),
+            aggregates: columns
+                .iter()
+                .map(|column| column.aggregate_rows(data, &group.rows))
+                .collect(),
+        }
+    }
+}
+
+/// The rows of the table body, split into entries for pagination
+///
+/// An entry is a data row of the table or of a group, together with its
+/// detail panel and tree children, or the header of a collapsed group.
+/// Headers of open groups belong to the entry of their first row.
+pub(crate) struct Body<T> {
+    /// Rows in display order
+    pub rows: Vec<BodyRow<T>>,
+    /// Whether the rows are grouped
+    pub grouped: bool,
+    /// Entry of each row, ascending
+    entries: Vec<usize>,
+    /// Number of entries
+    entry_count: usize,
+}
+
+impl<T> Body<T> {
+    fn push(&mut self, row: BodyRow<T>) {
+        self.rows.push(row);
+        self.entries.push(self.entry_count);
+    }
+
+    /// Number of entries, the unit of pagination
+    pub fn entry_count(&self) -> usize {
+        self.entry_count
+    }
+
+    /// Positions of the rows of entries `start..end`
+    ///
+    /// The headers of the groups the first entry belongs to are repeated
+    /// at the top, so a group split across pages keeps its label and its
+    /// aggregates over all of its rows.
+    pub fn page(&self, start: usize, end: usize) -> Vec<usize> {
+        let first = self.entries.partition_point(|&entry| entry < start);
+        let last = self.entries.partition_point(|&entry| entry < end);
+        let mut level = match self.rows.get(first) {
+            Some(BodyRow::Group { group, .. }) => group.depth,
+            Some(BodyRow::Data { depth, .. }) => *depth,
+            _ => 0,
+        };
+        let mut positions = Vec::new();
+        for position in (0..first).rev() {
+            if level == 0 {
+                break;
+            }
+            if let BodyRow::Group { group, .. } = &self.rows[position]
+                && group.depth < level
+            {
+                positions.push(position);
+                level = group.depth;
+            }
+        }
+        positions.reverse();
+        positions.extend(first..last);
+        positions
+    }
+}
+
+/// What to expand while building the body rows
+pub(crate) struct BodyOptions<'a, T> {
+    /// Row id accessor (row ids default to the row index)
+    pub row_id: &'a Option<Callback<T, AttrValue>>,
+    /// Child rows accessor for tree data
+    pub children: Option<&'a Callback<T, Vec<T>>>,
+    /// Cheap check whether a row has children, so `children` only runs for
+    /// expanded rows
+    pub has_children: Option<&'a Callback<T, bool>>,
+    /// Whether rows have a detail panel
+    pub detail: bool,
+    /// Ids of the expanded rows
+    pub expanded: &'a [AttrValue],
+    /// Keys of the collapsed groups
+    pub collapsed_groups: &'a [AttrValue],
+}
+
+/// Flatten groups, rows, expanded detail panels and tree children into the
+/// rows of the table body
+///
+/// `rows` are indices into `data` in display order, used when there are no
+/// `groups`. Child rows without a `row_id` accessor get the id of their
+/// parent followed by `.` and their position.
+pub(crate) fn body_rows<T: Clone + PartialEq + 'static>(
+    data: &[T],
+    rows: &[usize],
+    groups: &[RowGroup],
+    columns: &[DataTableColumn<T>],
+    options: &BodyOptions<'_, T>,
+) -> Body<T> {
+    let mut body = Body {
+        rows: Vec::new(),
+        grouped: !groups.is_empty(),
+        entries: Vec::new(),
+        entry_count: 0,
+    };
+    if groups.is_empty() {
+        for &index in rows {
+            push_data_row(&mut body, data, index, 0, options);
+        }
+    } else {
+        push_groups(&mut body, data, groups, columns, options);
+    }
+    body
+}
+
+fn push_groups<T: Clone + PartialEq + 'static>(
+    body: &mut Body<T>,
+    data: &[T],
+    groups: &[RowGroup],
+    columns: &[DataTableColumn<T>],
+    options: &BodyOptions<'_, T>,
+) {
+    for group in groups {
+        let collapsed = options.collapsed_groups.contains(&group.key);
+        body.push(BodyRow::Group {
+            group: GroupHeader::new(group, data, columns),
+            collapsed,
+        });
+        if collapsed {
+            body.entry_count += 1;
+            continue;
+        }
+        if group.subgroups.is_empty() {
+            for &index in &group.rows {
+                push_data_row(body, data, index, group.depth + 1, options);
+            }
+        } else {
+            push_groups(body, data, &group.subgroups, columns, options);
+        }
+    }
+}
+
+fn push_data_row<T: Clone + PartialEq + 'static>(
+    body: &mut Body<T>,
+    data: &[T],
+    index: usize,
+    depth: usize,
+    options: &BodyOptions<'_, T>,
+) {
+    let id = row_key(options.row_id, &data[index], index);
+    push_row(body, data, RowValue::Index(index), id, depth, options);
+    body.entry_count += 1;
+}
+
+fn push_row<T: Clone + PartialEq + 'static>(
+    body: &mut Body<T>,
+    data: &[T],
+    row: RowValue<T>,
+    id: AttrValue,
+    depth: usize,
+    options: &BodyOptions<'_, T>,
+) {
+    // Without a `has_children` check the children decide whether the row expands
+    let mut children = None;
+    let expandable = options.detail
+        || match (options.children, options.has_children) {
+            (None, _) => false,
+            (Some(_), Some(has_children)) => has_children.emit(row.get(data).clone()),
+            (Some(row_children), None) => {
+                let rows = row_children.emit(row.get(data).clone());
+                let has_children = !rows.is_empty();
+                children = Some(rows);
+                has_children
+            }
+        };
+    let expanded = expandable && options.expanded.contains(&id);
+    let number = body
+        .rows
+        .iter()
+        .rev()
+        .find_map(|row| match row {
+            BodyRow::Data { number, .. } => Some(*number),
+            _ => None,
+        })
+        .unwrap_or(0)
+        + 1;
+    body.push(BodyRow::Data {
+        row: row.clone(),
+        id: id.clone(),
+        depth,
+        number,
+        expandable,
+        expanded,
+    });
+    if !expanded {
+        return;
+    }
+    let children = children
+        .or_else(|| {
+            options
+                .children
+                .map(|children| children.emit(row.get(data).clone()))
+        })
+        .unwrap_or_default();
+    if options.detail {
+        body.push(BodyRow::Detail {
+            row,
+            id: id.clone(),
+        });
+    }
+    for (position, child) in children.into_iter().enumerate() {
+        let child_id = match options.row_id {
+            Some(row_id) => row_id.emit(child.clone()),
+            None => AttrValue::from(format!("{}.{}", id, position)),
+        };
+        push_row(
+            body,
+            data,
+            RowValue::Child(Rc::new(child)),
+            child_id,
+            depth + 1,
+            options,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::column::DataTableColumn;
+    use super::super::group::{DataTableAggregate, group_rows};
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Clone, PartialEq)]
+    struct Node {
+        name: &'static str,
+        children: Vec<Node>,
+    }
+
+    fn leaf(name: &'static str) -> Node {
+        Node {
+            name,
+            children: Vec::new(),
+        }
+    }
+
+    fn summary<T>(body: &[BodyRow<T>]) -> Vec<String> {
+        body.iter()
+            .map(|row| match row {
+                BodyRow::Group { group, collapsed } => format!("{} {}", group.key, collapsed),
+                BodyRow::Data {
+                    id,
+                    depth,
+                    expanded,
+                    ..
+                } => format!("{} {} {}", id, depth, expanded),
+                BodyRow::Detail { id, .. } => format!("detail {}", id),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_body_rows_plain() {
+        let data = vec![leaf("a"), leaf("b")];
+        let options = BodyOptions {
+            row_id: &None,
+            children: None,
+            has_children: None,
+            detail: false,
+            expanded: &[],
+            collapsed_groups: &[],
+        };
+        let body = body_rows(&data, &[1, 0], &[], &[], &options);
+        assert_eq!(summary(&body.rows), vec!["1 0 false", "0 0 false"]);
+        assert!(matches!(body.rows[1], BodyRow::Data { number: 2, .. }));
+    }
+
+    #[test]
+    fn test_body_rows_tree_and_detail() {
+        let data = vec![Node {
+            name: "root",
+            children: vec![
+                Node {
+                    name: "child",
+                    children: vec![leaf("grandchild")],
+                },
+                leaf("other"),
+            ],
+        }];
+        let row_id = Some(Callback::from(|node: Node| AttrValue::from(node.name)));
+        let children = Callback::from(|node: Node| node.children);
+        let expanded = vec![AttrValue::from("root"), AttrValue::from("grandchild")];
+        let options = BodyOptions {
+            row_id: &row_id,
+            children: Some(&children),
+            has_children: None,
+            detail: true,
+            expanded: &expanded,
+            collapsed_groups: &[],
+        };
+        let body = body_rows(&data, &[0], &[], &[], &options);
+        assert_eq!(
+            summary(&body.rows),
+            vec![
+                "root 0 true",
+                "detail root",
+                "child 1 false",
+                "other 1 false"
+            ]
+        );
+        assert!(matches!(
+            body.rows[2],
+            BodyRow::Data {
+                expandable: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_body_rows_child_ids() {
+        let data = vec![Node {
+            name: "root",
+            children: vec![leaf("a"), leaf("b")],
+        }];
+        let children = Callback::from(|node: Node| node.children);
+        let expanded = vec![AttrValue::from("0")];
+        let options = BodyOptions {
+            row_id: &None,
+            children: Some(&children),
+            has_children: None,
+            detail: false,
+            expanded: &expanded,
+            collapsed_groups: &[],
+        };
+        let body = body_rows(&data, &[0], &[], &[], &options);
+        assert_eq!(
+            summary(&body.rows),
+            vec!["0 0 true", "0.0 1 false", "0.1 1 false"]
+        );
+    }
+
+    #[test]
+    fn test_body_rows_has_children() {
+        let data = vec![
+            Node {
+                name: "root",
+                children: vec![leaf("a")],
+            },
+            leaf("leaf"),
+        ];
+        let row_id = Some(Callback::from(|node: Node| AttrValue::from(node.name)));
+        let calls = Rc::new(Cell::new(0));
+        let children = {
+            let calls = calls.clone();
+            Callback::from(move |node: Node| {
+                calls.set(calls.get() + 1);
+                node.children
+            })
+        };
+        let has_children = Callback::from(|node: Node| !node.children.is_empty());
+        let options = BodyOptions {
+            row_id: &row_id,
+            children: Some(&children),
+            has_children: Some(&has_children),
+            detail: false,
+            expanded: &[],
+            collapsed_groups: &[],
+        };
+        let body = body_rows(&data, &[0, 1], &[], &[], &options);
+        assert_eq!(summary(&body.rows), vec!["root 0 false", "leaf 0 false"]);
+        assert!(matches!(
+            body.rows[0],
+            BodyRow::Data {
+                expandable: true,
+                ..
+            }
+        ));
+        assert!(matches!(
+            body.rows[1],
+            BodyRow::Data {
+                expandable: false,
+                ..
+            }
+        ));
+        assert_eq!(calls.get(), 0);
+
+        let expanded = vec![AttrValue::from("root")];
+        let options = BodyOptions {
+            expanded: &expanded,
+            ..options
+        };
+        let body = body_rows(&data, &[0, 1], &[], &[], &options);
+        assert_eq!(
+            summary(&body.rows),
+            vec!["root 0 true", "a 1 false", "leaf 0 false"]
+        );
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_body_rows_groups() {
+        let data = vec![leaf("a"), leaf("b"), leaf("a")];
+        let columns = vec![
+            DataTableColumn::new("name", "Name", |n: Node| n.name)
+                .aggregate(DataTableAggregate::Count),
+        ];
+        let groups = group_rows(&data, &[0, 1, 2], &columns, &["name".into()]);
+        let collapsed = vec![AttrValue::from("name:b")];
+        let options = BodyOptions {
+            row_id: &None,
+            children: None,
+            has_children: None,
+            detail: false,
+            expanded: &[],
+            collapsed_groups: &collapsed,
+        };
+        let body = body_rows(&data, &[0, 1, 2], &groups, &columns, &options);
+        assert_eq!(
+            summary(&body.rows),
+            vec!["name:a false", "0 1 false", "2 1 false", "name:b true"]
+        );
+        assert_eq!(body.rows[0].key(), AttrValue::from("group:name:a"));
+        assert!(matches!(
+            &body.rows[0],
+            BodyRow::Group { group, .. }
+                if group.count == 2 && group.aggregates == vec![Some(DataTableValue::from(2))]
+        ));
+    }
+
+    #[test]
+    fn test_body_page() {
+        let data = vec![leaf("a"), leaf("a"), leaf("a"), leaf("b"), leaf("c")];
+        let columns = vec![DataTableColumn::new("name", "Name", |n: Node| n.name)];
+        let groups = group_rows(&data, &[0, 1, 2, 3, 4], &columns, &["name".into()]);
+        let collapsed = vec![AttrValue::from("name:c")];
+        let expanded = vec![AttrValue::from("2")];
+        let options = BodyOptions {
+            row_id: &None,
+            children: None,
+            has_children: None,
+            detail: true,
+            expanded: &expanded,
+            collapsed_groups: &collapsed,
+        };
+        let body = body_rows(&data, &[0, 1, 2, 3, 4], &groups, &columns, &options);
+        assert_eq!(body.entry_count(), 5);
+
+        // The group split across pages repeats its header; the detail panel
+        // stays with its row
+        let page: Vec<String> = body
+            .page(2, 4)
+            .into_iter()
+            .map(|position| body.rows[position].key().to_string())
+            .collect();
+        assert_eq!(
+            page,
+            vec!["group:name:a", "2", "detail:2", "group:name:b", "3"]
+        );
+        assert!(matches!(
+            &body.rows[body.page(2, 4)[0]],
+            BodyRow::Group { group, .. } if group.count == 3
+        ));
+
+        // A collapsed group is a single entry
+        assert_eq!(body.page(4, 5), vec![body.rows.len() - 1]);
+        assert!(body.page(5, 10).is_empty());
+    }
+}
@@ -0,0 +1,422 @@
+//! Column definitions for the data table
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::types::Alignment;
+use yew::prelude::*;
+
+use super::edit::DataTableEditor;
+use super::filter::{DataTableFilter, DataTableFilterValue, matches_filter};
+use super::group::DataTableAggregate;
+
+/// A typed cell value produced by a column accessor
+///
+/// Values are used for rendering (via `Display`) and by the table's
+/// built-in behaviours such as sorting and filtering.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum DataTableValue {
+    /// Text value
+    Text(AttrValue),
+    /// Numeric value
+    Number(f64),
+    /// Boolean value
+    Bool(bool),
+    /// Missing value
+    #[default]
+    Empty,
+}
+
+impl DataTableValue {
+    /// Returns `true` if the value is [`DataTableValue::Empty`]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, DataTableValue::Empty)
+    }
+
+    /// Returns the numeric value, if any
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            DataTableValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DataTableValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataTableValue::Text(s) => f.write_str(s),
+            DataTableValue::Number(n) => write!(f, "{}", n),
+            DataTableValue::Bool(b) => write!(f, "{}", b),
+            DataTableValue::Empty => Ok(()),
+        }
+    }
+}
+
+impl From<AttrValue> for DataTableValue {
+    fn from(value: AttrValue) -> Self {
+        DataTableValue::Text(value)
+    }
+}
+
+impl From<String> for DataTableValue {
+    fn from(value: String) -> Self {
+        DataTableValue::Text(AttrValue::from(value))
+    }
+}
+
+impl From<&'static str> for DataTableValue {
+    fn from(value: &'static str) -> Self {
+        DataTableValue::Text(AttrValue::from(value))
+    }
+}
+
+impl From<bool> for DataTableValue {
+    fn from(value: bool) -> Self {
+        DataTableValue::Bool(value)
+    }
+}
+
+macro_rules! impl_number_value {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for DataTableValue {
+                fn from(value: $ty) -> Self {
+                    DataTableValue::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_value!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64, usize, isize);
+
+impl<V: Into<DataTableValue>> From<Option<V>> for DataTableValue {
+    fn from(value: Option<V>) -> Self {
+        value.map(Into::into).unwrap_or_default()
+    }
+}
+
+/// Column definition for [`DataTable`](super::DataTable)
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use shadcn_rs::{Alignment, DataTableColumn};
+///
+/// let columns = vec![
+///     DataTableColumn::new("name", "Name", |u: User| u.name),
+///     DataTableColumn::new("amount", "Amount", |u: User| u.amount)
+///         .align(Alignment::End)
+///         .width("120px"),
+/// ];
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct DataTableColumn<T: Clone + PartialEq + 'static> {
+    /// Unique column identifier
+    pub id: AttrValue,
+
+    /// Header content
+    pub header: Html,
+
+    /// Extracts the cell value from a row
+    pub accessor: Callback<T, DataTableValue>,
+
+    /// Custom cell renderer (defaults to the accessor value as text)
+    pub cell: Option<Callback<T, Html>>,
+
+    /// Column width (any CSS length)
+    ///
+    /// Pinned columns need a `px` width; any other length is replaced by a
+    /// fixed width while the column is pinned.
+    pub width: Option<AttrValue>,
+
+    /// Horizontal alignment of header and cells
+    pub align: Alignment,
+
+    /// Whether the column can be sorted (when sorting is enabled on the table)
+    pub sortable: bool,
+
+    /// Custom comparator used instead of comparing accessor values
+    pub comparator: Option<Callback<(T, T), Ordering>>,
+
+    /// Filter input rendered for this column (when filtering is enabled on the table)
+    pub filter: Option<DataTableFilter>,
+
+    /// Custom filter function used instead of the built-in matching
+    pub filter_fn: Option<Callback<(T, DataTableFilterValue), bool>>,
+
+    /// Whether the column is listed in the column visibility menu
+    pub hideable: bool,
+
+    /// Editor for the cells of this column (when editing is enabled on the table)
+    pub editor: Option<DataTableEditor>,
+
+    /// Validates an edited value, returning the error message if it is invalid
+    pub validator: Option<Callback<DataTableValue, Result<(), AttrValue>>>,
+
+    /// Aggregate shown in group header rows (when the table is grouped)
+    pub aggregate: Option<DataTableAggregate>,
+}
+
+impl<T: Clone + PartialEq + 'static> DataTableColumn<T> {
+    /// Create a column from an id, header and value accessor
+    pub fn new<V, F>(id: impl Into<AttrValue>, header: impl Into<Html>, accessor: F) -> Self
+    where
+        V: Into<DataTableValue>,
+        F: Fn(T) -> V + 'static,
+    {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            accessor: Callback::from(move |row: T| accessor(row).into()),
+            cell: None,
+            width: None,
+            align: Alignment::Start,
+            sortable: true,
+            comparator: None,
+            filter: None,
+            filter_fn: None,
+            hideable: true,
+            editor: None,
+            validator: None,
+            aggregate: None,
+        }
+    }
+
+    /// Set a custom cell renderer
+    pub fn cell<F: Fn(T) -> Html + 'static>(mut self, cell: F) -> Self {
+        self.cell = Some(Callback::from(cell));
+        self
+    }
+
+    /// Set the column width
+    pub fn width(mut self, width: impl Into<AttrValue>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Set the column alignment
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Enable or disable sorting for this column
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Set a custom comparator for sorting
+    pub fn comparator<F: Fn(T, T) -> Ordering + 'static>(mut self, comparator: F) -> Self {
+        self.comparator = Some(Callback::from(move |(a, b): (T, T)| comparator(a, b)));
+        self
+    }
+
+    /// Compare two rows by this column, ascending
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        match &self.comparator {
+            Some(comparator) => comparator.emit((a.clone(), b.clone())),
+            None => super::sort::compare_values(&self.value(a), &self.value(b)),
+        }
+    }
+
+    /// Set the filter kind for this column
+    pub fn filter(mut self, filter: DataTableFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Set a custom filter function
+    pub fn filter_fn<F: Fn(T, DataTableFilterValue) -> bool + 'static>(
+        mut self,
+        filter_fn: F,
+    ) -> Self {
+        self.filter_fn = Some(Callback::from(
+            move |(row, value): (T, DataTableFilterValue)| filter_fn(row, value),
+        ));
+        self
+    }
+
+    /// Allow or prevent hiding the column from the column visibility menu
+    pub fn hideable(mut self, hideable: bool) -> Self {
+        self.hideable = hideable;
+        self
+    }
+
+    /// Make the cells of this column editable with the given editor
+    pub fn editor(mut self, editor: DataTableEditor) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    /// Set a validator for edited values
+    pub fn validator<F, E>(mut self, validator: F) -> Self
+    where
+        F: Fn(DataTableValue) -> Result<(), E> + 'static,
+        E: Into<AttrValue>,
+    {
+        self.validator = Some(Callback::from(move |value: DataTableValue| {
+            validator(value).map_err(Into::into)
+        }));
+        self
+    }
+
+    /// Parse and validate an editor draft of this column
+    pub fn parse_edit(&self, draft: &str) -> Result<DataTableValue, AttrValue> {
+        let value = match &self.editor {
+            Some(editor) => editor.parse(draft)?,
+            None => DataTableValue::from(draft.to_string()),
+        };
+        match &self.validator {
+            Some(validator) => validator.emit(value.clone()).map(|()| value),
+            None => Ok(value),
+        }
+    }
+
+    /// Set the aggregate shown in group header rows
+    pub fn aggregate(mut self, aggregate: DataTableAggregate) -> Self {
+        self.aggregate = Some(aggregate);
+        self
+    }
+
+    /// Aggregate the values of this column over the given rows, if it has an aggregate
+    pub fn aggregate_rows(&self, data: &[T], rows: &[usize]) -> Option<DataTableValue> {
+        let aggregate = self.aggregate.as_ref()?;
+        Some(aggregate.apply(rows.iter().map(|&row| self.value(&data[row])).collect()))
+    }
+
+    /// Check whether a row matches a filter value of this column
+    pub fn matches(&self, row: &T, value: &DataTableFilterValue) -> bool {
+        match &self.filter_fn {
+            Some(filter_fn) => filter_fn.emit((row.clone(), value.clone())),
+            None => matches_filter(&self.value(row), value, self.filter.as_ref()),
+        }
+    }
+
+    /// Get the value of this column for a row
+    pub fn value(&self, row: &T) -> DataTableValue {
+        self.accessor.emit(row.clone())
+    }
+
+    /// Render the cell content of this column for a row
+    pub fn render_cell(&self, row: &T) -> Html {
+        match &self.cell {
+            Some(cell) => cell.emit(row.clone()),
+            None => html! { { self.value(row).to_string() } },
+        }
+    }
+
+    /// Plain text of the header, or the column id if the header is not plain text
+    pub fn header_text(&self) -> String {
+        match &self.header {
+            Html::VText(text) => text.text.to_string(),
+            _ => self.id.to_string(),
+        }
+    }
+
+    /// Inline style for header and cells
+    pub(crate) fn style(&self) -> Option<AttrValue> {
+        self.width
+            .as_ref()
+            .map(|width| AttrValue::from(format!("width: {};", width)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq)]
+    struct Row {
+        name: String,
+        amount: u32,
+        note: Option<String>,
+    }
+
+    fn row() -> Row {
+        Row {
+            name: "Ada".to_string(),
+            amount: 42,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_column_accessor() {
+        let name = DataTableColumn::new("name", "Name", |r: Row| r.name);
+        let amount = DataTableColumn::new("amount", "Amount", |r: Row| r.amount);
+        let note = DataTableColumn::new("note", "Note", |r: Row| r.note);
+
+        assert_eq!(name.value(&row()), DataTableValue::from("Ada"));
+        assert_eq!(amount.value(&row()), DataTableValue::Number(42.0));
+        assert_eq!(note.value(&row()), DataTableValue::Empty);
+    }
+
+    #[test]
+    fn test_column_builder() {
+        let column = DataTableColumn::new("amount", "Amount", |r: Row| r.amount)
+            .width("120px")
+            .align(Alignment::End);
+
+        assert_eq!(column.id, AttrValue::from("amount"));
+        assert_eq!(column.width, Some(AttrValue::from("120px")));
+        assert_eq!(column.align, Alignment::End);
+        assert_eq!(column.style(), Some(AttrValue::from("width: 120px;")));
+        assert_eq!(column.header_text(), "Amount");
+        assert!(column.hideable);
+        assert!(!column.hideable(false).hideable);
+    }
+
+    #[test]
+    fn test_column_parse_edit() {
+        let column = DataTableColumn::new("amount", "Amount", |r: Row| r.amount)
+            .editor(DataTableEditor::Number)
+            .validator(|value: DataTableValue| match value.as_number() {
+                Some(n) if n < 0.0 => Err("Must not be negative"),
+                _ => Ok(()),
+            });
+
+        assert_eq!(column.parse_edit("12"), Ok(DataTableValue::Number(12.0)));
+        assert_eq!(
+            column.parse_edit("-1"),
+            Err(AttrValue::from("Must not be negative"))
+        );
+        assert_eq!(
+            column.parse_edit("abc"),
+            Err(AttrValue::from("Enter a number"))
+        );
+
+        let name = DataTableColumn::new("name", "Name", |r: Row| r.name);
+        assert!(name.editor.is_none());
+        assert_eq!(name.parse_edit("Ada"), Ok(DataTableValue::from("Ada")));
+    }
+
+    #[test]
+    fn test_column_aggregate_rows() {
+        let data = vec![row(), Row { amount: 8, ..row() }];
+        let amount = DataTableColumn::new("amount", "Amount", |r: Row| r.amount)
+            .aggregate(DataTableAggregate::Sum);
+        let name = DataTableColumn::new("name", "Name", |r: Row| r.name);
+
+        assert_eq!(
+            amount.aggregate_rows(&data, &[0, 1]),
+            Some(DataTableValue::Number(50.0))
+        );
+        assert_eq!(
+            amount.aggregate_rows(&data, &[1]),
+            Some(DataTableValue::Number(8.0))
+        );
+        assert_eq!(name.aggregate_rows(&data, &[0, 1]), None);
+    }
+
+    #[test]
+    fn test_value_display() {
+        assert_eq!(DataTableValue::from("text").to_string(), "text");
+        assert_eq!(DataTableValue::from(1.5).to_string(), "1.5");
+        assert_eq!(DataTableValue::from(3_i32).to_string(), "3");
+        assert_eq!(DataTableValue::from(true).to_string(), "true");
+        assert_eq!(DataTableValue::Empty.to_string(), "");
+    }
+}
@@ -0,0 +1,249 @@
+//! Inline cell editing for the data table
+//!
+//! The table never changes its data: a committed edit is reported through
+//! `on_cell_edit` and the new value shows up once the parent updates `data`.
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+use super::column::DataTableValue;
+
+/// Editor rendered in the cells of an editable column
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataTableEditor {
+    /// Text input, commits a text value
+    Text,
+    /// Number input, commits a numeric value
+    Number,
+    /// Native select of the given options, commits the selected option
+    Select(Vec<AttrValue>),
+    /// Checkbox, commits a boolean as soon as it is toggled
+    Checkbox,
+    /// Date picker, commits an ISO date (`YYYY-MM-DD`)
+    Date,
+}
+
+impl DataTableEditor {
+    /// Draft text of the editor for a cell value
+    pub fn draft(&self, value: &DataTableValue) -> String {
+        match (self, value) {
+            (DataTableEditor::Checkbox, DataTableValue::Bool(checked)) => checked.to_string(),
+            (DataTableEditor::Checkbox, _) => false.to_string(),
+            (_, value) => value.to_string(),
+        }
+    }
+
+    /// Parse an editor draft into a cell value
+    pub fn parse(&self, draft: &str) -> Result<DataTableValue, AttrValue> {
+        match self {
+            DataTableEditor::Text => Ok(DataTableValue::from(draft.to_string())),
+            DataTableEditor::Checkbox => Ok(DataTableValue::Bool(draft == "true")),
+            _ if draft.trim().is_empty() => Ok(DataTableValue::Empty),
+            DataTableEditor::Number => draft
+                .trim()
+                .parse::<f64>()
+                .map(DataTableValue::Number)
+                .map_err(|_| AttrValue::from("Enter a number")),
+            DataTableEditor::Select(_) | DataTableEditor::Date => {
+                Ok(DataTableValue::from(draft.trim().to_string()))
+            }
+        }
+    }
+}
+
+/// A committed cell edit, reported by `on_cell_edit`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTableCellEdit {
+    /// Id of the edited row (see the `row_id` prop)
+    pub row_id: AttrValue,
+    /// Id of the edited column
+    pub column_id: AttrValue,
+    /// New cell value
+    pub value: DataTableValue,
+}
+
+/// The cell currently being edited
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EditingCell {
+    pub row_id: AttrValue,
+    pub column_id: AttrValue,
+    /// Edited text, `None` until the user changes the cell value
+    pub draft: Option<String>,
+    /// Validation error of the last commit attempt
+    pub error: Option<AttrValue>,
+}
+
+impl EditingCell {
+    /// Start editing a cell
+    pub fn new(row_id: AttrValue, column_id: AttrValue) -> Self {
+        Self {
+            row_id,
+            column_id,
+            draft: None,
+            error: None,
+        }
+    }
+
+    /// Returns `true` if this is the given cell
+    pub fn is(&self, row_id: &str, column_id: &str) -> bool {
+        self.row_id == row_id && self.column_id == column_id
+    }
+}
+
+/// The editable cell after (or with `backwards`, before) a cell, in reading order
+///
+/// `rows` are the displayed row ids and `columns` the ids of the editable
+/// columns in display order.
+pub fn next_editable_cell(
+    rows: &[AttrValue],
+    columns: &[AttrValue],
+    row_id: &AttrValue,
+    column_id: &AttrValue,
+    backwards: bool,
+) -> Option<(AttrValue, AttrValue)> {
+    let row = rows.iter().position(|id| id == row_id)?;
+    let column = columns.iter().position(|id| id == column_id)?;
+    let position = row * columns.len() + column;
+    let next = if backwards {
+        position.checked_sub(1)?
+    } else {
+        position + 1
+    };
+    let row = rows.get(next / columns.len())?;
+    Some((row.clone(), columns[next % columns.len()].clone()))
+}
+
+/// Focus the mounted cell of a row and column in a `<tbody>`
+///
+/// Cells that are not focusable themselves (such as checkbox cells) focus
+/// their first input instead.
+pub(crate) fn focus_cell(body_ref: &NodeRef, row_id: &str, column_id: &str) {
+    let Some(body) = body_ref.cast::<Element>() else {
+        return;
+    };
+    let rows = body.children();
+    let Some(row) = (0..rows.length())
+        .filter_map(|index| rows.item(index))
+        .find(|row| row.get_attribute("data-row-id").as_deref() == Some(row_id))
+    else {
+        return;
+    };
+    let cells = row.children();
+    let Some(cell) = (0..cells.length())
+        .filter_map(|index| cells.item(index))
+        .find(|cell| cell.get_attribute("data-column-id").as_deref() == Some(column_id))
+    else {
+        return;
+    };
+    let target = if cell.has_attribute("tabindex") {
+        Some(cell)
+    } else {
+        cell.query_selector("input").ok().flatten()
+    };
+    if let Some(target) = target.and_then(|target| target.dyn_into::<HtmlElement>().ok()) {
+        let _ = target.focus();
+    }
+}
+
+/// Focus the first control of the cell editor in a `<tbody>`
+pub(crate) fn focus_editor(body_ref: &NodeRef) {
+    let editor = body_ref.cast::<Element>().and_then(|body| {
+        body.query_selector(
+            ".data-table-editor input, .data-table-editor select, .data-table-editor button",
+        )
+        .ok()
+        .flatten()
+    });
+    if let Some(editor) = editor.and_then(|editor| editor.dyn_into::<HtmlElement>().ok()) {
+        let _ = editor.focus();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&'static str]) -> Vec<AttrValue> {
+        values.iter().map(|v| AttrValue::from(*v)).collect()
+    }
+
+    #[test]
+    fn test_editor_draft() {
+        let value = DataTableValue::from(12.5);
+        assert_eq!(DataTableEditor::Number.draft(&value), "12.5");
+        assert_eq!(DataTableEditor::Text.draft(&DataTableValue::Empty), "");
+        assert_eq!(
+            DataTableEditor::Checkbox.draft(&DataTableValue::Bool(true)),
+            "true"
+        );
+        assert_eq!(
+            DataTableEditor::Checkbox.draft(&DataTableValue::Empty),
+            "false"
+        );
+    }
+
+    #[test]
+    fn test_editor_parse() {
+        assert_eq!(
+            DataTableEditor::Text.parse(" Ada "),
+            Ok(DataTableValue::from(" Ada "))
+        );
+        assert_eq!(
+            DataTableEditor::Number.parse(" 42 "),
+            Ok(DataTableValue::Number(42.0))
+        );
+        assert_eq!(DataTableEditor::Number.parse(""), Ok(DataTableValue::Empty));
+        assert_eq!(
+            DataTableEditor::Number.parse("4x2"),
+            Err(AttrValue::from("Enter a number"))
+        );
+        assert_eq!(
+            DataTableEditor::Checkbox.parse("true"),
+            Ok(DataTableValue::Bool(true))
+        );
+        assert_eq!(
+            DataTableEditor::Date.parse("2024-01-15"),
+            Ok(DataTableValue::from("2024-01-15"))
+        );
+    }
+
+    #[test]
+    fn test_next_editable_cell() {
+        let rows = ids(&["r1", "r2"]);
+        let columns = ids(&["a", "b"]);
+
+        assert_eq!(
+            next_editable_cell(&rows, &columns, &"r1".into(), &"a".into(), false),
+            Some(("r1".into(), "b".into()))
+        );
+        assert_eq!(
+            next_editable_cell(&rows, &columns, &"r1".into(), &"b".into(), false),
+            Some(("r2".into(), "a".into()))
+        );
+        assert_eq!(
+            next_editable_cell(&rows, &columns, &"r2".into(), &"a".into(), true),
+            Some(("r1".into(), "b".into()))
+        );
+        assert_eq!(
+            next_editable_cell(&rows, &columns, &"r2".into(), &"b".into(), false),
+            None
+        );
+        assert_eq!(
+            next_editable_cell(&rows, &columns, &"r1".into(), &"a".into(), true),
+            None
+        );
+        assert_eq!(
+            next_editable_cell(&rows, &columns, &"r9".into(), &"a".into(), false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_editing_cell() {
+        let cell = EditingCell::new("r1".into(), "a".into());
+        assert!(cell.is("r1", "a"));
+        assert!(!cell.is("r1", "b"));
+        assert!(cell.draft.is_none());
+    }
+}
@@ -0,0 +1,322 @@
+//! Export of data table rows as CSV, TSV or JSON, and copying rows to the clipboard
+
+use gloo::file::{Blob, ObjectUrl};
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use super::column::DataTableColumn;
+use super::layout::{DataTableState, layout_columns};
+
+/// File format of a data table export
+///
+/// The enum is non-exhaustive: `Json` only exists with the `serde`
+/// feature, and enabling it must not break matches in other crates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum DataTableExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// Tab-separated values with a header row, as pasted into spreadsheets
+    Tsv,
+    /// Array of objects keyed by column id in display order (requires the
+    /// `serde` feature)
+    #[cfg(feature = "serde")]
+    Json,
+}
+
+impl DataTableExportFormat {
+    /// Formats offered by the export menu
+    pub fn all() -> Vec<Self> {
+        vec![
+            DataTableExportFormat::Csv,
+            DataTableExportFormat::Tsv,
+            #[cfg(feature = "serde")]
+            DataTableExportFormat::Json,
+        ]
+    }
+
+    /// Display name of the format
+    pub fn label(&self) -> &'static str {
+        match self {
+            DataTableExportFormat::Csv => "CSV",
+            DataTableExportFormat::Tsv => "TSV",
+            #[cfg(feature = "serde")]
+            DataTableExportFormat::Json => "JSON",
+        }
+    }
+
+    /// File extension of the format, without the dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DataTableExportFormat::Csv => "csv",
+            DataTableExportFormat::Tsv => "tsv",
+            #[cfg(feature = "serde")]
+            DataTableExportFormat::Json => "json",
+        }
+    }
+
+    /// MIME type of the format
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            DataTableExportFormat::Csv => "text/csv",
+            DataTableExportFormat::Tsv => "text/tab-separated-values",
+            #[cfg(feature = "serde")]
+            DataTableExportFormat::Json => "application/json",
+        }
+    }
+}
+
+/// An export requested from the table toolbar, reported by `on_export`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTableExport {
+    /// Format of the content
+    pub format: DataTableExportFormat,
+    /// File name, including the extension
+    pub filename: AttrValue,
+    /// Exported text
+    pub content: String,
+}
+
+/// Export rows as text in the given format
+///
+/// Rows are exported in the given order. The exported columns are the
+/// visible columns of `column_state`, in display order; their values come
+/// from the column accessors, so custom cell renderers do not apply.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use shadcn_rs::components::data_table::export_rows;
+/// use shadcn_rs::{DataTableExportFormat, DataTableState};
+///
+/// let csv = export_rows(&users, &columns, &DataTableState::default(), DataTableExportFormat::Csv);
+/// ```
+pub fn export_rows<'a, T: Clone + PartialEq + 'static>(
+    rows: impl IntoIterator<Item = &'a T>,
+    columns: &[DataTableColumn<T>],
+    column_state: &DataTableState,
+    format: DataTableExportFormat,
+) -> String {
+    let columns: Vec<&DataTableColumn<T>> = layout_columns(columns, column_state)
+        .iter()
+        .map(|layout| &columns[layout.index])
+        .collect();
+    match format {
+        DataTableExportFormat::Csv => delimited(rows, &columns, ",", "\r\n", csv_field),
+        DataTableExportFormat::Tsv => delimited(rows, &columns, "\t", "\n", tsv_field),
+        #[cfg(feature = "serde")]
+        DataTableExportFormat::Json => json(rows, &columns),
+    }
+}
+
+fn delimited<'a, T: Clone + PartialEq + 'static>(
+    rows: impl IntoIterator<Item = &'a T>,
+    columns: &[&DataTableColumn<T>],
+    separator: &str,
+    line_end: &str,
+    field: fn(&str) -> String,
+) -> String {
+    let header = columns
+        .iter()
+        .map(|column| field(&column.header_text()))
+        .collect::<Vec<_>>()
+        .join(separator);
+    let lines = rows.into_iter().map(|row| {
+        columns
+            .iter()
+            .map(|column| field(&column.value(row).to_string()))
+            .collect::<Vec<_>>()
+            .join(separator)
+    });
+    std::iter::once(header)
+        .chain(lines)
+        .map(|line| line + line_end)
+        .collect()
+}
+
+/// Quote a CSV field if it contains a comma, quote or line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Replace the tabs and line breaks of a TSV field, which has no quoting
+fn tsv_field(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+#[cfg(feature = "serde")]
+fn json<'a, T: Clone + PartialEq + 'static>(
+    rows: impl IntoIterator<Item = &'a T>,
+    columns: &[&DataTableColumn<T>],
+) -> String {
+    use super::column::DataTableValue;
+    use serde_json::{Map, Number, Value};
+
+    let rows: Vec<Value> = rows
+        .into_iter()
+        .map(|row| {
+            let fields: Map<String, Value> = columns
+                .iter()
+                .map(|column| {
+                    let value = match column.value(row) {
+                        DataTableValue::Text(text) => Value::String(text.to_string()),
+                        DataTableValue::Number(n) => {
+                            Number::from_f64(n).map_or(Value::Null, Value::Number)
+                        }
+                        DataTableValue::Bool(b) => Value::Bool(b),
+                        DataTableValue::Empty => Value::Null,
+                    };
+                    (column.id.to_string(), value)
+                })
+                .collect();
+            Value::Object(fields)
+        })
+        .collect();
+    serde_json::to_string_pretty(&rows).unwrap_or_default()
+}
+
+/// Offer text as a file download
+pub(crate) fn download(filename: &str, mime_type: &str, content: &str) {
+    let url = ObjectUrl::from(Blob::new_with_options(content, Some(mime_type)));
+    let Ok(link) = gloo::utils::document().create_element("a") else {
+        return;
+    };
+    let _ = link.set_attribute("href", &url);
+    let _ = link.set_attribute("download", filename);
+    if let Ok(link) = link.dyn_into::<HtmlElement>() {
+        link.click();
+    }
+    // The browser reads the URL after the click returns
+    Timeout::new(0, move || drop(url)).forget();
+}
+
+/// Put text on the clipboard
+pub(crate) fn copy_to_clipboard(text: &str) {
+    // The write fails silently without clipboard permission
+    let _ = gloo::utils::window()
+        .navigator()
+        .clipboard()
+        .write_text(text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq)]
+    struct Row {
+        name: &'static str,
+        note: &'static str,
+        amount: f64,
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                name: "Ada",
+                note: "says \"hi\", twice",
+                amount: 12.5,
+            },
+            Row {
+                name: "Bob",
+                note: "line\nbreak\tand tab",
+                amount: 3.0,
+            },
+        ]
+    }
+
+    fn columns() -> Vec<DataTableColumn<Row>> {
+        vec![
+            DataTableColumn::new("name", "Name", |r: Row| r.name),
+            DataTableColumn::new("note", "Note", |r: Row| r.note),
+            DataTableColumn::new("amount", html! { <b>{ "Amount" }</b> }, |r: Row| r.amount),
+        ]
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let csv = export_rows(
+            &rows(),
+            &columns(),
+            &DataTableState::default(),
+            DataTableExportFormat::Csv,
+        );
+        assert_eq!(
+            csv,
+            "Name,Note,amount\r\nAda,\"says \"\"hi\"\", twice\",12.5\r\nBob,\"line\nbreak\tand tab\",3\r\n"
+        );
+    }
+
+    #[test]
+    fn test_export_tsv_uses_visible_columns() {
+        let state = DataTableState::default().with_hidden("note", true);
+        let data = rows();
+        let tsv = export_rows(
+            data.iter().rev(),
+            &columns(),
+            &state,
+            DataTableExportFormat::Tsv,
+        );
+        assert_eq!(tsv, "Name\tamount\nBob\t3\nAda\t12.5\n");
+
+        let state = DataTableState::default().with_hidden("amount", true);
+        let tsv = export_rows(&data[1..], &columns(), &state, DataTableExportFormat::Tsv);
+        assert_eq!(tsv, "Name\tNote\nBob\tline break and tab\n");
+    }
+
+    #[test]
+    fn test_export_format() {
+        assert_eq!(DataTableExportFormat::Csv.extension(), "csv");
+        assert_eq!(
+            DataTableExportFormat::Tsv.mime_type(),
+            "text/tab-separated-values"
+        );
+        assert_eq!(DataTableExportFormat::all()[0], DataTableExportFormat::Csv);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_export_json() {
+        let json = export_rows(
+            &rows()[..1],
+            &columns(),
+            &DataTableState::default(),
+            DataTableExportFormat::Json,
+        );
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([{ "name": "Ada", "note": "says \"hi\", twice", "amount": 12.5 }])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_export_json_keeps_column_order() {
+        let keys = |state: &DataTableState| -> Vec<String> {
+            let json = export_rows(&rows()[..1], &columns(), state, DataTableExportFormat::Json);
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            value[0].as_object().unwrap().keys().cloned().collect()
+        };
+        assert_eq!(keys(&DataTableState::default()), ["name", "note", "amount"]);
+
+        let ids = [
+            AttrValue::from("name"),
+            AttrValue::from("note"),
+            AttrValue::from("amount"),
+        ];
+        let state = DataTableState::default().with_moved("amount", "name", &ids);
+        assert_eq!(keys(&state), ["amount", "name", "note"]);
+    }
+}
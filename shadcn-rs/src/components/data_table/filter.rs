@@ -0,0 +1,342 @@
+//! Filtering for the data table
+
+use yew::prelude::*;
+
+use super::column::{DataTableColumn, DataTableValue};
+
+/// Built-in column filter kinds
+///
+/// The kind decides which input is rendered in the filter row and how
+/// rows are matched when the column has no custom filter function.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataTableFilter {
+    /// Text input, matches cells containing the query (case-insensitive)
+    Contains,
+    /// Text input, matches cells equal to the query (case-insensitive)
+    Equals,
+    /// Min/max number inputs, matches numeric cells within the range
+    Range,
+    /// Multi-select of the given options, matches any selected option
+    Select(Vec<AttrValue>),
+}
+
+/// Value of a single column filter
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum DataTableFilterValue {
+    /// Text query (for `Contains` and `Equals`)
+    Text(String),
+    /// Inclusive numeric range (for `Range`)
+    Range {
+        /// Lower bound
+        min: Option<f64>,
+        /// Upper bound
+        max: Option<f64>,
+    },
+    /// Selected options (for `Select`)
+    Values(Vec<String>),
+}
+
+impl DataTableFilterValue {
+    /// Returns `true` if the filter has no effect
+    pub fn is_inactive(&self) -> bool {
+        match self {
+            DataTableFilterValue::Text(text) => text.trim().is_empty(),
+            DataTableFilterValue::Range { min, max } => min.is_none() && max.is_none(),
+            DataTableFilterValue::Values(values) => values.is_empty(),
+        }
+    }
+}
+
+/// Filter applied to a single column
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataTableColumnFilter {
+    /// Column identifier
+    pub column: String,
+    /// Filter value
+    pub value: DataTableFilterValue,
+}
+
+/// Complete filter state of a data table
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct DataTableFilterState {
+    /// Global search query, matched against every column
+    pub global: String,
+    /// Active column filters
+    pub columns: Vec<DataTableColumnFilter>,
+}
+
+impl DataTableFilterState {
+    /// Returns `true` if no filter is active
+    pub fn is_empty(&self) -> bool {
+        self.global.trim().is_empty() && self.columns.iter().all(|f| f.value.is_inactive())
+    }
+
+    /// Get the filter value of a column
+    pub fn column(&self, column: &str) -> Option<&DataTableFilterValue> {
+        self.columns
+            .iter()
+            .find(|filter| filter.column == column)
+            .map(|filter| &filter.value)
+    }
+
+    /// Return a copy with the global query replaced
+    pub fn with_global(&self, global: impl Into<String>) -> Self {
+        Self {
+            global: global.into(),
+            columns: self.columns.clone(),
+        }
+    }
+
+    /// Return a copy with a column filter set, or removed if inactive
+    pub fn with_column(&self, column: &str, value: DataTableFilterValue) -> Self {
+        let mut columns: Vec<DataTableColumnFilter> = self
+            .columns
+            .iter()
+            .filter(|filter| filter.column != column)
+            .cloned()
+            .collect();
+        if !value.is_inactive() {
+            columns.push(DataTableColumnFilter {
+                column: column.to_string(),
+                value,
+            });
+        }
+        Self {
+            global: self.global.clone(),
+            columns,
+        }
+    }
+}
+
+/// Match a cell value against a filter value using the built-in rules
+pub fn matches_filter(
+    value: &DataTableValue,
+    filter: &DataTableFilterValue,
+    kind: Option<&DataTableFilter>,
+) -> bool {
+    match filter {
+        DataTableFilterValue::Text(query) => {
+            let query = query.trim().to_lowercase();
+            let text = value.to_string().to_lowercase();
+            match kind {
+                Some(DataTableFilter::Equals) => text == query,
+                _ => text.contains(&query),
+            }
+        }
+        DataTableFilterValue::Range { min, max } => value
+            .as_number()
+            .is_some_and(|n| min.is_none_or(|min| n >= min) && max.is_none_or(|max| n <= max)),
+        DataTableFilterValue::Values(values) => values.contains(&value.to_string()),
+    }
+}
+
+/// Filter rows and return the indices of the matching ones
+///
+/// `rows` are indices into `data`. A row matches when it satisfies every
+/// active column filter and, if set, contains the global query in at
+/// least one column.
+pub fn filter_rows<T: Clone + PartialEq + 'static>(
+    data: &[T],
+    rows: Vec<usize>,
+    columns: &[DataTableColumn<T>],
+    state: &DataTableFilterState,
+) -> Vec<usize> {
+    let column_filters: Vec<(&DataTableColumn<T>, &DataTableFilterValue)> = state
+        .columns
+        .iter()
+        .filter(|filter| !filter.value.is_inactive())
+        .filter_map(|filter| {
+            columns
+                .iter()
+                .find(|column| column.id == filter.column.as_str())
+                .map(|column| (column, &filter.value))
+        })
+        .collect();
+    let global = state.global.trim().to_lowercase();
+
+    if column_filters.is_empty() && global.is_empty() {
+        return rows;
+    }
+
+    rows.into_iter()
+        .filter(|&index| {
+            let row = &data[index];
+            let columns_match = column_filters
+                .iter()
+                .all(|(column, value)| column.matches(row, value));
+            columns_match
+                && (global.is_empty()
+                    || columns.iter().any(|column| {
+                        column
+                            .value(row)
+                            .to_string()
+                            .to_lowercase()
+                            .contains(&global)
+                    }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq)]
+    struct Row {
+        name: &'static str,
+        status: &'static str,
+        amount: f64,
+    }
+
+    fn data() -> Vec<Row> {
+        vec![
+            Row {
+                name: "Ken",
+                status: "success",
+                amount: 316.0,
+            },
+            Row {
+                name: "Abe",
+                status: "processing",
+                amount: 242.0,
+            },
+            Row {
+                name: "Monserrat",
+                status: "failed",
+                amount: 837.0,
+            },
+        ]
+    }
+
+    fn columns() -> Vec<DataTableColumn<Row>> {
+        vec![
+            DataTableColumn::new("name", "Name", |r: Row| r.name).filter(DataTableFilter::Contains),
+            DataTableColumn::new("status", "Status", |r: Row| r.status).filter(
+                DataTableFilter::Select(vec![
+                    "success".into(),
+                    "processing".into(),
+                    "failed".into(),
+                ]),
+            ),
+            DataTableColumn::new("amount", "Amount", |r: Row| r.amount)
+                .filter(DataTableFilter::Range),
+        ]
+    }
+
+    fn apply(state: &DataTableFilterState) -> Vec<usize> {
+        let data = data();
+        filter_rows(&data, (0..data.len()).collect(), &columns(), state)
+    }
+
+    #[test]
+    fn test_filter_global() {
+        let state = DataTableFilterState::default().with_global("SER");
+        assert_eq!(apply(&state), vec![2]);
+    }
+
+    #[test]
+    fn test_filter_contains() {
+        let state = DataTableFilterState::default()
+            .with_column("name", DataTableFilterValue::Text("e".to_string()));
+        assert_eq!(apply(&state), vec![0, 1, 2]);
+
+        let state = state.with_column("name", DataTableFilterValue::Text("ab".to_string()));
+        assert_eq!(apply(&state), vec![1]);
+    }
+
+    #[test]
+    fn test_filter_equals() {
+        let value = DataTableValue::from("Ken");
+        let filter = DataTableFilterValue::Text("ken".to_string());
+        assert!(matches_filter(
+            &value,
+            &filter,
+            Some(&DataTableFilter::Equals)
+        ));
+
+        let filter = DataTableFilterValue::Text("ke".to_string());
+        assert!(!matches_filter(
+            &value,
+            &filter,
+            Some(&DataTableFilter::Equals)
+        ));
+        assert!(matches_filter(
+            &value,
+            &filter,
+            Some(&DataTableFilter::Contains)
+        ));
+    }
+
+    #[test]
+    fn test_filter_range() {
+        let state = DataTableFilterState::default().with_column(
+            "amount",
+            DataTableFilterValue::Range {
+                min: Some(300.0),
+                max: None,
+            },
+        );
+        assert_eq!(apply(&state), vec![0, 2]);
+
+        let state = state.with_column(
+            "amount",
+            DataTableFilterValue::Range {
+                min: Some(300.0),
+                max: Some(500.0),
+            },
+        );
+        assert_eq!(apply(&state), vec![0]);
+    }
+
+    #[test]
+    fn test_filter_select_and_combined() {
+        let state = DataTableFilterState::default().with_column(
+            "status",
+            DataTableFilterValue::Values(vec!["success".to_string(), "failed".to_string()]),
+        );
+        assert_eq!(apply(&state), vec![0, 2]);
+
+        let state = state.with_global("ken");
+        assert_eq!(apply(&state), vec![0]);
+    }
+
+    #[test]
+    fn test_filter_custom_fn() {
+        let data = data();
+        let columns = vec![
+            DataTableColumn::new("amount", "Amount", |r: Row| r.amount).filter_fn(
+                |row: Row, value: DataTableFilterValue| match value {
+                    DataTableFilterValue::Text(text) => text == "big" && row.amount > 500.0,
+                    _ => true,
+                },
+            ),
+        ];
+        let state = DataTableFilterState::default()
+            .with_column("amount", DataTableFilterValue::Text("big".to_string()));
+        assert_eq!(
+            filter_rows(&data, (0..data.len()).collect(), &columns, &state),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn test_inactive_filters_are_removed() {
+        let state = DataTableFilterState::default()
+            .with_column("name", DataTableFilterValue::Text("ken".to_string()))
+            .with_column("name", DataTableFilterValue::Text(String::new()));
+        assert!(state.columns.is_empty());
+        assert!(state.is_empty());
+        assert_eq!(apply(&state), vec![0, 1, 2]);
+    }
+}
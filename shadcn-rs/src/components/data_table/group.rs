@@ -0,0 +1,310 @@
+//! Row grouping and aggregation for the data table
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use yew::prelude::*;
+
+use super::column::{DataTableColumn, DataTableValue};
+use super::sort::compare_values;
+
+/// Aggregate shown for a column in group header rows
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataTableAggregate {
+    /// Sum of the numeric values
+    Sum,
+    /// Number of rows
+    Count,
+    /// Mean of the numeric values
+    Average,
+    /// Smallest non-empty value
+    Min,
+    /// Largest non-empty value
+    Max,
+    /// Custom aggregate of the values of the group
+    Custom(Callback<Vec<DataTableValue>, DataTableValue>),
+}
+
+impl DataTableAggregate {
+    /// Aggregate the cell values of a group
+    pub fn apply(&self, values: Vec<DataTableValue>) -> DataTableValue {
+        let numbers = || values.iter().filter_map(DataTableValue::as_number);
+        let extreme = |wanted: Ordering| {
+            values
+                .iter()
+                .filter(|value| !value.is_empty())
+                .cloned()
+                .reduce(|best, value| {
+                    if compare_values(&value, &best) == wanted {
+                        value
+                    } else {
+                        best
+                    }
+                })
+                .unwrap_or_default()
+        };
+        match self {
+            DataTableAggregate::Count => DataTableValue::from(values.len()),
+            DataTableAggregate::Sum => match numbers().count() {
+                0 => DataTableValue::Empty,
+                _ => DataTableValue::Number(numbers().sum()),
+            },
+            DataTableAggregate::Average => match numbers().count() {
+                0 => DataTableValue::Empty,
+                count => DataTableValue::Number(numbers().sum::<f64>() / count as f64),
+            },
+            DataTableAggregate::Min => extreme(Ordering::Less),
+            DataTableAggregate::Max => extreme(Ordering::Greater),
+            DataTableAggregate::Custom(aggregate) => aggregate.emit(values),
+        }
+    }
+}
+
+/// Rows sharing the value of a grouped column
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowGroup {
+    /// Unique key of the group: `column:value` segments of the group and
+    /// its parents, joined by `/`
+    ///
+    /// `\`, `:` and `/` in column ids and values are escaped with a `\`,
+    /// so the group of `a/b` in column `path` has the key `path:a\/b`.
+    pub key: AttrValue,
+    /// Index of the grouped column
+    pub column: usize,
+    /// Shared value of the grouped column
+    pub value: DataTableValue,
+    /// Nesting level, 0 for the outermost groups
+    pub depth: usize,
+    /// Indices of all rows of the group, in display order
+    pub rows: Vec<usize>,
+    /// Nested groups (grouped by the next column), empty for the innermost level
+    pub subgroups: Vec<RowGroup>,
+}
+
+/// Group rows by the values of the given columns, outermost first
+///
+/// `rows` are indices into `data` in display order. Rows are grouped by
+/// the text of their value, and groups keep the order of their first row,
+/// so sorting by a grouped column orders its groups. Unknown column ids
+/// are ignored.
+pub fn group_rows<T: Clone + PartialEq + 'static>(
+    data: &[T],
+    rows: &[usize],
+    columns: &[DataTableColumn<T>],
+    group_by: &[AttrValue],
+) -> Vec<RowGroup> {
+    let levels: Vec<usize> = group_by
+        .iter()
+        .filter_map(|id| columns.iter().position(|column| column.id == *id))
+        .collect();
+    build_groups(data, rows, columns, &levels, "", 0)
+}
+
+fn build_groups<T: Clone + PartialEq + 'static>(
+    data: &[T],
+    rows: &[usize],
+    columns: &[DataTableColumn<T>],
+    levels: &[usize],
+    parent: &str,
+    depth: usize,
+) -> Vec<RowGroup> {
+    let Some((&column, inner)) = levels.split_first() else {
+        return Vec::new();
+    };
+    let mut groups: Vec<RowGroup> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for &row in rows {
+        let value = columns[column].value(&data[row]);
+        let segment = format!(
+            "{}:{}",
+            escape_key_part(&columns[column].id),
+            escape_key_part(&value.to_string())
+        );
+        match positions.get(&segment) {
+            Some(&position) => groups[position].rows.push(row),
+            None => {
+                let key = if parent.is_empty() {
+                    segment.clone()
+                } else {
+                    format!("{}/{}", parent, segment)
+                };
+                positions.insert(segment, groups.len());
+                groups.push(RowGroup {
+                    key: AttrValue::from(key),
+                    column,
+                    value,
+                    depth,
+                    rows: vec![row],
+                    subgroups: Vec::new(),
+                });
+            }
+        }
+    }
+    for group in &mut groups {
+        group.subgroups = build_groups(data, &group.rows, columns, inner, &group.key, depth + 1);
+    }
+    groups
+}
+
+/// Escape the characters that separate the parts of a group key
+fn escape_key_part(part: &str) -> String {
+    let mut escaped = String::with_capacity(part.len());
+    for c in part.chars() {
+        if matches!(c, '\\' | ':' | '/') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq)]
+    struct Row {
+        region: &'static str,
+        status: &'static str,
+        amount: f64,
+    }
+
+    fn data() -> Vec<Row> {
+        vec![
+            Row {
+                region: "EU",
+                status: "Paid",
+                amount: 10.0,
+            },
+            Row {
+                region: "US",
+                status: "Paid",
+                amount: 20.0,
+            },
+            Row {
+                region: "EU",
+                status: "Open",
+                amount: 30.0,
+            },
+            Row {
+                region: "EU",
+                status: "Paid",
+                amount: 40.0,
+            },
+        ]
+    }
+
+    fn columns() -> Vec<DataTableColumn<Row>> {
+        vec![
+            DataTableColumn::new("region", "Region", |r: Row| r.region),
+            DataTableColumn::new("status", "Status", |r: Row| r.status),
+            DataTableColumn::new("amount", "Amount", |r: Row| r.amount),
+        ]
+    }
+
+    #[test]
+    fn test_group_rows() {
+        let groups = group_rows(&data(), &[0, 1, 2, 3], &columns(), &["region".into()]);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].key, AttrValue::from("region:EU"));
+        assert_eq!(groups[0].rows, vec![0, 2, 3]);
+        assert_eq!(groups[1].value, DataTableValue::from("US"));
+        assert!(groups[0].subgroups.is_empty());
+    }
+
+    #[test]
+    fn test_group_rows_nested() {
+        let groups = group_rows(
+            &data(),
+            &[3, 2, 1, 0],
+            &columns(),
+            &["region".into(), "unknown".into(), "status".into()],
+        );
+
+        assert_eq!(groups[0].rows, vec![3, 2, 0]);
+        let subgroups = &groups[0].subgroups;
+        assert_eq!(subgroups.len(), 2);
+        assert_eq!(subgroups[0].key, AttrValue::from("region:EU/status:Paid"));
+        assert_eq!(subgroups[0].rows, vec![3, 0]);
+        assert_eq!(subgroups[0].depth, 1);
+        assert_eq!(subgroups[1].rows, vec![2]);
+    }
+
+    #[test]
+    fn test_group_keys_escape_separators() {
+        #[derive(Clone, PartialEq)]
+        struct Pair {
+            a: &'static str,
+            b: &'static str,
+        }
+        let data = vec![Pair { a: "x/b:y", b: "z" }, Pair { a: "x", b: "y/b:z" }];
+        let columns = vec![
+            DataTableColumn::new("a", "A", |p: Pair| p.a),
+            DataTableColumn::new("b", "B", |p: Pair| p.b),
+        ];
+        let groups = group_rows(&data, &[0, 1], &columns, &["a".into(), "b".into()]);
+
+        assert_eq!(groups[0].key, AttrValue::from("a:x\\/b\\:y"));
+        assert_eq!(
+            groups[0].subgroups[0].key,
+            AttrValue::from("a:x\\/b\\:y/b:z")
+        );
+        assert_eq!(
+            groups[1].subgroups[0].key,
+            AttrValue::from("a:x/b:y\\/b\\:z")
+        );
+    }
+
+    #[test]
+    fn test_group_rows_without_columns() {
+        assert!(group_rows(&data(), &[0, 1], &columns(), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let values = || {
+            vec![
+                DataTableValue::from(4.0),
+                DataTableValue::Empty,
+                DataTableValue::from(2.0),
+            ]
+        };
+
+        assert_eq!(
+            DataTableAggregate::Sum.apply(values()),
+            DataTableValue::from(6.0)
+        );
+        assert_eq!(
+            DataTableAggregate::Count.apply(values()),
+            DataTableValue::from(3)
+        );
+        assert_eq!(
+            DataTableAggregate::Average.apply(values()),
+            DataTableValue::from(3.0)
+        );
+        assert_eq!(
+            DataTableAggregate::Min.apply(values()),
+            DataTableValue::from(2.0)
+        );
+        assert_eq!(
+            DataTableAggregate::Max.apply(values()),
+            DataTableValue::from(4.0)
+        );
+        assert_eq!(DataTableAggregate::Sum.apply(vec![]), DataTableValue::Empty);
+        assert_eq!(
+            DataTableAggregate::Max.apply(vec!["b".into(), "a".into()]),
+            DataTableValue::from("b")
+        );
+
+        let distinct = DataTableAggregate::Custom(Callback::from(|values: Vec<DataTableValue>| {
+            let mut names: Vec<String> = values.iter().map(ToString::to_string).collect();
+            names.dedup();
+            DataTableValue::from(names.len())
+        }));
+        assert_eq!(
+            distinct.apply(vec!["a".into(), "a".into(), "b".into()]),
+            DataTableValue::from(2)
+        );
+    }
+}
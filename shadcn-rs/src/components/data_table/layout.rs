@@ -0,0 +1,374 @@
+//! Column layout for the data table: order, widths, pinning and visibility
+
+use std::collections::BTreeMap;
+
+use yew::prelude::*;
+
+use super::column::DataTableColumn;
+
+/// Minimum width of a resized column, in pixels
+pub const MIN_COLUMN_WIDTH: f64 = 40.0;
+
+/// Width of pinned columns without a pixel width, in pixels
+pub const DEFAULT_PINNED_WIDTH: f64 = 150.0;
+
+/// Side a column is pinned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum ColumnPin {
+    /// Pinned to the left edge
+    Left,
+    /// Pinned to the right edge
+    Right,
+}
+
+/// Column layout state of a data table
+///
+/// Plain data keyed by column id, so a user's layout can be saved and
+/// restored (serializable with the `serde` feature).
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct DataTableState {
+    /// Column ids in display order (missing columns follow in definition order)
+    pub column_order: Vec<String>,
+    /// Column widths in pixels
+    pub column_widths: BTreeMap<String, f64>,
+    /// Pinned columns
+    ///
+    /// Sticky offsets add up the widths of the columns pinned before, so a
+    /// pinned column keeps a pixel width: its resized width, its column
+    /// width if given in `px`, or else `DEFAULT_PINNED_WIDTH`.
+    pub column_pins: BTreeMap<String, ColumnPin>,
+    /// Hidden column ids
+    pub hidden_columns: Vec<String>,
+}
+
+impl DataTableState {
+    /// Returns `true` if the column is hidden
+    pub fn is_hidden(&self, column: &str) -> bool {
+        self.hidden_columns.iter().any(|id| id == column)
+    }
+
+    /// Get the width of a column, if resized
+    pub fn width(&self, column: &str) -> Option<f64> {
+        self.column_widths.get(column).copied()
+    }
+
+    /// Get the pinned side of a column
+    pub fn pin(&self, column: &str) -> Option<ColumnPin> {
+        self.column_pins.get(column).copied()
+    }
+
+    /// Return a copy with a column shown or hidden
+    pub fn with_hidden(&self, column: &str, hidden: bool) -> Self {
+        let mut state = self.clone();
+        state.hidden_columns.retain(|id| id != column);
+        if hidden {
+            state.hidden_columns.push(column.to_string());
+        }
+        state
+    }
+
+    /// Return a copy with a column width set (clamped to [`MIN_COLUMN_WIDTH`])
+    pub fn with_width(&self, column: &str, width: f64) -> Self {
+        let mut state = self.clone();
+        state
+            .column_widths
+            .insert(column.to_string(), width.max(MIN_COLUMN_WIDTH));
+        state
+    }
+
+    /// Return a copy with a column pinned, or unpinned with `None`
+    pub fn with_pin(&self, column: &str, pin: Option<ColumnPin>) -> Self {
+        let mut state = self.clone();
+        match pin {
+            Some(pin) => state.column_pins.insert(column.to_string(), pin),
+            None => state.column_pins.remove(column),
+        };
+        state
+    }
+
+    /// Return a copy with `column` moved to the position of `target`
+    ///
+    /// `ids` are the ids of all columns in definition order.
+    pub fn with_moved(&self, column: &str, target: &str, ids: &[AttrValue]) -> Self {
+        let mut order = self.full_order(ids);
+        let (Some(from), Some(to)) = (
+            order.iter().position(|id| id == column),
+            order.iter().position(|id| id == target),
+        ) else {
+            return self.clone();
+        };
+        let moved = order.remove(from);
+        order.insert(to, moved);
+        Self {
+            column_order: order,
+            ..self.clone()
+        }
+    }
+
+    /// Ids of all columns in display order, before pinning and hiding
+    fn full_order(&self, ids: &[AttrValue]) -> Vec<String> {
+        let mut order: Vec<String> = self
+            .column_order
+            .iter()
+            .filter(|id| ids.iter().any(|known| known == id.as_str()))
+            .cloned()
+            .collect();
+        for id in ids {
+            if !order.iter().any(|known| known == id.as_str()) {
+                order.push(id.to_string());
+            }
+        }
+        order
+    }
+}
+
+/// Placement of a visible column
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnLayout {
+    /// Index of the column definition
+    pub index: usize,
+    /// Fixed width in pixels (resized, or pinned without a `px` column width)
+    pub width: Option<f64>,
+    /// Pinned side
+    pub pin: Option<ColumnPin>,
+    /// Distance from the pinned edge, in pixels
+    pub offset: f64,
+}
+
+impl ColumnLayout {
+    /// Inline style for the header and cells of the column
+    pub fn style<T: Clone + PartialEq + 'static>(
+        &self,
+        column: &DataTableColumn<T>,
+    ) -> Option<AttrValue> {
+        let mut parts = Vec::new();
+        match self.width {
+            Some(width) => parts.push(format!(
+                "width: {0}px; min-width: {0}px; max-width: {0}px;",
+                width
+            )),
+            None => parts.extend(column.style().map(|style| style.to_string())),
+        }
+        match self.pin {
+            Some(ColumnPin::Left) => parts.push(format!("left: {}px;", self.offset)),
+            Some(ColumnPin::Right) => parts.push(format!("right: {}px;", self.offset)),
+            None => {}
+        }
+        (!parts.is_empty()).then(|| AttrValue::from(parts.join(" ")))
+    }
+
+    /// CSS classes for pinned columns
+    pub fn classes(&self) -> Classes {
+        match self.pin {
+            Some(ColumnPin::Left) => classes!("data-table-pinned", "data-table-pinned-left"),
+            Some(ColumnPin::Right) => classes!("data-table-pinned", "data-table-pinned-right"),
+            None => Classes::new(),
+        }
+    }
+}
+
+/// Parse a CSS pixel length such as `"120px"`
+fn parse_px(width: &str) -> Option<f64> {
+    width.trim().strip_suffix("px")?.trim().parse().ok()
+}
+
+/// Lay out the visible columns: left-pinned, unpinned, then right-pinned,
+/// each group in display order
+pub fn layout_columns<T: Clone + PartialEq + 'static>(
+    columns: &[DataTableColumn<T>],
+    state: &DataTableState,
+) -> Vec<ColumnLayout> {
+    let ids: Vec<AttrValue> = columns.iter().map(|column| column.id.clone()).collect();
+    let visible: Vec<ColumnLayout> = state
+        .full_order(&ids)
+        .iter()
+        .filter(|id| !state.is_hidden(id))
+        .filter_map(|id| columns.iter().position(|column| column.id == id.as_str()))
+        .map(|index| {
+            let column = &columns[index];
+            let pin = state.pin(&column.id);
+            // A pinned `%`, `rem` or `auto` width cannot be added up into the
+            // offsets of the next pinned columns
+            let px_width = column.width.as_deref().and_then(parse_px);
+            let width = state
+                .width(&column.id)
+                .or_else(|| (pin.is_some() && px_width.is_none()).then_some(DEFAULT_PINNED_WIDTH));
+            ColumnLayout {
+                index,
+                width,
+                pin,
+                offset: 0.0,
+            }
+        })
+        .collect();
+
+    let group = |pin: Option<ColumnPin>| {
+        visible
+            .iter()
+            .filter(move |layout| layout.pin == pin)
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let mut left = group(Some(ColumnPin::Left));
+    let middle = group(None);
+    let mut right = group(Some(ColumnPin::Right));
+
+    let pinned_width = |layout: &ColumnLayout| {
+        layout
+            .width
+            .or_else(|| columns[layout.index].width.as_deref().and_then(parse_px))
+            .unwrap_or(DEFAULT_PINNED_WIDTH)
+    };
+    let mut offset = 0.0;
+    for layout in &mut left {
+        layout.offset = offset;
+        offset += pinned_width(layout);
+    }
+    let mut offset = 0.0;
+    for layout in right.iter_mut().rev() {
+        layout.offset = offset;
+        offset += pinned_width(layout);
+    }
+
+    left.into_iter().chain(middle).chain(right).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq)]
+    struct Row {
+        a: u32,
+    }
+
+    fn columns() -> Vec<DataTableColumn<Row>> {
+        ["a", "b", "c", "d"]
+            .into_iter()
+            .map(|id| DataTableColumn::new(id, id, |r: Row| r.a))
+            .collect()
+    }
+
+    fn order(layouts: &[ColumnLayout]) -> Vec<usize> {
+        layouts.iter().map(|layout| layout.index).collect()
+    }
+
+    #[test]
+    fn test_layout_default_order() {
+        let layouts = layout_columns(&columns(), &DataTableState::default());
+        assert_eq!(order(&layouts), vec![0, 1, 2, 3]);
+        assert!(layouts.iter().all(|layout| layout.pin.is_none()));
+    }
+
+    #[test]
+    fn test_layout_hidden_and_order() {
+        let state = DataTableState {
+            column_order: vec!["c".to_string(), "unknown".to_string(), "a".to_string()],
+            ..Default::default()
+        }
+        .with_hidden("b", true);
+        assert_eq!(order(&layout_columns(&columns(), &state)), vec![2, 0, 3]);
+
+        let state = state.with_hidden("b", false);
+        assert_eq!(order(&layout_columns(&columns(), &state)), vec![2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn test_with_moved() {
+        let ids: Vec<AttrValue> = columns().iter().map(|c| c.id.clone()).collect();
+        let state = DataTableState::default().with_moved("a", "c", &ids);
+        assert_eq!(state.column_order, vec!["b", "c", "a", "d"]);
+
+        let state = state.with_moved("d", "b", &ids);
+        assert_eq!(state.column_order, vec!["d", "b", "c", "a"]);
+
+        assert_eq!(state.with_moved("x", "b", &ids), state);
+    }
+
+    #[test]
+    fn test_layout_pinning() {
+        let state = DataTableState::default()
+            .with_pin("c", Some(ColumnPin::Left))
+            .with_pin("a", Some(ColumnPin::Right))
+            .with_pin("d", Some(ColumnPin::Left))
+            .with_width("c", 100.0);
+        let layouts = layout_columns(&columns(), &state);
+
+        assert_eq!(order(&layouts), vec![2, 3, 1, 0]);
+        assert_eq!(layouts[0].offset, 0.0);
+        assert_eq!(layouts[1].offset, 100.0);
+        assert_eq!(layouts[1].width, Some(DEFAULT_PINNED_WIDTH));
+        assert_eq!(layouts[3].offset, 0.0);
+        assert_eq!(layouts[2].width, None);
+
+        let state = state.with_pin("c", None);
+        assert_eq!(order(&layout_columns(&columns(), &state)), vec![3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_layout_pinned_percent_width() {
+        let columns = vec![
+            DataTableColumn::new("a", "A", |r: Row| r.a).width("20%"),
+            DataTableColumn::new("b", "B", |r: Row| r.a).width("80px"),
+            DataTableColumn::new("c", "C", |r: Row| r.a),
+        ];
+        let state = DataTableState::default()
+            .with_pin("a", Some(ColumnPin::Left))
+            .with_pin("b", Some(ColumnPin::Left))
+            .with_pin("c", Some(ColumnPin::Left));
+        let layouts = layout_columns(&columns, &state);
+
+        // The percentage gives way to the width the offsets are built on
+        assert_eq!(layouts[0].width, Some(DEFAULT_PINNED_WIDTH));
+        assert_eq!(
+            layouts[0].style(&columns[0]).as_deref(),
+            Some("width: 150px; min-width: 150px; max-width: 150px; left: 0px;")
+        );
+        assert_eq!(layouts[1].width, None);
+        assert_eq!(layouts[1].offset, DEFAULT_PINNED_WIDTH);
+        assert_eq!(layouts[2].offset, DEFAULT_PINNED_WIDTH + 80.0);
+    }
+
+    #[test]
+    fn test_with_width_clamps() {
+        let state = DataTableState::default().with_width("a", 5.0);
+        assert_eq!(state.width("a"), Some(MIN_COLUMN_WIDTH));
+    }
+
+    #[test]
+    fn test_layout_style() {
+        let columns = vec![
+            DataTableColumn::new("a", "A", |r: Row| r.a).width("80px"),
+            DataTableColumn::new("b", "B", |r: Row| r.a),
+        ];
+        let state = DataTableState::default()
+            .with_pin("a", Some(ColumnPin::Left))
+            .with_pin("b", Some(ColumnPin::Left))
+            .with_width("b", 120.0);
+        let layouts = layout_columns(&columns, &state);
+
+        assert_eq!(layouts[0].width, None);
+        assert_eq!(
+            layouts[0].style(&columns[0]),
+            Some(AttrValue::from("width: 80px; left: 0px;"))
+        );
+        assert_eq!(
+            layouts[1].style(&columns[1]),
+            Some(AttrValue::from(
+                "width: 120px; min-width: 120px; max-width: 120px; left: 80px;"
+            ))
+        );
+        assert_eq!(parse_px("12.5px"), Some(12.5));
+        assert_eq!(parse_px("10rem"), None);
+    }
+}
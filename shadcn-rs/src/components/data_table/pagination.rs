@@ -0,0 +1,153 @@
+//! Pagination for the data table
+
+/// An entry in the page list of the pagination control
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageItem {
+    /// A zero-based page index
+    Page(usize),
+    /// A gap of skipped pages
+    Ellipsis,
+}
+
+/// Number of pages needed for `total` rows (at least one)
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Clamp a zero-based page index to the available pages
+pub fn clamp_page(page: usize, total: usize, page_size: usize) -> usize {
+    page.min(page_count(total, page_size) - 1)
+}
+
+/// Row range `[start, end)` shown on a zero-based page
+pub fn page_bounds(page: usize, page_size: usize, total: usize) -> (usize, usize) {
+    let start = (page * page_size.max(1)).min(total);
+    let end = (start + page_size.max(1)).min(total);
+    (start, end)
+}
+
+/// "Showing X–Y of Z" summary for a zero-based page
+pub fn page_summary(page: usize, page_size: usize, total: usize) -> String {
+    if total == 0 {
+        return "No rows".to_string();
+    }
+    let (start, end) = page_bounds(page, page_size, total);
+    format!("Showing {}–{} of {}", start + 1, end, total)
+}
+
+/// Page list with the first and last page, `siblings` pages around the
+/// current one, and ellipses for the gaps in between
+///
+/// A gap of a single page shows that page instead of an ellipsis.
+pub fn page_items(current: usize, count: usize, siblings: usize) -> Vec<PageItem> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let last = count - 1;
+    let current = current.min(last);
+    let start = current.saturating_sub(siblings);
+    let end = (current + siblings).min(last);
+
+    let mut items = Vec::new();
+
+    if start > 0 {
+        items.push(PageItem::Page(0));
+        match start {
+            1 => {}
+            2 => items.push(PageItem::Page(1)),
+            _ => items.push(PageItem::Ellipsis),
+        }
+    }
+    items.extend((start..=end).map(PageItem::Page));
+    if end < last {
+        match last - end {
+            1 => {}
+            2 => items.push(PageItem::Page(last - 1)),
+            _ => items.push(PageItem::Ellipsis),
+        }
+        items.push(PageItem::Page(last));
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PageItem::{Ellipsis, Page};
+
+    #[test]
+    fn test_page_count() {
+        assert_eq!(page_count(0, 10), 1);
+        assert_eq!(page_count(10, 10), 1);
+        assert_eq!(page_count(11, 10), 2);
+        assert_eq!(page_count(5, 0), 5);
+    }
+
+    #[test]
+    fn test_clamp_page() {
+        assert_eq!(clamp_page(3, 25, 10), 2);
+        assert_eq!(clamp_page(1, 25, 10), 1);
+        assert_eq!(clamp_page(4, 0, 10), 0);
+    }
+
+    #[test]
+    fn test_page_bounds() {
+        assert_eq!(page_bounds(0, 10, 25), (0, 10));
+        assert_eq!(page_bounds(2, 10, 25), (20, 25));
+        assert_eq!(page_bounds(5, 10, 25), (25, 25));
+    }
+
+    #[test]
+    fn test_page_summary() {
+        assert_eq!(page_summary(0, 10, 25), "Showing 1–10 of 25");
+        assert_eq!(page_summary(2, 10, 25), "Showing 21–25 of 25");
+        assert_eq!(page_summary(0, 10, 0), "No rows");
+    }
+
+    #[test]
+    fn test_page_items_small() {
+        assert_eq!(page_items(0, 3, 1), vec![Page(0), Page(1), Page(2)]);
+        assert_eq!(page_items(0, 1, 1), vec![Page(0)]);
+        assert!(page_items(0, 0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_page_items_ellipsis() {
+        assert_eq!(
+            page_items(5, 10, 1),
+            vec![
+                Page(0),
+                Ellipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                Ellipsis,
+                Page(9)
+            ]
+        );
+        assert_eq!(
+            page_items(0, 10, 1),
+            vec![Page(0), Page(1), Ellipsis, Page(9)]
+        );
+        assert_eq!(
+            page_items(9, 10, 1),
+            vec![Page(0), Ellipsis, Page(8), Page(9)]
+        );
+    }
+
+    #[test]
+    fn test_page_items_single_gap_shows_page() {
+        assert_eq!(
+            page_items(3, 7, 1),
+            vec![
+                Page(0),
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Page(6)
+            ]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Row selection for the data table
+//!
+//! Selection is keyed by row id so that it survives sorting, filtering
+//! and pagination.
+
+use std::collections::HashSet;
+use yew::prelude::*;
+
+/// Selection mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum SelectionMode {
+    /// No selection
+    None,
+    /// Single row selection
+    Single,
+    /// Multiple row selection
+    Multiple,
+}
+
+/// Toggle a single row
+///
+/// In single mode the row replaces the current selection.
+pub fn toggle_row(selected: &[AttrValue], id: &AttrValue, mode: SelectionMode) -> Vec<AttrValue> {
+    let is_selected = selected.contains(id);
+    match mode {
+        SelectionMode::None => selected.to_vec(),
+        SelectionMode::Single if is_selected => Vec::new(),
+        SelectionMode::Single => vec![id.clone()],
+        SelectionMode::Multiple if is_selected => {
+            selected.iter().filter(|s| *s != id).cloned().collect()
+        }
+        SelectionMode::Multiple => {
+            let mut next = selected.to_vec();
+            next.push(id.clone());
+            next
+        }
+    }
+}
+
+/// Select every visible row between `anchor` and `target` (inclusive)
+///
+/// Used for shift-click range selection. Falls back to toggling `target`
+/// when the anchor is not visible.
+pub fn select_range(
+    selected: &[AttrValue],
+    visible: &[AttrValue],
+    anchor: &AttrValue,
+    target: &AttrValue,
+) -> Vec<AttrValue> {
+    let (Some(from), Some(to)) = (
+        visible.iter().position(|id| id == anchor),
+        visible.iter().position(|id| id == target),
+    ) else {
+        return toggle_row(selected, target, SelectionMode::Multiple);
+    };
+    let (from, to) = (from.min(to), from.max(to));
+    extend_selection(selected, &visible[from..=to])
+}
+
+/// Toggle all visible rows: select them all, or clear them if all are selected
+pub fn toggle_all(selected: &[AttrValue], visible: &[AttrValue]) -> Vec<AttrValue> {
+    if all_selected(selected, visible) {
+        let visible: HashSet<&AttrValue> = visible.iter().collect();
+        selected
+            .iter()
+            .filter(|id| !visible.contains(id))
+            .cloned()
+            .collect()
+    } else {
+        extend_selection(selected, visible)
+    }
+}
+
+/// Append the ids of `rows` that are not already selected
+fn extend_selection(selected: &[AttrValue], rows: &[AttrValue]) -> Vec<AttrValue> {
+    let mut seen: HashSet<&AttrValue> = selected.iter().collect();
+    let mut next = selected.to_vec();
+    for id in rows {
+        if seen.insert(id) {
+            next.push(id.clone());
+        }
+    }
+    next
+}
+
+/// Returns `(all, some)` for the visible rows in a single pass
+///
+/// `all` is `true` if there are visible rows and all of them are selected;
+/// `some` is `true` if some, but not all, of them are. These map to the
+/// checked and indeterminate states of a "select all" checkbox.
+pub fn selection_status(selected: &[AttrValue], visible: &[AttrValue]) -> (bool, bool) {
+    let selected: HashSet<&AttrValue> = selected.iter().collect();
+    let count = visible.iter().filter(|id| selected.contains(id)).count();
+    let all = count > 0 && count == visible.len();
+    (all, count > 0 && !all)
+}
+
+/// Returns `true` if there are visible rows and all of them are selected
+pub fn all_selected(selected: &[AttrValue], visible: &[AttrValue]) -> bool {
+    selection_status(selected, visible).0
+}
+
+/// Returns `true` if some, but not all, visible rows are selected
+pub fn some_selected(selected: &[AttrValue], visible: &[AttrValue]) -> bool {
+    selection_status(selected, visible).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&'static str]) -> Vec<AttrValue> {
+        values.iter().map(|v| AttrValue::from(*v)).collect()
+    }
+
+    #[test]
+    fn test_toggle_row_multiple() {
+        let selected = toggle_row(&[], &"a".into(), SelectionMode::Multiple);
+        let selected = toggle_row(&selected, &"b".into(), SelectionMode::Multiple);
+        assert_eq!(selected, ids(&["a", "b"]));
+
+        let selected = toggle_row(&selected, &"a".into(), SelectionMode::Multiple);
+        assert_eq!(selected, ids(&["b"]));
+    }
+
+    #[test]
+    fn test_toggle_row_single() {
+        let selected = toggle_row(&ids(&["a"]), &"b".into(), SelectionMode::Single);
+        assert_eq!(selected, ids(&["b"]));
+
+        let selected = toggle_row(&selected, &"b".into(), SelectionMode::Single);
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_row_none() {
+        let selected = toggle_row(&ids(&["a"]), &"b".into(), SelectionMode::None);
+        assert_eq!(selected, ids(&["a"]));
+    }
+
+    #[test]
+    fn test_select_range() {
+        let visible = ids(&["a", "b", "c", "d", "e"]);
+        let selected = select_range(&ids(&["x"]), &visible, &"d".into(), &"b".into());
+        assert_eq!(selected, ids(&["x", "b", "c", "d"]));
+    }
+
+    #[test]
+    fn test_select_range_missing_anchor() {
+        let visible = ids(&["a", "b"]);
+        let selected = select_range(&[], &visible, &"z".into(), &"b".into());
+        assert_eq!(selected, ids(&["b"]));
+    }
+
+    #[test]
+    fn test_toggle_all() {
+        let visible = ids(&["a", "b"]);
+        let selected = toggle_all(&ids(&["x", "a"]), &visible);
+        assert_eq!(selected, ids(&["x", "a", "b"]));
+
+        let selected = toggle_all(&selected, &visible);
+        assert_eq!(selected, ids(&["x"]));
+    }
+
+    #[test]
+    fn test_selection_status() {
+        let visible = ids(&["a", "b"]);
+        assert!(!all_selected(&[], &visible));
+        assert!(!some_selected(&[], &visible));
+        assert!(some_selected(&ids(&["a"]), &visible));
+        assert!(all_selected(&ids(&["a", "b"]), &visible));
+        assert!(!some_selected(&ids(&["a", "b"]), &visible));
+        assert!(!all_selected(&ids(&["a"]), &[]));
+        assert_eq!(selection_status(&ids(&["b", "x"]), &visible), (false, true));
+        assert_eq!(selection_status(&ids(&["b", "a"]), &visible), (true, false));
+    }
+
+    #[test]
+    fn test_select_range_keeps_order_without_duplicates() {
+        let visible = ids(&["a", "b", "c"]);
+        let selected = select_range(&ids(&["b"]), &visible, &"a".into(), &"c".into());
+        assert_eq!(selected, ids(&["b", "a", "c"]));
+    }
+}
@@ -0,0 +1,374 @@
+//! Sorting for the data table
+
+use std::cmp::Ordering;
+
+use super::column::{DataTableColumn, DataTableValue};
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum SortDirection {
+    /// Ascending order
+    Ascending,
+    /// Descending order
+    Descending,
+}
+
+impl SortDirection {
+    /// Value for the `aria-sort` attribute
+    pub fn aria_sort(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+/// Sort applied to a single column
+///
+/// The table sort state is a `Vec<DataTableSort>` ordered by priority:
+/// the first entry is the primary sort key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataTableSort {
+    /// Column identifier
+    pub column: String,
+    /// Sort direction
+    pub direction: SortDirection,
+}
+
+impl DataTableSort {
+    /// Ascending sort on a column
+    pub fn ascending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Descending sort on a column
+    pub fn descending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// Compute the sort state after a header click
+///
+/// Clicking cycles a column through ascending, descending and unsorted.
+/// A plain click replaces the sort state; with `multi` (shift-click) the
+/// column is added to, cycled within, or removed from the existing state.
+pub fn next_sort_state(current: &[DataTableSort], column: &str, multi: bool) -> Vec<DataTableSort> {
+    let existing = current.iter().find(|sort| sort.column == column);
+    let next = match existing.map(|sort| sort.direction) {
+        None => Some(SortDirection::Ascending),
+        Some(SortDirection::Ascending) => Some(SortDirection::Descending),
+        Some(SortDirection::Descending) => None,
+    };
+
+    if !multi {
+        return next
+            .map(|direction| {
+                vec![DataTableSort {
+                    column: column.to_string(),
+                    direction,
+                }]
+            })
+            .unwrap_or_default();
+    }
+
+    let mut state: Vec<DataTableSort> = current.to_vec();
+    match (existing.is_some(), next) {
+        (true, Some(direction)) => {
+            if let Some(sort) = state.iter_mut().find(|sort| sort.column == column) {
+                sort.direction = direction;
+            }
+        }
+        (true, None) => state.retain(|sort| sort.column != column),
+        (false, Some(direction)) => state.push(DataTableSort {
+            column: column.to_string(),
+            direction,
+        }),
+        (false, None) => {}
+    }
+    state
+}
+
+/// Compare two cell values
+///
+/// Numbers and booleans compare by value, text compares case-insensitively.
+/// Empty values sort after everything else.
+pub fn compare_values(a: &DataTableValue, b: &DataTableValue) -> Ordering {
+    match (a, b) {
+        (DataTableValue::Empty, DataTableValue::Empty) => Ordering::Equal,
+        (DataTableValue::Empty, _) => Ordering::Greater,
+        (_, DataTableValue::Empty) => Ordering::Less,
+        (DataTableValue::Number(a), DataTableValue::Number(b)) => a.total_cmp(b),
+        (DataTableValue::Bool(a), DataTableValue::Bool(b)) => a.cmp(b),
+        (DataTableValue::Text(a), DataTableValue::Text(b)) => a
+            .to_lowercase()
+            .cmp(&b.to_lowercase())
+            .then_with(|| a.cmp(b)),
+        (a, b) => {
+            let (a, b) = (a.to_string(), b.to_string());
+            a.to_lowercase()
+                .cmp(&b.to_lowercase())
+                .then_with(|| a.cmp(&b))
+        }
+    }
+}
+
+/// Cell value with its case-folded text, computed once per row
+struct SortValue {
+    value: DataTableValue,
+    /// Lowercased text of [`DataTableValue::Text`] values
+    folded: Option<String>,
+}
+
+impl SortValue {
+    fn new(value: DataTableValue) -> Self {
+        let folded = match &value {
+            DataTableValue::Text(text) => Some(text.to_lowercase()),
+            _ => None,
+        };
+        Self { value, folded }
+    }
+
+    /// Same ordering as [`compare_values`] without allocating for text
+    fn compare(&self, other: &Self) -> Ordering {
+        match (&self.value, &other.value, &self.folded, &other.folded) {
+            (DataTableValue::Text(a), DataTableValue::Text(b), Some(fa), Some(fb)) => {
+                fa.cmp(fb).then_with(|| a.cmp(b))
+            }
+            (a, b, _, _) => compare_values(a, b),
+        }
+    }
+}
+
+/// Sort key for a single column: precomputed values or a custom comparator
+enum SortKey<'a, T: Clone + PartialEq + 'static> {
+    Values(Vec<SortValue>),
+    Comparator(&'a DataTableColumn<T>),
+}
+
+/// Sort rows and return their indices in display order
+///
+/// `rows` are indices into `data`; sort keys are only computed for these
+/// rows. Sort entries referring to unknown or non-sortable columns are
+/// ignored, and the sort is stable.
+pub fn sort_rows<T: Clone + PartialEq + 'static>(
+    data: &[T],
+    rows: Vec<usize>,
+    columns: &[DataTableColumn<T>],
+    sort: &[DataTableSort],
+) -> Vec<usize> {
+    let keys: Vec<(SortKey<T>, SortDirection)> = sort
+        .iter()
+        .filter_map(|entry| {
+            let column = columns
+                .iter()
+                .find(|column| column.sortable && column.id == entry.column.as_str())?;
+            let key = if column.comparator.is_some() {
+                SortKey::Comparator(column)
+            } else {
+                SortKey::Values(
+                    rows.iter()
+                        .map(|&row| SortValue::new(column.value(&data[row])))
+                        .collect(),
+                )
+            };
+            Some((key, entry.direction))
+        })
+        .collect();
+
+    if keys.is_empty() {
+        return rows;
+    }
+
+    // Sort positions within `rows` so the keys line up with the values
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    order.sort_by(|&a, &b| {
+        keys.iter()
+            .map(|(key, direction)| {
+                let (ordering, reversible) = match key {
+                    SortKey::Values(values) => {
+                        let (va, vb) = (&values[a], &values[b]);
+                        // Keep empty values last regardless of direction
+                        (va.compare(vb), !va.value.is_empty() && !vb.value.is_empty())
+                    }
+                    SortKey::Comparator(column) => {
+                        (column.compare(&data[rows[a]], &data[rows[b]]), true)
+                    }
+                };
+                match direction {
+                    SortDirection::Descending if reversible => ordering.reverse(),
+                    _ => ordering,
+                }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+    order.into_iter().map(|position| rows[position]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq)]
+    struct Row {
+        name: &'static str,
+        age: Option<u32>,
+    }
+
+    fn data() -> Vec<Row> {
+        vec![
+            Row {
+                name: "carol",
+                age: Some(30),
+            },
+            Row {
+                name: "Alice",
+                age: None,
+            },
+            Row {
+                name: "bob",
+                age: Some(25),
+            },
+            Row {
+                name: "alice",
+                age: Some(30),
+            },
+        ]
+    }
+
+    fn columns() -> Vec<DataTableColumn<Row>> {
+        vec![
+            DataTableColumn::new("name", "Name", |r: Row| r.name),
+            DataTableColumn::new("age", "Age", |r: Row| r.age),
+        ]
+    }
+
+    #[test]
+    fn test_next_sort_state_cycles() {
+        let state = next_sort_state(&[], "name", false);
+        assert_eq!(state, vec![DataTableSort::ascending("name")]);
+
+        let state = next_sort_state(&state, "name", false);
+        assert_eq!(state, vec![DataTableSort::descending("name")]);
+
+        let state = next_sort_state(&state, "name", false);
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_next_sort_state_replaces_without_multi() {
+        let state = vec![DataTableSort::ascending("name")];
+        let state = next_sort_state(&state, "age", false);
+        assert_eq!(state, vec![DataTableSort::ascending("age")]);
+    }
+
+    #[test]
+    fn test_next_sort_state_multi() {
+        let state = vec![DataTableSort::ascending("name")];
+        let state = next_sort_state(&state, "age", true);
+        assert_eq!(
+            state,
+            vec![
+                DataTableSort::ascending("name"),
+                DataTableSort::ascending("age")
+            ]
+        );
+
+        let state = next_sort_state(&state, "name", true);
+        assert_eq!(state[0], DataTableSort::descending("name"));
+
+        let state = next_sort_state(&state, "name", true);
+        assert_eq!(state, vec![DataTableSort::ascending("age")]);
+    }
+
+    #[test]
+    fn test_sort_rows_single_column() {
+        let data = data();
+        let rows = sort_rows(
+            &data,
+            (0..data.len()).collect(),
+            &columns(),
+            &[DataTableSort::ascending("name")],
+        );
+        assert_eq!(rows, vec![1, 3, 2, 0]);
+    }
+
+    #[test]
+    fn test_sort_rows_empty_values_last() {
+        let data = data();
+        let rows = sort_rows(
+            &data,
+            (0..data.len()).collect(),
+            &columns(),
+            &[DataTableSort::descending("age")],
+        );
+        assert_eq!(rows, vec![0, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_sort_rows_multi_column() {
+        let data = data();
+        let rows = sort_rows(
+            &data,
+            (0..data.len()).collect(),
+            &columns(),
+            &[
+                DataTableSort::descending("age"),
+                DataTableSort::ascending("name"),
+            ],
+        );
+        assert_eq!(rows, vec![3, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_sort_rows_custom_comparator() {
+        let data = data();
+        let columns = vec![
+            DataTableColumn::new("name", "Name", |r: Row| r.name)
+                .comparator(|a: Row, b: Row| a.name.len().cmp(&b.name.len())),
+        ];
+        let rows = sort_rows(
+            &data,
+            (0..data.len()).collect(),
+            &columns,
+            &[DataTableSort::ascending("name")],
+        );
+        assert_eq!(rows, vec![2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn test_sort_rows_subset() {
+        let data = data();
+        let rows = sort_rows(
+            &data,
+            vec![3, 0, 2],
+            &columns(),
+            &[DataTableSort::ascending("age")],
+        );
+        assert_eq!(rows, vec![2, 3, 0]);
+    }
+
+    #[test]
+    fn test_sort_rows_ignores_unknown_columns() {
+        let data = data();
+        let rows = sort_rows(
+            &data,
+            (0..data.len()).collect(),
+            &columns(),
+            &[DataTableSort::ascending("missing")],
+        );
+        assert_eq!(rows, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,229 @@
+//! Row virtualization for the data table
+//!
+//! Only the rows intersecting the scroll viewport (plus an overscan margin)
+//! are mounted; spacer rows stand in for the rest. The viewport is the
+//! nearest enclosing [`ScrollArea`](crate::ScrollArea), or the window.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+/// Selector of the scroll container rows are virtualized against
+const VIEWPORT_SELECTOR: &str = ".scroll-area-viewport";
+
+/// Top offset of every row plus the total height (`heights.len() + 1` entries)
+pub fn row_offsets(heights: impl IntoIterator<Item = f64>) -> Vec<f64> {
+    let mut offsets = vec![0.0];
+    let mut total = 0.0;
+    for height in heights {
+        total += height;
+        offsets.push(total);
+    }
+    offsets
+}
+
+/// Rows to mount for a viewport spanning `view_start..view_end`
+///
+/// Coordinates are relative to the top of the first row. The range is
+/// widened by `overscan` rows on each side and snapped to multiples of
+/// `overscan`, so scrolling re-renders once every `overscan` rows.
+pub fn visible_rows(
+    offsets: &[f64],
+    view_start: f64,
+    view_end: f64,
+    overscan: usize,
+) -> Range<usize> {
+    let count = offsets.len().saturating_sub(1);
+    if count == 0 {
+        return 0..0;
+    }
+    let first = offsets[1..].partition_point(|&bottom| bottom <= view_start);
+    let last = offsets[..count].partition_point(|&top| top < view_end);
+
+    let step = overscan.max(1);
+    let start = (first.saturating_sub(overscan) / step * step).min(count);
+    let end = ((last + overscan).div_ceil(step) * step).clamp(start, count);
+    start..end
+}
+
+/// Heights of the spacers before and after the mounted rows
+pub fn spacer_heights(offsets: &[f64], rows: &Range<usize>) -> (f64, f64) {
+    let Some(&total) = offsets.last() else {
+        return (0.0, 0.0);
+    };
+    let before = offsets.get(rows.start).copied().unwrap_or(total);
+    let after = total - offsets.get(rows.end).copied().unwrap_or(total);
+    (before, after)
+}
+
+/// Compute the rows to mount from the current scroll position
+fn current_rows(body_ref: &NodeRef, offsets: &[f64], overscan: usize) -> Option<Range<usize>> {
+    let body = body_ref.cast::<Element>()?;
+    let body_top = body.get_bounding_client_rect().top();
+    let (top, bottom) = match body.closest(VIEWPORT_SELECTOR).ok().flatten() {
+        Some(viewport) => {
+            let rect = viewport.get_bounding_client_rect();
+            (rect.top(), rect.bottom())
+        }
+        None => {
+            let height = gloo::utils::window()
+                .inner_height()
+                .ok()
+                .and_then(|h| h.as_f64())
+                .unwrap_or_default();
+            (0.0, height)
+        }
+    };
+    Some(visible_rows(
+        offsets,
+        top - body_top,
+        bottom - body_top,
+        overscan,
+    ))
+}
+
+/// Track the rows of `body_ref` (a `<tbody>`) that intersect the viewport
+///
+/// `offsets` are the row offsets of the current render. Scrolling and
+/// resizing only cause a re-render when the returned range changes.
+#[hook]
+pub(crate) fn use_visible_rows(
+    body_ref: NodeRef,
+    offsets: Rc<Vec<f64>>,
+    overscan: usize,
+    enabled: bool,
+) -> Range<usize> {
+    let rows = use_state_eq(|| 0..0);
+    let latest = use_mut_ref(Rc::default);
+    *latest.borrow_mut() = offsets;
+
+    // Offsets change with data, filters, sorting and measured heights
+    {
+        let rows = rows.clone();
+        let body_ref = body_ref.clone();
+        let latest = latest.clone();
+        use_effect(move || {
+            if enabled && let Some(range) = current_rows(&body_ref, &latest.borrow(), overscan) {
+                rows.set(range);
+            }
+        });
+    }
+
+    {
+        let rows = rows.clone();
+        use_effect_with((body_ref, enabled), move |(body_ref, enabled)| {
+            let listeners = enabled.then(|| {
+                let update = {
+                    let body_ref = body_ref.clone();
+                    Rc::new(move || {
+                        let offsets: Rc<Vec<f64>> = latest.borrow().clone();
+                        if let Some(range) = current_rows(&body_ref, &offsets, overscan) {
+                            rows.set(range);
+                        }
+                    })
+                };
+                let viewport = body_ref
+                    .cast::<Element>()
+                    .and_then(|body| body.closest(VIEWPORT_SELECTOR).ok().flatten());
+                let on_scroll = {
+                    let update = update.clone();
+                    move |_: &Event| update()
+                };
+                let scroll = match viewport {
+                    Some(viewport) => EventListener::new(&viewport, "scroll", on_scroll),
+                    None => EventListener::new(&gloo::utils::window(), "scroll", on_scroll),
+                };
+                let resize =
+                    EventListener::new(&gloo::utils::window(), "resize", move |_| update());
+                (scroll, resize)
+            });
+            move || drop(listeners)
+        });
+    }
+
+    if enabled { (*rows).clone() } else { 0..0 }
+}
+
+/// Record the rendered height of each mounted row of `body_ref`
+///
+/// Rows are identified by their `data-row-id` attribute. Returns `true` if
+/// any height changed.
+pub(crate) fn measure_row_heights(
+    body_ref: &NodeRef,
+    heights: &RefCell<HashMap<AttrValue, f64>>,
+) -> bool {
+    let Some(body) = body_ref.cast::<Element>() else {
+        return false;
+    };
+    let children = body.children();
+    let mut heights = heights.borrow_mut();
+    let mut changed = false;
+    for index in 0..children.length() {
+        let Some(row) = children.item(index) else {
+            continue;
+        };
+        let Some(id) = row.get_attribute("data-row-id") else {
+            continue;
+        };
+        let Some(row) = row.dyn_ref::<HtmlElement>() else {
+            continue;
+        };
+        let height = f64::from(row.offset_height());
+        let previous = heights.insert(AttrValue::from(id), height);
+        changed |= previous.is_none_or(|previous| (previous - height).abs() > 0.5);
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_offsets() {
+        assert_eq!(row_offsets([10.0, 20.0, 30.0]), vec![0.0, 10.0, 30.0, 60.0]);
+        assert_eq!(row_offsets([]), vec![0.0]);
+    }
+
+    #[test]
+    fn test_visible_rows() {
+        let offsets = row_offsets(std::iter::repeat_n(40.0, 1000));
+
+        // Rows 25..35 are in view; widened by 5 and snapped to multiples of 5
+        assert_eq!(visible_rows(&offsets, 1000.0, 1400.0, 5), 20..40);
+        // Partially visible rows count
+        assert_eq!(visible_rows(&offsets, 1010.0, 1390.0, 5), 20..40);
+        assert_eq!(visible_rows(&offsets, 1010.0, 1390.0, 0), 25..35);
+    }
+
+    #[test]
+    fn test_visible_rows_edges() {
+        let offsets = row_offsets(std::iter::repeat_n(40.0, 100));
+
+        assert_eq!(visible_rows(&offsets, -500.0, 200.0, 5), 0..10);
+        assert_eq!(visible_rows(&offsets, -500.0, -100.0, 5), 0..5);
+        assert_eq!(visible_rows(&offsets, 3800.0, 4200.0, 5), 90..100);
+        assert_eq!(visible_rows(&offsets, 9000.0, 9400.0, 5), 95..100);
+        assert_eq!(visible_rows(&[0.0], 0.0, 400.0, 5), 0..0);
+    }
+
+    #[test]
+    fn test_visible_rows_variable_heights() {
+        let offsets = row_offsets([100.0, 10.0, 10.0, 10.0, 100.0]);
+        assert_eq!(visible_rows(&offsets, 105.0, 125.0, 0), 1..4);
+    }
+
+    #[test]
+    fn test_spacer_heights() {
+        let offsets = row_offsets(std::iter::repeat_n(40.0, 100));
+        assert_eq!(spacer_heights(&offsets, &(20..40)), (800.0, 2400.0));
+        assert_eq!(spacer_heights(&offsets, &(0..100)), (0.0, 0.0));
+        assert_eq!(spacer_heights(&[0.0], &(0..0)), (0.0, 0.0));
+    }
+}
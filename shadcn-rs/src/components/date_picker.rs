@@ -5,47 +5,60 @@
 //! # Examples
 //!
 //! ```rust,no_run
+//! use chrono::NaiveDate;
 //! use yew::prelude::*;
-//! use shadcn_rs::{DatePicker, Button};
+//! use shadcn_rs::DatePicker;
 //!
 //! #[function_component(App)]
 //! fn app() -> Html {
-//!     let date = use_state(|| None::<String>);
+//!     let date = use_state(|| None::<NaiveDate>);
 //!
-//!     let onchange = {
+//!     let on_change = {
 //!         let date = date.clone();
-//!         Callback::from(move |new_date: String| {
-//!             date.set(Some(new_date));
+//!         Callback::from(move |new_date: Option<NaiveDate>| {
+//!             date.set(new_date);
 //!         })
 //!     };
 //!
 //!     html! {
 //!         <DatePicker
-//!             value={(*date).clone()}
-//!             {onchange}
+//!             value={*date}
+//!             {on_change}
+//!             editable=true
 //!             placeholder="Pick a date"
 //!         />
 //!     }
 //! }
 //! ```
 
-use crate::hooks::use_toggle;
+use chrono::NaiveDate;
 use yew::prelude::*;
 
+use crate::components::calendar::{Calendar, is_date_disabled, parse_date};
+use crate::components::input::Input;
+use crate::components::locale::{Locale, use_locale};
+use crate::hooks::{
+    use_click_outside_conditional, use_controllable_state, use_escape_key_conditional, use_toggle,
+};
+use crate::utils::generate_id;
+
 /// Date picker component properties
 #[derive(Properties, PartialEq, Clone)]
 pub struct DatePickerProps {
-    /// Selected date value (ISO format YYYY-MM-DD)
+    /// Selected date (controlled)
+    ///
+    /// `value={date}` with an `Option<NaiveDate>` controls the picker, `None`
+    /// being no date; leave the prop out for an uncontrolled picker.
     #[prop_or_default]
-    pub value: Option<AttrValue>,
+    pub value: Option<Option<NaiveDate>>,
 
-    /// Default value (uncontrolled)
+    /// Initially selected date (uncontrolled)
     #[prop_or_default]
-    pub default_value: Option<AttrValue>,
+    pub default_value: Option<NaiveDate>,
 
-    /// Change event handler
+    /// Called with the new date when a date is picked, typed or cleared
     #[prop_or_default]
-    pub onchange: Option<Callback<String>>,
+    pub on_change: Option<Callback<Option<NaiveDate>>>,
 
     /// Placeholder text
     #[prop_or(AttrValue::from("Select date"))]
@@ -55,6 +68,11 @@ pub struct DatePickerProps {
     #[prop_or(false)]
     pub disabled: bool,
 
+    /// Let the user type the date in the display format, besides picking
+    /// it from the calendar
+    #[prop_or(false)]
+    pub editable: bool,
+
     /// Minimum selectable date
     #[prop_or_default]
     pub min_date: Option<AttrValue>,
@@ -63,9 +81,10 @@ pub struct DatePickerProps {
     #[prop_or_default]
     pub max_date: Option<AttrValue>,
 
-    /// Date format for display
-    #[prop_or(AttrValue::from("MM/DD/YYYY"))]
-    pub format: AttrValue,
+    /// Display format pattern, such as `DD.MM.YYYY` (see [`Locale`](crate::Locale)),
+    /// defaults to the date format of the locale
+    #[prop_or_default]
+    pub format: Option<AttrValue>,
 
     /// Additional CSS classes
     #[prop_or_default]
@@ -74,7 +93,12 @@ pub struct DatePickerProps {
 
 /// Date picker component
 ///
-/// Combines an input field with a calendar popup for date selection.
+/// Combines a trigger with a calendar popup for date selection. With
+/// `editable`, the trigger is a text input: the typed text is parsed with
+/// the display format (ISO dates are accepted too) when the input loses
+/// focus or on `Enter`, and text that is not a selectable date puts the
+/// input in its error state until it is corrected. Clearing the text
+/// clears the date.
 ///
 /// # Accessibility
 /// - Full keyboard navigation
@@ -85,22 +109,38 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
     let DatePickerProps {
         value,
         default_value,
-        onchange: _,
+        on_change,
         placeholder,
         disabled,
-        min_date: _,
-        max_date: _,
-        format: _,
+        editable,
+        min_date,
+        max_date,
+        format,
         class,
     } = props.clone();
 
-    // Internal state for uncontrolled mode
-    let internal_value = use_state(|| value.clone().or_else(|| default_value.clone()));
+    let (current_value, set_value) = use_controllable_state(value, default_value, on_change);
+
+    let locale = use_locale();
+    let format = format.unwrap_or_else(|| locale.date_format.clone());
+    let display_value = current_value.map(|date| locale.format(date, &format));
 
-    let current_value = value.or_else(|| (*internal_value).clone());
+    // Text typed into the editable input, until it is committed
+    let draft = use_state(|| None::<String>);
+    let invalid = use_state(|| false);
+    let error_id = use_state(|| generate_id("date-picker-error"));
 
     // Popover open state
-    let (is_open, toggle, _set_open) = use_toggle(false);
+    let (is_open, toggle, set_open) = use_toggle(false);
+    let root_ref = use_node_ref();
+    {
+        let set_open = set_open.clone();
+        use_click_outside_conditional(root_ref.clone(), move || set_open.emit(false), is_open);
+    }
+    {
+        let set_open = set_open.clone();
+        use_escape_key_conditional(move || set_open.emit(false), is_open);
+    }
 
     let classes: Classes = vec![
         Classes::from("date-picker"),
@@ -132,8 +172,87 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
         })
     };
 
-    html! {
-        <div class={classes}>
+    let on_select = {
+        let set_value = set_value.clone();
+        let draft = draft.clone();
+        let invalid = invalid.clone();
+        Callback::from(move |date: NaiveDate| {
+            set_value.emit(Some(date));
+            draft.set(None);
+            invalid.set(false);
+            set_open.emit(false);
+        })
+    };
+
+    let commit_draft = {
+        let draft = draft.clone();
+        let invalid = invalid.clone();
+        let locale = locale.clone();
+        let format = format.clone();
+        let min = min_date.as_deref().and_then(parse_date);
+        let max = max_date.as_deref().and_then(parse_date);
+        Callback::from(move |_: ()| {
+            let Some(text) = (*draft).clone() else {
+                return;
+            };
+            match parse_entry(&text, &locale, &format, min, max) {
+                Ok(date) => {
+                    set_value.emit(date);
+                    draft.set(None);
+                    invalid.set(false);
+                }
+                Err(()) => invalid.set(true),
+            }
+        })
+    };
+
+    let trigger = if editable {
+        let oninput = {
+            let draft = draft.clone();
+            Callback::from(move |e: InputEvent| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                draft.set(Some(input.value()));
+            })
+        };
+        let onblur = commit_draft.reform(|_: FocusEvent| ());
+        let onkeydown = {
+            let commit_draft = commit_draft.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                if e.key() == "Enter" {
+                    e.prevent_default();
+                    commit_draft.emit(());
+                }
+            })
+        };
+        let text = (*draft).clone().or(display_value).unwrap_or_default();
+        html! {
+            <div class="date-picker-field">
+                <Input
+                    value={AttrValue::from(text)}
+                    placeholder={placeholder}
+                    {disabled}
+                    error={*invalid}
+                    aria_invalid={(*invalid).then_some(true)}
+                    aria_describedby={(*invalid).then(|| (*error_id).clone())}
+                    {oninput}
+                    {onblur}
+                    {onkeydown}
+                />
+                <button
+                    type="button"
+                    class="date-picker-calendar-button"
+                    onclick={toggle_calendar}
+                    {disabled}
+                    aria-label={locale.choose_date_label.clone()}
+                    aria-haspopup="dialog"
+                    aria-expanded={is_open.to_string()}
+                >
+                    { "📅" }
+                </button>
+            </div>
+        }
+    } else {
+        html! {
             <button
                 type="button"
                 class={button_classes}
@@ -143,7 +262,7 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
                 aria-expanded={is_open.to_string()}
             >
                 {
-                    if let Some(date_value) = current_value {
+                    if let Some(date_value) = display_value {
                         html! { <span class="date-picker-value">{ date_value }</span> }
                     } else {
                         html! { <span class="date-picker-placeholder">{ placeholder }</span> }
@@ -151,17 +270,53 @@ pub fn date_picker(props: &DatePickerProps) -> Html {
                 }
                 <span class="date-picker-icon">{ "📅" }</span>
             </button>
+        }
+    };
+
+    html! {
+        <div class={classes} ref={root_ref}>
+            { trigger }
+            if *invalid {
+                <p class="date-picker-error" id={(*error_id).clone()}>
+                    { locale.invalid_date_message.replace("{format}", &format) }
+                </p>
+            }
             if is_open {
-                <div class="date-picker-popover">
-                    <div class="date-picker-calendar">
-                        { "Calendar would be rendered here" }
-                    </div>
+                <div class="date-picker-popover" role="dialog" aria-label={locale.choose_date_label.clone()}>
+                    <Calendar
+                        value={current_value}
+                        on_change={on_select}
+                        {min_date}
+                        {max_date}
+                    />
                 </div>
             }
         </div>
     }
 }
 
+/// Parse the text typed into an editable picker
+///
+/// Blank text clears the date. Otherwise the text is parsed with the
+/// display format, or as an ISO date, and must be a selectable date.
+fn parse_entry(
+    text: &str,
+    locale: &Locale,
+    format: &str,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+) -> Result<Option<NaiveDate>, ()> {
+    if text.trim().is_empty() {
+        return Ok(None);
+    }
+    locale
+        .parse(text, format)
+        .or_else(|| parse_date(text))
+        .filter(|date| !is_date_disabled(*date, min, max, &[]))
+        .map(Some)
+        .ok_or(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,12 +326,13 @@ mod tests {
         let props = DatePickerProps {
             value: None,
             default_value: None,
-            onchange: None,
+            on_change: None,
             placeholder: AttrValue::from("Select date"),
             disabled: false,
+            editable: false,
             min_date: None,
             max_date: None,
-            format: AttrValue::from("MM/DD/YYYY"),
+            format: None,
             class: Classes::new(),
         };
 
@@ -187,18 +343,19 @@ mod tests {
     #[test]
     fn test_date_picker_with_value() {
         let props = DatePickerProps {
-            value: Some(AttrValue::from("2024-01-15")),
+            value: Some(NaiveDate::from_ymd_opt(2024, 1, 15)),
             default_value: None,
-            onchange: None,
+            on_change: None,
             placeholder: AttrValue::from("Select date"),
             disabled: false,
+            editable: false,
             min_date: None,
             max_date: None,
-            format: AttrValue::from("MM/DD/YYYY"),
+            format: None,
             class: Classes::new(),
         };
 
-        assert_eq!(props.value, Some(AttrValue::from("2024-01-15")));
+        assert_eq!(props.value, Some(NaiveDate::from_ymd_opt(2024, 1, 15)));
     }
 
     #[test]
@@ -206,12 +363,13 @@ mod tests {
         let props = DatePickerProps {
             value: None,
             default_value: None,
-            onchange: None,
+            on_change: None,
             placeholder: AttrValue::from("Select date"),
             disabled: true,
+            editable: false,
             min_date: None,
             max_date: None,
-            format: AttrValue::from("MM/DD/YYYY"),
+            format: None,
             class: Classes::new(),
         };
 
@@ -223,16 +381,64 @@ mod tests {
         let props = DatePickerProps {
             value: None,
             default_value: None,
-            onchange: None,
+            on_change: None,
             placeholder: AttrValue::from("Select date"),
             disabled: false,
+            editable: false,
             min_date: None,
             max_date: None,
-            format: AttrValue::from("YYYY-MM-DD"),
+            format: Some(AttrValue::from("YYYY-MM-DD")),
             class: Classes::new(),
         };
 
-        assert_eq!(props.format, AttrValue::from("YYYY-MM-DD"));
+        assert_eq!(props.format, Some(AttrValue::from("YYYY-MM-DD")));
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let de = Locale::de();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 1);
+        assert_eq!(
+            parse_entry("1.6.2024", &de, "DD.MM.YYYY", None, None),
+            Ok(date)
+        );
+        assert_eq!(
+            parse_entry("2024-06-01", &de, "DD.MM.YYYY", None, None),
+            Ok(date)
+        );
+        assert_eq!(
+            parse_entry("1.13.2024", &de, "DD.MM.YYYY", None, None),
+            Err(())
+        );
+        assert_eq!(
+            parse_entry("tomorrow", &de, "DD.MM.YYYY", None, None),
+            Err(())
+        );
+
+        let min = NaiveDate::from_ymd_opt(2024, 7, 1);
+        assert_eq!(
+            parse_entry("01.06.2024", &de, "DD.MM.YYYY", min, None),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn test_parse_entry_clears_on_blank_text() {
+        let en = Locale::en();
+        assert_eq!(parse_entry("", &en, "MM/DD/YYYY", None, None), Ok(None));
+        assert_eq!(parse_entry("   ", &en, "MM/DD/YYYY", None, None), Ok(None));
+    }
+
+    #[test]
+    fn test_controlled_clear_stays_controlled() {
+        // After the text is cleared the parent passes back `None`, which must
+        // not fall back to the internal state of an uncontrolled picker
+        let cleared: Option<NaiveDate> = None;
+        let props = yew::props!(DatePickerProps { value: cleared });
+        assert_eq!(props.value, Some(None));
+
+        let props = yew::props!(DatePickerProps {});
+        assert_eq!(props.value, None);
     }
 
     #[test]
@@ -240,12 +446,13 @@ mod tests {
         let props = DatePickerProps {
             value: None,
             default_value: None,
-            onchange: None,
+            on_change: None,
             placeholder: AttrValue::from("Select date"),
             disabled: false,
+            editable: false,
             min_date: Some(AttrValue::from("2024-01-01")),
             max_date: Some(AttrValue::from("2024-12-31")),
-            format: AttrValue::from("MM/DD/YYYY"),
+            format: None,
             class: Classes::new(),
         };
 
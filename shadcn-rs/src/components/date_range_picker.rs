@@ -0,0 +1,456 @@
+//! Date Range Picker component
+//!
+//! A trigger opening a popover with range presets and a range calendar.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use chrono::NaiveDate;
+//! use yew::prelude::*;
+//! use shadcn_rs::{DateRange, DateRangePicker, DateRangePreset, use_locale};
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     let locale = use_locale();
+//!     let range = use_state(|| None::<DateRange>);
+//!
+//!     let on_change = {
+//!         let range = range.clone();
+//!         Callback::from(move |new_range: DateRange| range.set(Some(new_range)))
+//!     };
+//!
+//!     let mut presets = DateRangePreset::defaults(&locale);
+//!     presets.push(DateRangePreset::last_days(&locale, 90));
+//!
+//!     html! {
+//!         <DateRangePicker value={*range} {on_change} {presets} />
+//!     }
+//! }
+//! ```
+
+use chrono::{Datelike, Days, Local, NaiveDate};
+use yew::prelude::*;
+
+use crate::components::calendar::{
+    Calendar, CalendarMode, DateRange, add_months, is_range_length_allowed, month_end, month_start,
+    parse_date,
+};
+use crate::components::locale::{Locale, use_locale};
+use crate::components::popover::{Popover, PopoverContent, PopoverTrigger};
+use crate::hooks::use_controllable_state_optional;
+
+/// A named range offered in the preset sidebar of a [`DateRangePicker`]
+#[derive(Clone, PartialEq)]
+pub struct DateRangePreset {
+    /// Button label
+    pub label: AttrValue,
+    /// Range of the preset, computed from today's date
+    pub range: Callback<NaiveDate, DateRange>,
+}
+
+impl DateRangePreset {
+    /// Create a preset from a label and a function of today's date
+    pub fn new(
+        label: impl Into<AttrValue>,
+        range: impl Fn(NaiveDate) -> DateRange + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            range: Callback::from(range),
+        }
+    }
+
+    /// Today only
+    pub fn today(locale: &Locale) -> Self {
+        Self::new(locale.today_label.clone(), |today| {
+            DateRange::new(today, today)
+        })
+    }
+
+    /// The last `days` days, today included
+    pub fn last_days(locale: &Locale, days: u32) -> Self {
+        let label = locale.last_days_label.replace("{days}", &days.to_string());
+        Self::new(label, move |today| {
+            let start = today
+                .checked_sub_days(Days::new(u64::from(days.max(1) - 1)))
+                .unwrap_or(today);
+            DateRange::new(start, today)
+        })
+    }
+
+    /// The current calendar month, from its first to its last day
+    pub fn this_month(locale: &Locale) -> Self {
+        Self::new(locale.this_month_label.clone(), |today| {
+            DateRange::new(month_start(today), month_end(today))
+        })
+    }
+
+    /// The calendar quarter before the current one
+    pub fn last_quarter(locale: &Locale) -> Self {
+        Self::new(locale.last_quarter_label.clone(), |today| {
+            let quarter_start = add_months(today, -(today.month0() as i32 % 3));
+            let start = add_months(quarter_start, -3);
+            DateRange::new(start, month_end(add_months(start, 2)))
+        })
+    }
+
+    /// Today, last 7 days, this month and last quarter, labelled in the
+    /// language of the locale
+    pub fn defaults(locale: &Locale) -> Vec<Self> {
+        vec![
+            Self::today(locale),
+            Self::last_days(locale, 7),
+            Self::this_month(locale),
+            Self::last_quarter(locale),
+        ]
+    }
+
+    /// Range of the preset on a given day
+    pub fn range_on(&self, today: NaiveDate) -> DateRange {
+        self.range.emit(today)
+    }
+}
+
+/// Date range picker component properties
+#[derive(Properties, PartialEq, Clone)]
+pub struct DateRangePickerProps {
+    /// Selected range (controlled)
+    #[prop_or_default]
+    pub value: Option<DateRange>,
+
+    /// Initially selected range (uncontrolled)
+    #[prop_or_default]
+    pub default_value: Option<DateRange>,
+
+    /// Called with the new range when a preset is chosen or a range is
+    /// completed on the calendar
+    #[prop_or_default]
+    pub on_change: Option<Callback<DateRange>>,
+
+    /// Ranges offered in the sidebar, defaulting to
+    /// [`DateRangePreset::defaults`] in the locale; an empty list hides the
+    /// sidebar
+    #[prop_or_default]
+    pub presets: Option<Vec<DateRangePreset>>,
+
+    /// Number of months shown side by side
+    #[prop_or(2)]
+    pub number_of_months: u32,
+
+    /// Placeholder text
+    #[prop_or(AttrValue::from("Pick a date range"))]
+    pub placeholder: AttrValue,
+
+    /// Disabled state
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Minimum selectable date
+    #[prop_or_default]
+    pub min_date: Option<AttrValue>,
+
+    /// Maximum selectable date
+    #[prop_or_default]
+    pub max_date: Option<AttrValue>,
+
+    /// Minimum number of days of a range, bounds included
+    #[prop_or_default]
+    pub min_range_days: Option<u32>,
+
+    /// Maximum number of days of a range, bounds included
+    #[prop_or_default]
+    pub max_range_days: Option<u32>,
+
+    /// Display format pattern, such as `DD.MM.YYYY` (see [`Locale`](crate::Locale)),
+    /// defaults to the date format of the locale
+    #[prop_or_default]
+    pub format: Option<AttrValue>,
+
+    /// Additional CSS classes
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Date range picker component
+///
+/// Shows the selected range on a trigger button that opens a popover with
+/// a preset sidebar and a range calendar. Choosing a preset or picking the
+/// second day of a range on the calendar selects it and closes the popover.
+/// A preset is shown as active while the selected range equals its range
+/// for today. Preset ranges are cut to `min_date` and `max_date`, and a
+/// preset is disabled when nothing of it is left or its length is not
+/// allowed.
+///
+/// # Accessibility
+/// - Calendar keyboard navigation
+/// - Presets are toggle buttons with `aria-pressed`
+/// - Closes on Escape key and click outside
+#[function_component(DateRangePicker)]
+pub fn date_range_picker(props: &DateRangePickerProps) -> Html {
+    let DateRangePickerProps {
+        value,
+        default_value,
+        on_change,
+        presets,
+        number_of_months,
+        placeholder,
+        disabled,
+        min_date,
+        max_date,
+        min_range_days,
+        max_range_days,
+        format,
+        class,
+    } = props.clone();
+
+    let on_change = on_change.map(|on_change| {
+        Callback::from(move |range: Option<DateRange>| {
+            if let Some(range) = range {
+                on_change.emit(range);
+            }
+        })
+    });
+    let (current_value, set_value) =
+        use_controllable_state_optional(value, default_value, on_change);
+
+    let locale = use_locale();
+    let format = format.unwrap_or_else(|| locale.date_format.clone());
+    let display_value = current_value.map(|range| {
+        format!(
+            "{} – {}",
+            locale.format(range.start, &format),
+            locale.format(range.end, &format)
+        )
+    });
+
+    let is_open = use_state(|| false);
+    let trigger_ref = use_node_ref();
+    let today = Local::now().date_naive();
+
+    // Only opens: the trigger is outside the popover, so pressing it while
+    // open already closes the popover before the click
+    let open = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: MouseEvent| is_open.set(true))
+    };
+    let close = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: ()| is_open.set(false))
+    };
+    let select = {
+        let is_open = is_open.clone();
+        Callback::from(move |range: DateRange| {
+            set_value.emit(Some(range));
+            is_open.set(false);
+        })
+    };
+
+    let classes: Classes = vec![Classes::from("date-range-picker"), class]
+        .into_iter()
+        .collect();
+
+    let trigger_classes: Classes = vec![
+        Classes::from("date-range-picker-trigger"),
+        if disabled {
+            Classes::from("date-range-picker-trigger-disabled")
+        } else {
+            Classes::new()
+        },
+    ]
+    .into_iter()
+    .collect();
+
+    let presets = presets.unwrap_or_else(|| DateRangePreset::defaults(&locale));
+    let (min, max) = (
+        min_date.as_deref().and_then(parse_date),
+        max_date.as_deref().and_then(parse_date),
+    );
+    let preset_buttons = presets.iter().map(|preset| {
+        let range = clamp_range(preset.range_on(today), min, max)
+            .filter(|range| is_range_length_allowed(range, min_range_days, max_range_days));
+        let active = range.is_some() && current_value == range;
+        let onclick = range.map(|range| select.reform(move |_: MouseEvent| range));
+        html! {
+            <button
+                type="button"
+                class={classes!("date-range-picker-preset", active.then_some("active"))}
+                aria-pressed={active.to_string()}
+                disabled={range.is_none()}
+                {onclick}
+            >
+                { preset.label.clone() }
+            </button>
+        }
+    });
+
+    html! {
+        <div class={classes}>
+            <Popover>
+                <PopoverTrigger>
+                    <button
+                        ref={trigger_ref.clone()}
+                        type="button"
+                        class={trigger_classes}
+                        onclick={open}
+                        {disabled}
+                        aria-haspopup="dialog"
+                        aria-expanded={is_open.to_string()}
+                    >
+                        <span class="date-range-picker-icon">{ "📅" }</span>
+                        {
+                            if let Some(range_value) = display_value {
+                                html! { <span class="date-range-picker-value">{ range_value }</span> }
+                            } else {
+                                html! { <span class="date-range-picker-placeholder">{ placeholder }</span> }
+                            }
+                        }
+                    </button>
+                </PopoverTrigger>
+                <PopoverContent
+                    open={*is_open}
+                    on_close={close}
+                    anchor={trigger_ref}
+                    class="date-range-picker-content"
+                >
+                    if !presets.is_empty() {
+                        <div class="date-range-picker-presets" role="group" aria-label={locale.presets_label.clone()}>
+                            { for preset_buttons }
+                        </div>
+                    }
+                    <Calendar
+                        mode={CalendarMode::Range}
+                        range={current_value}
+                        on_range_change={select}
+                        {number_of_months}
+                        {min_date}
+                        {max_date}
+                        {min_range_days}
+                        {max_range_days}
+                    />
+                </PopoverContent>
+            </Popover>
+        </div>
+    }
+}
+
+/// Part of a range between `min` and `max`, or `None` if it lies outside
+fn clamp_range(
+    range: DateRange,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+) -> Option<DateRange> {
+    let start = min.map_or(range.start, |min| range.start.max(min));
+    let end = max.map_or(range.end, |max| range.end.min(max));
+    (start <= end).then_some(DateRange { start, end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_default_presets() {
+        let today = date(2024, 3, 5);
+        let ranges: Vec<DateRange> = DateRangePreset::defaults(&Locale::en())
+            .iter()
+            .map(|preset| preset.range_on(today))
+            .collect();
+
+        assert_eq!(ranges[0], DateRange::new(today, today));
+        assert_eq!(ranges[1], DateRange::new(date(2024, 2, 28), today));
+        assert_eq!(ranges[1].days(), 7);
+        assert_eq!(
+            ranges[2],
+            DateRange::new(date(2024, 3, 1), date(2024, 3, 31))
+        );
+        assert_eq!(
+            ranges[3],
+            DateRange::new(date(2023, 10, 1), date(2023, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_last_quarter() {
+        let preset = DateRangePreset::last_quarter(&Locale::en());
+        assert_eq!(
+            preset.range_on(date(2024, 6, 30)),
+            DateRange::new(date(2024, 1, 1), date(2024, 3, 31))
+        );
+        assert_eq!(
+            preset.range_on(date(2024, 7, 1)),
+            DateRange::new(date(2024, 4, 1), date(2024, 6, 30))
+        );
+    }
+
+    #[test]
+    fn test_localized_presets() {
+        let labels: Vec<AttrValue> = DateRangePreset::defaults(&Locale::de())
+            .into_iter()
+            .map(|preset| preset.label)
+            .collect();
+        assert_eq!(
+            labels,
+            ["Heute", "Letzte 7 Tage", "Dieser Monat", "Letztes Quartal"]
+        );
+        assert_eq!(
+            DateRangePreset::last_days(&Locale::fr(), 30).label,
+            "30 derniers jours"
+        );
+    }
+
+    #[test]
+    fn test_clamp_range() {
+        let week = DateRange::new(date(2024, 3, 1), date(2024, 3, 7));
+        assert_eq!(clamp_range(week, None, None), Some(week));
+        assert_eq!(
+            clamp_range(week, Some(date(2024, 3, 4)), Some(date(2024, 3, 31))),
+            Some(DateRange::new(date(2024, 3, 4), date(2024, 3, 7)))
+        );
+        assert_eq!(
+            clamp_range(week, None, Some(date(2024, 3, 1))),
+            Some(DateRange::new(date(2024, 3, 1), date(2024, 3, 1)))
+        );
+        assert_eq!(clamp_range(week, Some(date(2024, 3, 8)), None), None);
+        assert_eq!(clamp_range(week, None, Some(date(2024, 2, 29))), None);
+    }
+
+    #[test]
+    fn test_custom_preset() {
+        let preset = DateRangePreset::new("Next 3 days", |today: NaiveDate| {
+            DateRange::new(today, today + Days::new(2))
+        });
+        assert_eq!(preset.label, AttrValue::from("Next 3 days"));
+        assert_eq!(preset.range_on(date(2024, 12, 31)).end, date(2025, 1, 2));
+        assert_eq!(
+            DateRangePreset::last_days(&Locale::en(), 30).label,
+            "Last 30 days"
+        );
+    }
+
+    #[test]
+    fn test_date_range_picker_props() {
+        let props = DateRangePickerProps {
+            value: Some(DateRange::new(date(2024, 1, 1), date(2024, 1, 7))),
+            default_value: None,
+            on_change: Some(Callback::from(|_: DateRange| {})),
+            presets: Some(vec![DateRangePreset::today(&Locale::en())]),
+            number_of_months: 2,
+            placeholder: AttrValue::from("Pick a date range"),
+            disabled: false,
+            min_date: None,
+            max_date: None,
+            min_range_days: None,
+            max_range_days: Some(31),
+            format: None,
+            class: Classes::new(),
+        };
+
+        assert_eq!(props.presets.map(|presets| presets.len()), Some(1));
+        assert_eq!(props.number_of_months, 2);
+        assert_eq!(props.value.map(|range| range.days()), Some(7));
+    }
+}
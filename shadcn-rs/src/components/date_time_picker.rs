@@ -0,0 +1,366 @@
+//! Date Time Picker component
+//!
+//! A trigger opening a popover with a calendar and a time picker.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use chrono::{DateTime, FixedOffset, NaiveDateTime};
+//! use yew::prelude::*;
+//! use shadcn_rs::{DateTimePicker, HourCycle};
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     let meeting = use_state(|| None::<DateTime<FixedOffset>>);
+//!
+//!     let on_zoned_change = {
+//!         let meeting = meeting.clone();
+//!         Callback::from(move |at: DateTime<FixedOffset>| meeting.set(Some(at)))
+//!     };
+//!
+//!     html! {
+//!         <DateTimePicker
+//!             value={meeting.map(|at| at.naive_local())}
+//!             utc_offset={FixedOffset::east_opt(3600)}
+//!             {on_zoned_change}
+//!             hour_cycle={HourCycle::H12}
+//!             minute_step={15}
+//!         />
+//!     }
+//! }
+//! ```
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use yew::prelude::*;
+
+use crate::components::calendar::Calendar;
+use crate::components::locale::use_locale;
+use crate::components::popover::{Popover, PopoverContent, PopoverTrigger};
+use crate::components::time_picker::{HourCycle, TimePicker, format_time};
+use crate::hooks::use_controllable_state;
+
+/// A local date and time in a fixed UTC offset, or in the browser's time
+/// zone without one
+///
+/// Returns `None` for local times skipped by a daylight saving change.
+pub fn to_zoned(
+    date_time: NaiveDateTime,
+    utc_offset: Option<FixedOffset>,
+) -> Option<DateTime<FixedOffset>> {
+    match utc_offset {
+        Some(offset) => date_time.and_local_timezone(offset).single(),
+        None => date_time
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|local| local.fixed_offset()),
+    }
+}
+
+/// Label of a UTC offset, such as `UTC+05:30`, or `UTC` for no offset
+pub fn offset_label(offset: FixedOffset) -> String {
+    if offset.local_minus_utc() == 0 {
+        "UTC".to_string()
+    } else {
+        format!("UTC{}", offset)
+    }
+}
+
+/// Date time picker component properties
+#[derive(Properties, PartialEq, Clone)]
+pub struct DateTimePickerProps {
+    /// Selected local date and time (controlled)
+    ///
+    /// `value={date_time}` with an `Option<NaiveDateTime>` controls the
+    /// picker, `None` being no date and time; leave the prop out for an
+    /// uncontrolled picker.
+    #[prop_or_default]
+    pub value: Option<Option<NaiveDateTime>>,
+
+    /// Initially selected local date and time (uncontrolled)
+    #[prop_or_default]
+    pub default_value: Option<NaiveDateTime>,
+
+    /// Called with the new local date and time when the date or time changes
+    #[prop_or_default]
+    pub on_change: Option<Callback<NaiveDateTime>>,
+
+    /// UTC offset the local date and time are in; the browser's time zone
+    /// when not set
+    #[prop_or_default]
+    pub utc_offset: Option<FixedOffset>,
+
+    /// Called with the new date and time in `utc_offset` (see [`to_zoned`])
+    #[prop_or_default]
+    pub on_zoned_change: Option<Callback<DateTime<FixedOffset>>>,
+
+    /// Time of a date picked before any time is set
+    #[prop_or(NaiveTime::MIN)]
+    pub default_time: NaiveTime,
+
+    /// 12-hour or 24-hour clock
+    #[prop_or_default]
+    pub hour_cycle: HourCycle,
+
+    /// Show a seconds field
+    #[prop_or(false)]
+    pub show_seconds: bool,
+
+    /// Increment between the offered minutes
+    #[prop_or(1)]
+    pub minute_step: u32,
+
+    /// Placeholder text
+    #[prop_or(AttrValue::from("Pick a date and time"))]
+    pub placeholder: AttrValue,
+
+    /// Disabled state
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Minimum selectable date
+    #[prop_or_default]
+    pub min_date: Option<AttrValue>,
+
+    /// Maximum selectable date
+    #[prop_or_default]
+    pub max_date: Option<AttrValue>,
+
+    /// Display format pattern of the date, such as `DD.MM.YYYY` (see
+    /// [`Locale`](crate::Locale)), defaults to the date format of the locale
+    #[prop_or_default]
+    pub format: Option<AttrValue>,
+
+    /// Additional CSS classes
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Date time picker component
+///
+/// Shows the selected date and time on a trigger button that opens a
+/// popover with a calendar and a [`TimePicker`]. Picking a date keeps the
+/// selected time (`default_time` before one is set), and changing the time
+/// before a date is picked selects today. The popover stays open while
+/// editing and closes on Escape or a click outside.
+///
+/// With `utc_offset`, the offset is shown next to the time.
+///
+/// # Accessibility
+/// - Calendar keyboard navigation
+/// - Labelled time fields
+/// - Closes on Escape key and click outside
+#[function_component(DateTimePicker)]
+pub fn date_time_picker(props: &DateTimePickerProps) -> Html {
+    let DateTimePickerProps {
+        value,
+        default_value,
+        on_change,
+        utc_offset,
+        on_zoned_change,
+        default_time,
+        hour_cycle,
+        show_seconds,
+        minute_step,
+        placeholder,
+        disabled,
+        min_date,
+        max_date,
+        format,
+        class,
+    } = props.clone();
+
+    let on_value_change = Callback::from(move |date_time: Option<NaiveDateTime>| {
+        let Some(date_time) = date_time else {
+            return;
+        };
+        if let Some(on_change) = &on_change {
+            on_change.emit(date_time);
+        }
+        if let (Some(on_zoned_change), Some(zoned)) =
+            (&on_zoned_change, to_zoned(date_time, utc_offset))
+        {
+            on_zoned_change.emit(zoned);
+        }
+    });
+    let (current_value, set_value) =
+        use_controllable_state(value, default_value, Some(on_value_change));
+
+    let locale = use_locale();
+    let format = format.unwrap_or_else(|| locale.date_format.clone());
+    let display_value = current_value.map(|date_time| {
+        let mut text = format!(
+            "{} {}",
+            locale.format(date_time.date(), &format),
+            format_time(date_time.time(), hour_cycle, show_seconds)
+        );
+        if let Some(offset) = utc_offset {
+            text.push(' ');
+            text.push_str(&offset_label(offset));
+        }
+        text
+    });
+
+    let is_open = use_state(|| false);
+    let trigger_ref = use_node_ref();
+
+    // Only opens: the trigger is outside the popover, so pressing it while
+    // open already closes the popover before the click
+    let open = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: MouseEvent| is_open.set(true))
+    };
+    let close = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: ()| is_open.set(false))
+    };
+
+    let on_date_change = {
+        let set_value = set_value.clone();
+        Callback::from(move |date: NaiveDate| {
+            let time = current_value.map_or(default_time, |value| value.time());
+            set_value.emit(Some(date.and_time(time)));
+        })
+    };
+    let on_time_change = Callback::from(move |time: NaiveTime| {
+        let date = current_value.map_or_else(|| Local::now().date_naive(), |value| value.date());
+        set_value.emit(Some(date.and_time(time)));
+    });
+
+    let classes: Classes = vec![Classes::from("date-time-picker"), class]
+        .into_iter()
+        .collect();
+
+    let trigger_classes: Classes = vec![
+        Classes::from("date-time-picker-trigger"),
+        if disabled {
+            Classes::from("date-time-picker-trigger-disabled")
+        } else {
+            Classes::new()
+        },
+    ]
+    .into_iter()
+    .collect();
+
+    html! {
+        <div class={classes}>
+            <Popover>
+                <PopoverTrigger>
+                    <button
+                        ref={trigger_ref.clone()}
+                        type="button"
+                        class={trigger_classes}
+                        onclick={open}
+                        {disabled}
+                        aria-haspopup="dialog"
+                        aria-expanded={is_open.to_string()}
+                    >
+                        <span class="date-time-picker-icon">{ "📅" }</span>
+                        {
+                            if let Some(date_time) = display_value {
+                                html! { <span class="date-time-picker-value">{ date_time }</span> }
+                            } else {
+                                html! { <span class="date-time-picker-placeholder">{ placeholder }</span> }
+                            }
+                        }
+                    </button>
+                </PopoverTrigger>
+                <PopoverContent
+                    open={*is_open}
+                    on_close={close}
+                    anchor={trigger_ref}
+                    class="date-time-picker-content"
+                >
+                    <Calendar
+                        value={current_value.map(|value| value.date())}
+                        on_change={on_date_change}
+                        {min_date}
+                        {max_date}
+                    />
+                    <div class="date-time-picker-time">
+                        <TimePicker
+                            value={current_value.map(|value| value.time())}
+                            on_change={on_time_change}
+                            {hour_cycle}
+                            {show_seconds}
+                            {minute_step}
+                        />
+                        if let Some(offset) = utc_offset {
+                            <span class="date-time-picker-offset">{ offset_label(offset) }</span>
+                        }
+                    </div>
+                </PopoverContent>
+            </Popover>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_time(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_to_zoned_with_offset() {
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let zoned = to_zoned(date_time(10, 9, 30), Some(offset)).unwrap();
+        assert_eq!(zoned.naive_local(), date_time(10, 9, 30));
+        assert_eq!(zoned.naive_utc(), date_time(10, 7, 30));
+        assert_eq!(zoned.offset(), &offset);
+    }
+
+    #[test]
+    fn test_offset_label() {
+        assert_eq!(offset_label(FixedOffset::east_opt(0).unwrap()), "UTC");
+        assert_eq!(
+            offset_label(FixedOffset::east_opt(5 * 3600 + 1800).unwrap()),
+            "UTC+05:30"
+        );
+        assert_eq!(
+            offset_label(FixedOffset::west_opt(8 * 3600).unwrap()),
+            "UTC-08:00"
+        );
+    }
+
+    #[test]
+    fn test_date_time_picker_props() {
+        let props = DateTimePickerProps {
+            value: Some(Some(date_time(10, 9, 30))),
+            default_value: None,
+            on_change: None,
+            utc_offset: FixedOffset::east_opt(3600),
+            on_zoned_change: Some(Callback::from(|_: DateTime<FixedOffset>| {})),
+            default_time: NaiveTime::MIN,
+            hour_cycle: HourCycle::H24,
+            show_seconds: false,
+            minute_step: 5,
+            placeholder: AttrValue::from("Pick a date and time"),
+            disabled: false,
+            min_date: None,
+            max_date: None,
+            format: None,
+            class: Classes::new(),
+        };
+
+        assert_eq!(
+            props.value.flatten().map(|value| value.time()),
+            NaiveTime::from_hms_opt(9, 30, 0)
+        );
+        assert!(props.on_zoned_change.is_some());
+        assert_eq!(props.minute_step, 5);
+    }
+
+    #[test]
+    fn test_controlled_clear_stays_controlled() {
+        let cleared: Option<NaiveDateTime> = None;
+        let props = yew::props!(DateTimePickerProps { value: cleared });
+        assert_eq!(props.value, Some(None));
+
+        let props = yew::props!(DateTimePickerProps {});
+        assert_eq!(props.value, None);
+    }
+}
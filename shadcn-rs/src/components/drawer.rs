@@ -475,7 +475,7 @@ mod tests {
                 open: false,
                 default_open: false,
                 on_open_change: None,
-                side: side.clone(),
+                side,
                 children: Children::new(vec![]),
             };
             assert_eq!(props.side, side);
@@ -420,6 +420,11 @@ pub struct FormMessageProps {
 
 /// Form message type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum FormMessageType {
     /// Informational message
     Info,
@@ -393,7 +393,7 @@ mod tests {
             let props = HoverCardContentProps {
                 open: true,
                 on_close: None,
-                position: pos.clone(),
+                position: pos,
                 align: None,
                 show_arrow: true,
                 close_on_escape: true,
@@ -0,0 +1,738 @@
+//! Locale for date components
+//!
+//! Month and day names, the first day of the week, date format patterns
+//! and text direction used by `Calendar` and `DatePicker`. The locale is
+//! supplied by a `LocaleProvider`; components outside of one use English.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use yew::prelude::*;
+//! use shadcn_rs::{Calendar, Locale, LocaleProvider};
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     html! {
+//!         <LocaleProvider locale={Locale::de()}>
+//!             <Calendar />
+//!         </LocaleProvider>
+//!     }
+//! }
+//! ```
+//!
+//! # Format patterns
+//!
+//! Date formats are patterns of the following tokens, any other text being
+//! copied as is. Text in single quotes is never read as tokens, so
+//! `'Day' D` formats as `Day 5`; two single quotes give one.
+//!
+//! | Token  | Output                   |
+//! |--------|--------------------------|
+//! | `YYYY` | Year (`2024`)            |
+//! | `MMMM` | Month name (`January`)   |
+//! | `MMM`  | Short month name (`Jan`) |
+//! | `MM`   | Month, two digits (`01`) |
+//! | `M`    | Month (`1`)              |
+//! | `DD`   | Day, two digits (`05`)   |
+//! | `D`    | Day (`5`)                |
+//! | `dddd` | Weekday name (`Friday`)  |
+//!
+//! Parsing accepts one or two digits for `MM` and `DD`, month names in any
+//! case for `MMMM` and `MMM`, and ignores the weekday name of `dddd`.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use yew::prelude::*;
+
+/// Conventions and names used to display dates
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locale {
+    /// BCP 47 language tag, such as `en-US`
+    pub code: AttrValue,
+    /// Month names, January first
+    pub month_names: [AttrValue; 12],
+    /// Abbreviated month names, January first
+    pub month_names_short: [AttrValue; 12],
+    /// Weekday names, Sunday first
+    pub day_names: [AttrValue; 7],
+    /// Weekday abbreviations for calendar column headers, Sunday first
+    pub day_names_short: [AttrValue; 7],
+    /// First day of the week
+    pub first_day_of_week: Weekday,
+    /// Numeric date format, such as `MM/DD/YYYY`
+    pub date_format: AttrValue,
+    /// Long date format used to label days, such as `dddd, MMMM D, YYYY`
+    pub long_date_format: AttrValue,
+    /// Format of calendar captions, such as `MMMM YYYY`
+    pub month_year_format: AttrValue,
+    /// Header of the week number column
+    pub week_label: AttrValue,
+    /// Accessible name of the week number column
+    pub week_name: AttrValue,
+    /// Accessible name of the caption button that opens the month picker
+    pub choose_month_label: AttrValue,
+    /// Accessible name of the caption button that opens the year picker
+    pub choose_year_label: AttrValue,
+    /// Accessible name of the previous month button
+    pub previous_month_label: AttrValue,
+    /// Accessible name of the next month button
+    pub next_month_label: AttrValue,
+    /// Accessible name of the previous year button of the month picker
+    pub previous_year_label: AttrValue,
+    /// Accessible name of the next year button of the month picker
+    pub next_year_label: AttrValue,
+    /// Accessible name of the previous decade button of the year picker
+    pub previous_decade_label: AttrValue,
+    /// Accessible name of the next decade button of the year picker
+    pub next_decade_label: AttrValue,
+    /// Accessible name of the month picker grid
+    pub months_label: AttrValue,
+    /// Accessible name of the year picker grid
+    pub years_label: AttrValue,
+    /// Accessible name of the day grid
+    pub calendar_label: AttrValue,
+    /// Accessible name of the date picker button and popover
+    pub choose_date_label: AttrValue,
+    /// Error shown for a typed date that cannot be read, with `{format}`
+    /// standing for the expected format
+    pub invalid_date_message: AttrValue,
+    /// Accessible name of the preset sidebar of the date range picker
+    pub presets_label: AttrValue,
+    /// Label of the preset of today
+    pub today_label: AttrValue,
+    /// Label of the preset of the last days, with `{days}` standing for
+    /// their number
+    pub last_days_label: AttrValue,
+    /// Label of the preset of the current month
+    pub this_month_label: AttrValue,
+    /// Label of the preset of the previous quarter
+    pub last_quarter_label: AttrValue,
+    /// Whether text runs right to left
+    pub rtl: bool,
+}
+
+fn names<const N: usize>(names: [&'static str; N]) -> [AttrValue; N] {
+    names.map(AttrValue::from)
+}
+
+impl Locale {
+    /// English (United States)
+    pub fn en() -> Self {
+        Self {
+            code: AttrValue::from("en-US"),
+            month_names: names([
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]),
+            month_names_short: names([
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ]),
+            day_names: names([
+                "Sunday",
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+            ]),
+            day_names_short: names(["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"]),
+            first_day_of_week: Weekday::Sun,
+            date_format: AttrValue::from("MM/DD/YYYY"),
+            long_date_format: AttrValue::from("dddd, MMMM D, YYYY"),
+            month_year_format: AttrValue::from("MMMM YYYY"),
+            week_label: AttrValue::from("Wk"),
+            week_name: AttrValue::from("Week"),
+            choose_month_label: AttrValue::from("Choose month"),
+            choose_year_label: AttrValue::from("Choose year"),
+            previous_month_label: AttrValue::from("Previous month"),
+            next_month_label: AttrValue::from("Next month"),
+            previous_year_label: AttrValue::from("Previous year"),
+            next_year_label: AttrValue::from("Next year"),
+            previous_decade_label: AttrValue::from("Previous decade"),
+            next_decade_label: AttrValue::from("Next decade"),
+            months_label: AttrValue::from("Months"),
+            years_label: AttrValue::from("Years"),
+            calendar_label: AttrValue::from("Calendar"),
+            choose_date_label: AttrValue::from("Choose date"),
+            invalid_date_message: AttrValue::from("Enter a date as {format}"),
+            presets_label: AttrValue::from("Presets"),
+            today_label: AttrValue::from("Today"),
+            last_days_label: AttrValue::from("Last {days} days"),
+            this_month_label: AttrValue::from("This month"),
+            last_quarter_label: AttrValue::from("Last quarter"),
+            rtl: false,
+        }
+    }
+
+    /// German
+    pub fn de() -> Self {
+        Self {
+            code: AttrValue::from("de-DE"),
+            month_names: names([
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ]),
+            month_names_short: names([
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ]),
+            day_names: names([
+                "Sonntag",
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+            ]),
+            day_names_short: names(["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"]),
+            first_day_of_week: Weekday::Mon,
+            date_format: AttrValue::from("DD.MM.YYYY"),
+            long_date_format: AttrValue::from("dddd, D. MMMM YYYY"),
+            month_year_format: AttrValue::from("MMMM YYYY"),
+            week_label: AttrValue::from("KW"),
+            week_name: AttrValue::from("Kalenderwoche"),
+            choose_month_label: AttrValue::from("Monat wählen"),
+            choose_year_label: AttrValue::from("Jahr wählen"),
+            previous_month_label: AttrValue::from("Vorheriger Monat"),
+            next_month_label: AttrValue::from("Nächster Monat"),
+            previous_year_label: AttrValue::from("Vorheriges Jahr"),
+            next_year_label: AttrValue::from("Nächstes Jahr"),
+            previous_decade_label: AttrValue::from("Vorheriges Jahrzehnt"),
+            next_decade_label: AttrValue::from("Nächstes Jahrzehnt"),
+            months_label: AttrValue::from("Monate"),
+            years_label: AttrValue::from("Jahre"),
+            calendar_label: AttrValue::from("Kalender"),
+            choose_date_label: AttrValue::from("Datum wählen"),
+            invalid_date_message: AttrValue::from("Datum im Format {format} eingeben"),
+            presets_label: AttrValue::from("Zeiträume"),
+            today_label: AttrValue::from("Heute"),
+            last_days_label: AttrValue::from("Letzte {days} Tage"),
+            this_month_label: AttrValue::from("Dieser Monat"),
+            last_quarter_label: AttrValue::from("Letztes Quartal"),
+            rtl: false,
+        }
+    }
+
+    /// French
+    pub fn fr() -> Self {
+        Self {
+            code: AttrValue::from("fr-FR"),
+            month_names: names([
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ]),
+            month_names_short: names([
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ]),
+            day_names: names([
+                "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+            ]),
+            day_names_short: names(["di", "lu", "ma", "me", "je", "ve", "sa"]),
+            first_day_of_week: Weekday::Mon,
+            date_format: AttrValue::from("DD/MM/YYYY"),
+            long_date_format: AttrValue::from("dddd D MMMM YYYY"),
+            month_year_format: AttrValue::from("MMMM YYYY"),
+            week_label: AttrValue::from("Sem."),
+            week_name: AttrValue::from("Semaine"),
+            choose_month_label: AttrValue::from("Choisir le mois"),
+            choose_year_label: AttrValue::from("Choisir l’année"),
+            previous_month_label: AttrValue::from("Mois précédent"),
+            next_month_label: AttrValue::from("Mois suivant"),
+            previous_year_label: AttrValue::from("Année précédente"),
+            next_year_label: AttrValue::from("Année suivante"),
+            previous_decade_label: AttrValue::from("Décennie précédente"),
+            next_decade_label: AttrValue::from("Décennie suivante"),
+            months_label: AttrValue::from("Mois"),
+            years_label: AttrValue::from("Années"),
+            calendar_label: AttrValue::from("Calendrier"),
+            choose_date_label: AttrValue::from("Choisir une date"),
+            invalid_date_message: AttrValue::from("Saisissez une date au format {format}"),
+            presets_label: AttrValue::from("Périodes"),
+            today_label: AttrValue::from("Aujourd’hui"),
+            last_days_label: AttrValue::from("{days} derniers jours"),
+            this_month_label: AttrValue::from("Ce mois-ci"),
+            last_quarter_label: AttrValue::from("Trimestre précédent"),
+            rtl: false,
+        }
+    }
+
+    /// Spanish
+    pub fn es() -> Self {
+        Self {
+            code: AttrValue::from("es-ES"),
+            month_names: names([
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ]),
+            month_names_short: names([
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ]),
+            day_names: names([
+                "domingo",
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+            ]),
+            day_names_short: names(["do", "lu", "ma", "mi", "ju", "vi", "sá"]),
+            first_day_of_week: Weekday::Mon,
+            date_format: AttrValue::from("DD/MM/YYYY"),
+            long_date_format: AttrValue::from("dddd, D de MMMM de YYYY"),
+            month_year_format: AttrValue::from("MMMM de YYYY"),
+            week_label: AttrValue::from("Sem."),
+            week_name: AttrValue::from("Semana"),
+            choose_month_label: AttrValue::from("Elegir mes"),
+            choose_year_label: AttrValue::from("Elegir año"),
+            previous_month_label: AttrValue::from("Mes anterior"),
+            next_month_label: AttrValue::from("Mes siguiente"),
+            previous_year_label: AttrValue::from("Año anterior"),
+            next_year_label: AttrValue::from("Año siguiente"),
+            previous_decade_label: AttrValue::from("Década anterior"),
+            next_decade_label: AttrValue::from("Década siguiente"),
+            months_label: AttrValue::from("Meses"),
+            years_label: AttrValue::from("Años"),
+            calendar_label: AttrValue::from("Calendario"),
+            choose_date_label: AttrValue::from("Elegir fecha"),
+            invalid_date_message: AttrValue::from("Introduzca una fecha con el formato {format}"),
+            presets_label: AttrValue::from("Periodos"),
+            today_label: AttrValue::from("Hoy"),
+            last_days_label: AttrValue::from("Últimos {days} días"),
+            this_month_label: AttrValue::from("Este mes"),
+            last_quarter_label: AttrValue::from("Trimestre anterior"),
+            rtl: false,
+        }
+    }
+
+    /// Japanese
+    pub fn ja() -> Self {
+        Self {
+            code: AttrValue::from("ja-JP"),
+            month_names: names([
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ]),
+            month_names_short: names([
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ]),
+            day_names: names([
+                "日曜日",
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+            ]),
+            day_names_short: names(["日", "月", "火", "水", "木", "金", "土"]),
+            first_day_of_week: Weekday::Sun,
+            date_format: AttrValue::from("YYYY/MM/DD"),
+            long_date_format: AttrValue::from("YYYY年M月D日 dddd"),
+            month_year_format: AttrValue::from("YYYY年M月"),
+            week_label: AttrValue::from("週"),
+            week_name: AttrValue::from("週"),
+            choose_month_label: AttrValue::from("月を選択"),
+            choose_year_label: AttrValue::from("年を選択"),
+            previous_month_label: AttrValue::from("前の月"),
+            next_month_label: AttrValue::from("次の月"),
+            previous_year_label: AttrValue::from("前の年"),
+            next_year_label: AttrValue::from("次の年"),
+            previous_decade_label: AttrValue::from("前の10年"),
+            next_decade_label: AttrValue::from("次の10年"),
+            months_label: AttrValue::from("月"),
+            years_label: AttrValue::from("年"),
+            calendar_label: AttrValue::from("カレンダー"),
+            choose_date_label: AttrValue::from("日付を選択"),
+            invalid_date_message: AttrValue::from("{format} の形式で日付を入力してください"),
+            presets_label: AttrValue::from("期間"),
+            today_label: AttrValue::from("今日"),
+            last_days_label: AttrValue::from("過去{days}日間"),
+            this_month_label: AttrValue::from("今月"),
+            last_quarter_label: AttrValue::from("前四半期"),
+            rtl: false,
+        }
+    }
+
+    /// Bundled locale for a language tag, matched on its language
+    /// (`de-AT` gives German), or `None` if the language is not bundled
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Self::en()),
+            "de" => Some(Self::de()),
+            "fr" => Some(Self::fr()),
+            "es" => Some(Self::es()),
+            "ja" => Some(Self::ja()),
+            _ => None,
+        }
+    }
+
+    /// Name of the month of a date
+    pub fn month_name(&self, date: NaiveDate) -> &AttrValue {
+        &self.month_names[date.month0() as usize]
+    }
+
+    /// Name of a weekday
+    pub fn day_name(&self, day: Weekday) -> &AttrValue {
+        &self.day_names[day.num_days_from_sunday() as usize]
+    }
+
+    /// Column header abbreviation of a weekday
+    pub fn day_name_short(&self, day: Weekday) -> &AttrValue {
+        &self.day_names_short[day.num_days_from_sunday() as usize]
+    }
+
+    /// Format a date with a pattern (see the module documentation)
+    pub fn format(&self, date: NaiveDate, pattern: &str) -> String {
+        let mut output = String::new();
+        let mut rest = pattern;
+        while let Some(c) = rest.chars().next() {
+            if let Some((literal, after)) = take_literal(rest) {
+                output.push_str(literal);
+                rest = after;
+                continue;
+            }
+            let token = TOKENS
+                .iter()
+                .find(|token| rest.starts_with(**token))
+                .copied();
+            match token {
+                Some(token) => {
+                    output.push_str(&self.format_token(date, token));
+                    rest = &rest[token.len()..];
+                }
+                None => {
+                    output.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        output
+    }
+
+    /// Format a date with the numeric `date_format`
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        self.format(date, &self.date_format)
+    }
+
+    /// Format a date with the `long_date_format`
+    pub fn format_long_date(&self, date: NaiveDate) -> String {
+        self.format(date, &self.long_date_format)
+    }
+
+    /// Format the month of a date with the `month_year_format`
+    pub fn format_month_year(&self, date: NaiveDate) -> String {
+        self.format(date, &self.month_year_format)
+    }
+
+    /// Parse a date written with a pattern (see the module documentation)
+    ///
+    /// Returns `None` if the text does not follow the pattern or is not a
+    /// valid date.
+    pub fn parse(&self, text: &str, pattern: &str) -> Option<NaiveDate> {
+        let (mut year, mut month, mut day) = (None, None, None);
+        let mut rest = text.trim();
+        let mut pattern = pattern.trim();
+        while let Some(c) = pattern.chars().next() {
+            if let Some((literal, after)) = take_literal(pattern) {
+                pattern = after;
+                rest = rest.strip_prefix(literal)?;
+                continue;
+            }
+            let Some(token) = TOKENS.iter().find(|token| pattern.starts_with(**token)) else {
+                pattern = &pattern[c.len_utf8()..];
+                rest = if c.is_whitespace() {
+                    rest.trim_start()
+                } else {
+                    rest.strip_prefix(c)?
+                };
+                continue;
+            };
+            pattern = &pattern[token.len()..];
+            rest = match *token {
+                "YYYY" => {
+                    let (value, rest) = take_number(rest, 4, 4)?;
+                    year = Some(value as i32);
+                    rest
+                }
+                "MM" | "M" => {
+                    let (value, rest) = take_number(rest, 1, 2)?;
+                    month = Some(value);
+                    rest
+                }
+                "DD" | "D" => {
+                    let (value, rest) = take_number(rest, 1, 2)?;
+                    day = Some(value);
+                    rest
+                }
+                "MMMM" | "MMM" => {
+                    let (index, rest) =
+                        take_name(rest, self.month_names.iter().chain(&self.month_names_short))?;
+                    month = Some(index as u32 % 12 + 1);
+                    rest
+                }
+                _ => take_name(rest, self.day_names.iter())?.1,
+            };
+        }
+        if !rest.trim().is_empty() {
+            return None;
+        }
+        NaiveDate::from_ymd_opt(year?, month?, day?)
+    }
+
+    /// Parse a date written with the numeric `date_format`
+    pub fn parse_date(&self, text: &str) -> Option<NaiveDate> {
+        self.parse(text, &self.date_format)
+    }
+
+    fn format_token(&self, date: NaiveDate, token: &str) -> String {
+        match token {
+            "YYYY" => format!("{:04}", date.year()),
+            "MMMM" => self.month_name(date).to_string(),
+            "MMM" => self.month_names_short[date.month0() as usize].to_string(),
+            "MM" => format!("{:02}", date.month()),
+            "M" => date.month().to_string(),
+            "DD" => format!("{:02}", date.day()),
+            "D" => date.day().to_string(),
+            "dddd" => self.day_name(date.weekday()).to_string(),
+            _ => token.to_string(),
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::en()
+    }
+}
+
+/// Pattern tokens, longest first so that `MMMM` is not read as `MM` twice
+const TOKENS: [&str; 8] = ["YYYY", "MMMM", "dddd", "MMM", "MM", "DD", "M", "D"];
+
+/// Read a quoted literal from the start of a pattern and return its text:
+/// `'de'` is `de` and `''` is a single quote
+fn take_literal(pattern: &str) -> Option<(&str, &str)> {
+    let quoted = pattern.strip_prefix('\'')?;
+    match quoted.find('\'') {
+        Some(0) => Some(("'", &quoted[1..])),
+        Some(end) => Some((&quoted[..end], &quoted[end + 1..])),
+        None => Some((quoted, "")),
+    }
+}
+
+/// Read a number of `min` to `max` digits from the start of the text
+fn take_number(text: &str, min: usize, max: usize) -> Option<(u32, &str)> {
+    let digits = text
+        .chars()
+        .take(max)
+        .take_while(char::is_ascii_digit)
+        .count();
+    if digits < min {
+        return None;
+    }
+    Some((text[..digits].parse().ok()?, &text[digits..]))
+}
+
+/// Read the longest of the names at the start of the text, ignoring case,
+/// and return its position among the names
+fn take_name<'a, 'n>(
+    text: &'a str,
+    names: impl Iterator<Item = &'n AttrValue>,
+) -> Option<(usize, &'a str)> {
+    names
+        .enumerate()
+        .filter_map(|(index, name)| strip_prefix_ignore_case(text, name).map(|rest| (index, rest)))
+        .min_by_key(|(_, rest)| rest.len())
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut chars = text.chars();
+    for expected in prefix.chars() {
+        let c = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.as_str())
+}
+
+/// Locale provider properties
+#[derive(Properties, PartialEq)]
+pub struct LocaleProviderProps {
+    /// Locale of the date components inside the provider
+    pub locale: Locale,
+
+    /// Children
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Supplies a locale to the date components inside it
+#[function_component(LocaleProvider)]
+pub fn locale_provider(props: &LocaleProviderProps) -> Html {
+    html! {
+        <ContextProvider<Locale> context={props.locale.clone()}>
+            { props.children.clone() }
+        </ContextProvider<Locale>>
+    }
+}
+
+/// Locale supplied by the nearest `LocaleProvider`, English without one
+#[hook]
+pub fn use_locale() -> Locale {
+    use_context::<Locale>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_format_tokens() {
+        let locale = Locale::en();
+        let day = date(2024, 3, 5);
+        assert_eq!(locale.format(day, "YYYY-MM-DD"), "2024-03-05");
+        assert_eq!(locale.format(day, "M/D/YYYY"), "3/5/2024");
+        assert_eq!(locale.format(day, "MMM D"), "Mar 5");
+        assert_eq!(locale.format_date(day), "03/05/2024");
+        assert_eq!(locale.format_long_date(day), "Tuesday, March 5, 2024");
+        assert_eq!(locale.format_month_year(day), "March 2024");
+    }
+
+    #[test]
+    fn test_bundled_locales() {
+        let day = date(2024, 3, 5);
+        assert_eq!(Locale::de().format_date(day), "05.03.2024");
+        assert_eq!(Locale::de().format_long_date(day), "Dienstag, 5. März 2024");
+        assert_eq!(Locale::fr().format_date(day), "05/03/2024");
+        assert_eq!(
+            Locale::es().format_long_date(day),
+            "martes, 5 de marzo de 2024"
+        );
+        assert_eq!(Locale::ja().format_month_year(day), "2024年3月");
+        assert_eq!(Locale::ja().format_date(day), "2024/03/05");
+        assert_eq!(Locale::de().first_day_of_week, Weekday::Mon);
+        assert_eq!(Locale::en().day_name_short(Weekday::Sat), "Sa");
+        for locale in [
+            Locale::en(),
+            Locale::de(),
+            Locale::fr(),
+            Locale::es(),
+            Locale::ja(),
+        ] {
+            assert!(locale.invalid_date_message.contains("{format}"));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let en = Locale::en();
+        let day = date(2024, 3, 5);
+        assert_eq!(en.parse_date("03/05/2024"), Some(day));
+        assert_eq!(en.parse_date(" 3/5/2024 "), Some(day));
+        assert_eq!(en.parse("March 5, 2024", "MMMM D, YYYY"), Some(day));
+        assert_eq!(en.parse("mar 5, 2024", "MMM D, YYYY"), Some(day));
+        assert_eq!(en.parse_date("2024-03-05"), None);
+        assert_eq!(en.parse_date("02/30/2024"), None);
+        assert_eq!(en.parse_date("03/05/24"), None);
+        assert_eq!(en.parse_date("03/05/2024x"), None);
+
+        assert_eq!(Locale::de().parse_date("5.3.2024"), Some(day));
+        assert_eq!(
+            Locale::de().parse("Dienstag, 5. März 2024", "dddd, D. MMMM YYYY"),
+            Some(day)
+        );
+        assert_eq!(Locale::ja().parse_date("2024/03/05"), Some(day));
+        assert_eq!(
+            Locale::es().parse("5 de marzo de 2024", "D de MMMM de YYYY"),
+            Some(day)
+        );
+    }
+
+    #[test]
+    fn test_quoted_literals() {
+        let en = Locale::en();
+        let day = date(2024, 3, 5);
+        assert_eq!(en.format(day, "'Day' D 'of' MMMM"), "Day 5 of March");
+        assert_eq!(en.format(day, "D''MM"), "5'03");
+        assert_eq!(
+            en.parse("Day 5 of March 2024", "'Day' D 'of' MMMM YYYY"),
+            Some(day)
+        );
+        assert_eq!(en.parse("5 March 2024", "'Day' D MMMM YYYY"), None);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for locale in [
+            Locale::en(),
+            Locale::de(),
+            Locale::fr(),
+            Locale::es(),
+            Locale::ja(),
+        ] {
+            let day = date(2024, 12, 31);
+            assert_eq!(locale.parse_date(&locale.format_date(day)), Some(day));
+            assert_eq!(
+                locale.parse(&locale.format_long_date(day), &locale.long_date_format),
+                Some(day)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(Locale::from_code("de-AT"), Some(Locale::de()));
+        assert_eq!(Locale::from_code("FR"), Some(Locale::fr()));
+        assert_eq!(Locale::from_code("ja_JP"), Some(Locale::ja()));
+        assert_eq!(Locale::from_code("pt-BR"), None);
+        assert_eq!(Locale::default().code, "en-US");
+    }
+}
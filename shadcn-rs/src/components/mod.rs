@@ -68,7 +68,11 @@ pub mod calendar;
 pub mod combobox;
 pub mod command;
 pub mod date_picker;
+pub mod date_range_picker;
+pub mod date_time_picker;
 pub mod input_otp;
+pub mod locale;
+pub mod time_picker;
 
 // Tier 8 - Complex Components
 pub mod carousel;
@@ -186,7 +190,7 @@ pub use sidebar::{
 };
 
 // Re-export Tier 7 components
-pub use calendar::{Calendar, CalendarMode};
+pub use calendar::{Calendar, CalendarDay, CalendarMode, DateRange};
 pub use combobox::{
     Combobox, ComboboxContent, ComboboxEmpty, ComboboxGroup, ComboboxInput, ComboboxItem,
     ComboboxSeparator, ComboboxTrigger,
@@ -196,11 +200,20 @@ pub use command::{
     CommandShortcut,
 };
 pub use date_picker::DatePicker;
+pub use date_range_picker::{DateRangePicker, DateRangePreset};
+pub use date_time_picker::DateTimePicker;
 pub use input_otp::InputOTP;
+pub use locale::{Locale, LocaleProvider, use_locale};
+pub use time_picker::{HourCycle, TimePicker};
 
 // Re-export Tier 8 components
 pub use carousel::{Carousel, CarouselContent, CarouselItem, CarouselNext, CarouselPrevious};
-pub use chart::{Chart, ChartData, ChartType};
-pub use data_table::{DataTable, SelectionMode, SortDirection};
+pub use chart::{Chart, ChartData, ChartPoint, ChartSeries, ChartType};
+pub use data_table::{
+    ColumnPin, DataTable, DataTableAggregate, DataTableCellEdit, DataTableColumn,
+    DataTableColumnFilter, DataTableEditor, DataTableExport, DataTableExportFormat,
+    DataTableFilter, DataTableFilterState, DataTableFilterValue, DataTableSort, DataTableState,
+    DataTableValue, RowGroup, SelectionMode, SortDirection,
+};
 pub use sonner::{Sonner, SonnerPosition, SonnerToast, SonnerType};
 pub use toast::{Toast, ToastPosition, ToastVariant};
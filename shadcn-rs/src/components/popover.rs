@@ -29,6 +29,8 @@
 use crate::hooks::{use_click_outside_conditional, use_escape_key_conditional};
 use crate::types::Position;
 use crate::utils::Portal;
+use gloo::events::{EventListener, EventListenerOptions};
+use web_sys::Element;
 use yew::prelude::*;
 
 /// Popover component properties
@@ -123,6 +125,11 @@ pub struct PopoverContentProps {
     #[prop_or_default]
     pub align: Option<AttrValue>,
 
+    /// Element to place the popover next to, on the side given by
+    /// `position`; without one the popover is placed by CSS only
+    #[prop_or_default]
+    pub anchor: Option<NodeRef>,
+
     /// Whether to close on click outside
     #[prop_or(true)]
     pub close_on_outside_click: bool,
@@ -149,6 +156,7 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
         on_close,
         position,
         align: _,
+        anchor,
         close_on_outside_click,
         close_on_escape,
         class,
@@ -156,6 +164,36 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
     } = props.clone();
 
     let content_ref = use_node_ref();
+    let anchor_rect = use_state_eq(|| None::<(f64, f64, f64, f64)>);
+
+    // Follow the anchor while it scrolls or the window resizes
+    {
+        let anchor_rect = anchor_rect.clone();
+        use_effect_with((open, anchor.clone()), move |(open, anchor)| {
+            let listeners = match anchor {
+                Some(anchor) if *open => {
+                    anchor_rect.set(measure_anchor(anchor));
+                    let window = gloo::utils::window();
+                    let listen = |event: &'static str| {
+                        let anchor = anchor.clone();
+                        let anchor_rect = anchor_rect.clone();
+                        EventListener::new_with_options(
+                            &window,
+                            event,
+                            EventListenerOptions::run_in_capture_phase(),
+                            move |_| anchor_rect.set(measure_anchor(&anchor)),
+                        )
+                    };
+                    Some((listen("scroll"), listen("resize")))
+                }
+                _ => None,
+            };
+            move || {
+                drop(listeners);
+                anchor_rect.set(None);
+            }
+        });
+    }
 
     // Handle Escape key
     let on_close_esc = on_close.clone();
@@ -192,11 +230,17 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
     .into_iter()
     .collect();
 
+    // Before the effect has measured, measure during render
+    let style = (*anchor_rect)
+        .or_else(|| anchor.as_ref().and_then(measure_anchor))
+        .map(|rect| anchored_style(rect, position));
+
     html! {
         <Portal>
             <div
                 ref={content_ref}
                 class={classes}
+                {style}
                 role="dialog"
                 aria-modal="false"
             >
@@ -206,6 +250,31 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
     }
 }
 
+/// Viewport rectangle of an anchor as `(top, right, bottom, left)`
+fn measure_anchor(anchor: &NodeRef) -> Option<(f64, f64, f64, f64)> {
+    let rect = anchor.cast::<Element>()?.get_bounding_client_rect();
+    Some((rect.top(), rect.right(), rect.bottom(), rect.left()))
+}
+
+/// Fixed position style placing content next to an anchor rectangle,
+/// given as `(top, right, bottom, left)` viewport coordinates
+fn anchored_style(anchor: (f64, f64, f64, f64), position: Position) -> String {
+    const GAP: f64 = 4.0;
+    let (top, right, bottom, left) = anchor;
+    let (x, y, transform) = match position {
+        Position::Top | Position::TopLeft => (left, top - GAP, "translateY(-100%)"),
+        Position::TopRight => (right, top - GAP, "translate(-100%, -100%)"),
+        Position::Bottom | Position::BottomLeft | Position::Center => (left, bottom + GAP, "none"),
+        Position::BottomRight => (right, bottom + GAP, "translateX(-100%)"),
+        Position::Right => (right + GAP, top, "none"),
+        Position::Left => (left - GAP, top, "translateX(-100%)"),
+    };
+    format!(
+        "position: fixed; top: {}px; left: {}px; transform: {};",
+        y, x, transform
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,8 +305,9 @@ mod tests {
             let props = PopoverContentProps {
                 open: true,
                 on_close: None,
-                position: position.clone(),
+                position,
                 align: None,
+                anchor: None,
                 close_on_outside_click: true,
                 close_on_escape: true,
                 class: Classes::new(),
@@ -254,6 +324,7 @@ mod tests {
             on_close: None,
             position: Position::Bottom,
             align: None,
+            anchor: None,
             close_on_outside_click: false,
             close_on_escape: false,
             class: Classes::new(),
@@ -263,4 +334,21 @@ mod tests {
         assert!(!props.close_on_outside_click);
         assert!(!props.close_on_escape);
     }
+
+    #[test]
+    fn test_anchored_style() {
+        let anchor = (100.0, 300.0, 140.0, 200.0);
+        assert_eq!(
+            anchored_style(anchor, Position::Bottom),
+            "position: fixed; top: 144px; left: 200px; transform: none;"
+        );
+        assert_eq!(
+            anchored_style(anchor, Position::Top),
+            "position: fixed; top: 96px; left: 200px; transform: translateY(-100%);"
+        );
+        assert_eq!(
+            anchored_style(anchor, Position::Left),
+            "position: fixed; top: 100px; left: 196px; transform: translateX(-100%);"
+        );
+    }
 }
@@ -279,7 +279,7 @@ mod tests {
             let props = ProgressProps {
                 value: Some(50.0),
                 max: 100.0,
-                size: size.clone(),
+                size,
                 variant: Variant::Primary,
                 indeterminate: false,
                 show_label: false,
@@ -24,6 +24,7 @@
 //! }
 //! ```
 
+use crate::hooks::use_controllable_state;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::prelude::*;
 use web_sys::{Element, MouseEvent};
@@ -34,6 +35,11 @@ type MouseClosureRc = std::rc::Rc<std::cell::RefCell<Option<Closure<dyn Fn(Mouse
 
 /// Resizable orientation
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ResizableOrientation {
     /// Horizontal layout (side by side)
     Horizontal,
@@ -65,6 +71,18 @@ pub struct ResizableProps {
     #[prop_or(ResizableOrientation::Horizontal)]
     pub orientation: ResizableOrientation,
 
+    /// Panel sizes as percentages (controlled)
+    #[prop_or_default]
+    pub sizes: Option<Vec<f64>>,
+
+    /// Default panel sizes as percentages (uncontrolled)
+    #[prop_or(vec![50.0, 50.0])]
+    pub default_sizes: Vec<f64>,
+
+    /// Callback when the panel sizes change
+    #[prop_or_default]
+    pub on_sizes_change: Option<Callback<Vec<f64>>>,
+
     /// Additional CSS classes
     #[prop_or_default]
     pub class: Classes,
@@ -84,21 +102,17 @@ pub struct ResizableProps {
 pub fn resizable(props: &ResizableProps) -> Html {
     let ResizableProps {
         orientation,
+        sizes,
+        default_sizes,
+        on_sizes_change,
         class,
         children,
     } = props.clone();
 
     let container_ref = use_node_ref();
-    let sizes = use_state(|| vec![50.0, 50.0]);
+    let (sizes, set_sizes) = use_controllable_state(sizes, default_sizes, on_sizes_change);
     let is_dragging = use_state(|| false);
 
-    let set_sizes = {
-        let sizes = sizes.clone();
-        Callback::from(move |new_sizes: Vec<f64>| {
-            sizes.set(new_sizes);
-        })
-    };
-
     let set_dragging = {
         let is_dragging = is_dragging.clone();
         Callback::from(move |dragging: bool| {
@@ -108,7 +122,7 @@ pub fn resizable(props: &ResizableProps) -> Html {
 
     let context = ResizableContext {
         orientation: orientation.clone(),
-        sizes: (*sizes).clone(),
+        sizes,
         set_sizes,
         is_dragging: *is_dragging,
         set_dragging,
@@ -217,6 +231,19 @@ pub fn resizable_panel(props: &ResizablePanelProps) -> Html {
     }
 }
 
+/// Sizes of the two panels with the handle `offset` pixels into a container
+/// `extent` pixels long, each panel keeping between 10% and 90%
+///
+/// Returns `None` for a container without extent, which has not been laid
+/// out.
+fn handle_sizes(offset: f64, extent: f64) -> Option<Vec<f64>> {
+    if extent <= 0.0 {
+        return None;
+    }
+    let percentage = (offset / extent * 100.0).clamp(10.0, 90.0);
+    Some(vec![percentage, 100.0 - percentage])
+}
+
 /// Resizable handle properties
 #[derive(Properties, PartialEq, Clone)]
 pub struct ResizableHandleProps {
@@ -269,20 +296,18 @@ pub fn resizable_handle(props: &ResizableHandleProps) -> Html {
                 {
                     let rect = container.get_bounding_client_rect();
 
-                    let percentage = match ctx.orientation {
+                    let sizes = match ctx.orientation {
                         ResizableOrientation::Horizontal => {
-                            let x = e.client_x() as f64 - rect.left();
-                            let width = rect.width();
-                            (x / width * 100.0).clamp(10.0, 90.0)
+                            handle_sizes(e.client_x() as f64 - rect.left(), rect.width())
                         }
                         ResizableOrientation::Vertical => {
-                            let y = e.client_y() as f64 - rect.top();
-                            let height = rect.height();
-                            (y / height * 100.0).clamp(10.0, 90.0)
+                            handle_sizes(e.client_y() as f64 - rect.top(), rect.height())
                         }
                     };
 
-                    ctx.set_sizes.emit(vec![percentage, 100.0 - percentage]);
+                    if let Some(sizes) = sizes {
+                        ctx.set_sizes.emit(sizes);
+                    }
                 }
             });
 
@@ -384,6 +409,9 @@ mod tests {
     fn test_resizable_horizontal() {
         let props = ResizableProps {
             orientation: ResizableOrientation::Horizontal,
+            sizes: None,
+            default_sizes: vec![50.0, 50.0],
+            on_sizes_change: None,
             class: Classes::new(),
             children: Children::new(vec![]),
         };
@@ -395,6 +423,9 @@ mod tests {
     fn test_resizable_vertical() {
         let props = ResizableProps {
             orientation: ResizableOrientation::Vertical,
+            sizes: None,
+            default_sizes: vec![50.0, 50.0],
+            on_sizes_change: None,
             class: Classes::new(),
             children: Children::new(vec![]),
         };
@@ -402,6 +433,14 @@ mod tests {
         assert_eq!(props.orientation, ResizableOrientation::Vertical);
     }
 
+    #[test]
+    fn test_handle_sizes() {
+        assert_eq!(handle_sizes(300.0, 1000.0), Some(vec![30.0, 70.0]));
+        assert_eq!(handle_sizes(-50.0, 1000.0), Some(vec![10.0, 90.0]));
+        assert_eq!(handle_sizes(990.0, 1000.0), Some(vec![90.0, 10.0]));
+        assert_eq!(handle_sizes(10.0, 0.0), None);
+    }
+
     #[test]
     fn test_resizable_panel_default_size() {
         let props = ResizablePanelProps {
@@ -22,6 +22,11 @@ use yew::prelude::*;
 
 /// Scroll direction
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ScrollDirection {
     /// Vertical scrolling only
     Vertical,
@@ -24,6 +24,11 @@ use yew::prelude::*;
 
 /// Separator orientation
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum SeparatorOrientation {
     /// Horizontal separator (default)
     Horizontal,
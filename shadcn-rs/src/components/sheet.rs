@@ -375,7 +375,7 @@ mod tests {
                 open: true,
                 default_open: false,
                 on_open_change: None,
-                side: side.clone(),
+                side,
                 children: Children::new(vec![]),
             };
 
@@ -429,7 +429,7 @@ mod tests {
             let props = SheetContentProps {
                 open: true,
                 on_close: None,
-                side: side.clone(),
+                side,
                 close_on_overlay_click: true,
                 close_on_escape: true,
                 class: Classes::new(),
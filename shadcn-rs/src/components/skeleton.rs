@@ -24,6 +24,11 @@ use yew::prelude::*;
 
 /// Skeleton shape variant
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum SkeletonShape {
     /// Rectangle skeleton (default)
     Rectangle,
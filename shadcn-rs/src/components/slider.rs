@@ -437,7 +437,7 @@ mod tests {
                 max: 100.0,
                 step: 1.0,
                 disabled: false,
-                size: size.clone(),
+                size,
                 show_marks: false,
                 onchange: None,
                 class: Classes::new(),
@@ -26,6 +26,11 @@ use yew::prelude::*;
 
 /// Sonner position
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum SonnerPosition {
     /// Top left
     TopLeft,
@@ -43,6 +48,11 @@ pub enum SonnerPosition {
 
 /// Sonner toast type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum SonnerType {
     /// Default type
     Default,
@@ -31,6 +31,11 @@ use yew::prelude::*;
 
 /// Tabs orientation
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TabsOrientation {
     /// Horizontal tabs (default)
     Horizontal,
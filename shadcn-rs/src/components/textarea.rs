@@ -40,6 +40,11 @@ use yew::prelude::*;
 
 /// Textarea resize behavior
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TextareaResize {
     /// No resize allowed
     None,
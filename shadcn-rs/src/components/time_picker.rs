@@ -0,0 +1,403 @@
+//! Time Picker component
+//!
+//! Hour, minute and optional second fields for picking a time of day.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use chrono::NaiveTime;
+//! use yew::prelude::*;
+//! use shadcn_rs::{HourCycle, TimePicker};
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     let time = use_state(|| NaiveTime::from_hms_opt(9, 30, 0));
+//!
+//!     let on_change = {
+//!         let time = time.clone();
+//!         Callback::from(move |new_time: NaiveTime| time.set(Some(new_time)))
+//!     };
+//!
+//!     html! {
+//!         <TimePicker
+//!             value={*time}
+//!             {on_change}
+//!             hour_cycle={HourCycle::H12}
+//!             minute_step={15}
+//!         />
+//!     }
+//! }
+//! ```
+
+use chrono::{NaiveTime, Timelike};
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::hooks::use_controllable_state;
+
+/// Clock used to show the hours of a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum HourCycle {
+    /// Hours 1 to 12 with an AM/PM period
+    H12,
+    /// Hours 0 to 23
+    #[default]
+    H24,
+}
+
+/// A field of the time picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeSegment {
+    /// Hour, 0-23 with [`HourCycle::H24`] and 1-12 with [`HourCycle::H12`]
+    Hour,
+    /// Minute
+    Minute,
+    /// Second
+    Second,
+    /// AM (0) or PM (1), with [`HourCycle::H12`] only
+    Period,
+}
+
+/// Hour on a 12-hour clock and whether it is PM
+pub fn to_12_hour(hour: u32) -> (u32, bool) {
+    let twelve = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    (twelve, hour >= 12)
+}
+
+/// Hour on a 24-hour clock of an hour on a 12-hour clock
+pub fn from_12_hour(hour: u32, pm: bool) -> u32 {
+    hour % 12 + if pm { 12 } else { 0 }
+}
+
+/// Values from 0 below `limit` in increments of `step`, with `current`
+/// added in order when it is not one of them
+pub fn step_values(limit: u32, step: u32, current: Option<u32>) -> Vec<u32> {
+    let mut values: Vec<u32> = (0..limit).step_by(step.max(1) as usize).collect();
+    if let Some(current) = current.filter(|current| *current < limit)
+        && let Err(index) = values.binary_search(&current)
+    {
+        values.insert(index, current);
+    }
+    values
+}
+
+/// Time with one segment set to a value
+///
+/// The value of [`TimeSegment::Hour`] is read on the given hour cycle and
+/// keeps the period of `time` with [`HourCycle::H12`]. Out of range values
+/// leave the time unchanged.
+pub fn with_segment(
+    time: NaiveTime,
+    segment: TimeSegment,
+    value: u32,
+    hour_cycle: HourCycle,
+) -> NaiveTime {
+    let (hour, pm) = to_12_hour(time.hour());
+    let updated = match (segment, hour_cycle) {
+        (TimeSegment::Hour, HourCycle::H24) => time.with_hour(value),
+        (TimeSegment::Hour, HourCycle::H12) => (1..=12)
+            .contains(&value)
+            .then(|| time.with_hour(from_12_hour(value, pm)))
+            .flatten(),
+        (TimeSegment::Minute, _) => time.with_minute(value),
+        (TimeSegment::Second, _) => time.with_second(value),
+        (TimeSegment::Period, _) => time.with_hour(from_12_hour(hour, value == 1)),
+    };
+    updated.unwrap_or(time)
+}
+
+/// Format a time as `14:05`, or `2:05 PM` with [`HourCycle::H12`]
+pub fn format_time(time: NaiveTime, hour_cycle: HourCycle, show_seconds: bool) -> String {
+    let seconds = if show_seconds {
+        format!(":{:02}", time.second())
+    } else {
+        String::new()
+    };
+    match hour_cycle {
+        HourCycle::H24 => format!("{:02}:{:02}{}", time.hour(), time.minute(), seconds),
+        HourCycle::H12 => {
+            let (hour, pm) = to_12_hour(time.hour());
+            let period = if pm { "PM" } else { "AM" };
+            format!("{}:{:02}{} {}", hour, time.minute(), seconds, period)
+        }
+    }
+}
+
+/// Time picker component properties
+#[derive(Properties, PartialEq, Clone)]
+pub struct TimePickerProps {
+    /// Selected time (controlled)
+    ///
+    /// `value={time}` with an `Option<NaiveTime>` controls the picker, `None`
+    /// being no time; leave the prop out for an uncontrolled picker.
+    #[prop_or_default]
+    pub value: Option<Option<NaiveTime>>,
+
+    /// Initially selected time (uncontrolled)
+    #[prop_or_default]
+    pub default_value: Option<NaiveTime>,
+
+    /// Called with the new time when a field changes
+    #[prop_or_default]
+    pub on_change: Option<Callback<NaiveTime>>,
+
+    /// 12-hour or 24-hour clock
+    #[prop_or_default]
+    pub hour_cycle: HourCycle,
+
+    /// Show a seconds field
+    #[prop_or(false)]
+    pub show_seconds: bool,
+
+    /// Increment between the offered minutes
+    #[prop_or(1)]
+    pub minute_step: u32,
+
+    /// Disabled state
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Accessible label of the field group
+    #[prop_or(AttrValue::from("Time"))]
+    pub aria_label: AttrValue,
+
+    /// Additional CSS classes
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Time picker component
+///
+/// A group of selects for the hour, the minute (in `minute_step`
+/// increments), the second with `show_seconds`, and the AM/PM period with
+/// [`HourCycle::H12`]. Without a time, the fields show `--` and the first
+/// field changed fills the others with zero.
+///
+/// # Accessibility
+/// - Native selects, labelled per field
+/// - Fields grouped under `aria_label`
+#[function_component(TimePicker)]
+pub fn time_picker(props: &TimePickerProps) -> Html {
+    let TimePickerProps {
+        value,
+        default_value,
+        on_change,
+        hour_cycle,
+        show_seconds,
+        minute_step,
+        disabled,
+        aria_label,
+        class,
+    } = props.clone();
+
+    let on_change = on_change.map(|on_change| {
+        Callback::from(move |time: Option<NaiveTime>| {
+            if let Some(time) = time {
+                on_change.emit(time);
+            }
+        })
+    });
+    let (current_value, set_value) = use_controllable_state(value, default_value, on_change);
+
+    let segment = |label: &'static str, segment: TimeSegment, options: Vec<(u32, String)>| {
+        let selected = current_value.map(|time| match segment {
+            TimeSegment::Hour if hour_cycle == HourCycle::H12 => to_12_hour(time.hour()).0,
+            TimeSegment::Hour => time.hour(),
+            TimeSegment::Minute => time.minute(),
+            TimeSegment::Second => time.second(),
+            TimeSegment::Period => u32::from(time.hour() >= 12),
+        });
+        let onchange = {
+            let set_value = set_value.clone();
+            Callback::from(move |e: Event| {
+                let select: HtmlSelectElement = e.target_unchecked_into();
+                if let Ok(value) = select.value().parse::<u32>() {
+                    let time = current_value.unwrap_or(NaiveTime::MIN);
+                    set_value.emit(Some(with_segment(time, segment, value, hour_cycle)));
+                }
+            })
+        };
+        html! {
+            <select
+                class="time-picker-select"
+                aria-label={label}
+                {disabled}
+                {onchange}
+            >
+                if selected.is_none() {
+                    <option value="" selected=true disabled=true>{ "--" }</option>
+                }
+                { for options.into_iter().map(|(value, text)| html! {
+                    <option value={value.to_string()} selected={selected == Some(value)}>
+                        { text }
+                    </option>
+                }) }
+            </select>
+        }
+    };
+
+    let hours: Vec<(u32, String)> = match hour_cycle {
+        HourCycle::H24 => (0..24).map(|hour| (hour, format!("{:02}", hour))).collect(),
+        HourCycle::H12 => std::iter::once(12)
+            .chain(1..12)
+            .map(|hour| (hour, hour.to_string()))
+            .collect(),
+    };
+    let two_digits = |values: Vec<u32>| -> Vec<(u32, String)> {
+        values
+            .into_iter()
+            .map(|value| (value, format!("{:02}", value)))
+            .collect()
+    };
+    let minutes = two_digits(step_values(
+        60,
+        minute_step,
+        current_value.map(|time| time.minute()),
+    ));
+    let seconds = two_digits((0..60).collect());
+
+    let classes: Classes = vec![
+        Classes::from("time-picker"),
+        if disabled {
+            Classes::from("time-picker-disabled")
+        } else {
+            Classes::new()
+        },
+        class,
+    ]
+    .into_iter()
+    .collect();
+
+    html! {
+        <div class={classes} role="group" aria-label={aria_label}>
+            { segment("Hours", TimeSegment::Hour, hours) }
+            <span class="time-picker-separator" aria-hidden="true">{ ":" }</span>
+            { segment("Minutes", TimeSegment::Minute, minutes) }
+            if show_seconds {
+                <span class="time-picker-separator" aria-hidden="true">{ ":" }</span>
+                { segment("Seconds", TimeSegment::Second, seconds) }
+            }
+            if hour_cycle == HourCycle::H12 {
+                { segment(
+                    "AM/PM",
+                    TimeSegment::Period,
+                    vec![(0, "AM".to_string()), (1, "PM".to_string())],
+                ) }
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn test_12_hour_conversion() {
+        assert_eq!(to_12_hour(0), (12, false));
+        assert_eq!(to_12_hour(11), (11, false));
+        assert_eq!(to_12_hour(12), (12, true));
+        assert_eq!(to_12_hour(23), (11, true));
+        assert_eq!(from_12_hour(12, false), 0);
+        assert_eq!(from_12_hour(12, true), 12);
+        assert_eq!(from_12_hour(7, true), 19);
+        assert!((0..24).all(|hour| {
+            let (twelve, pm) = to_12_hour(hour);
+            from_12_hour(twelve, pm) == hour
+        }));
+    }
+
+    #[test]
+    fn test_step_values() {
+        assert_eq!(step_values(60, 15, None), vec![0, 15, 30, 45]);
+        assert_eq!(step_values(60, 15, Some(20)), vec![0, 15, 20, 30, 45]);
+        assert_eq!(step_values(60, 15, Some(30)), vec![0, 15, 30, 45]);
+        assert_eq!(step_values(60, 0, None).len(), 60);
+    }
+
+    #[test]
+    fn test_with_segment() {
+        let t = time(14, 5, 9);
+        assert_eq!(
+            with_segment(t, TimeSegment::Hour, 8, HourCycle::H24),
+            time(8, 5, 9)
+        );
+        assert_eq!(
+            with_segment(t, TimeSegment::Hour, 8, HourCycle::H12),
+            time(20, 5, 9)
+        );
+        assert_eq!(
+            with_segment(t, TimeSegment::Hour, 12, HourCycle::H12),
+            time(12, 5, 9)
+        );
+        assert_eq!(
+            with_segment(t, TimeSegment::Period, 0, HourCycle::H12),
+            time(2, 5, 9)
+        );
+        assert_eq!(
+            with_segment(t, TimeSegment::Minute, 45, HourCycle::H24),
+            time(14, 45, 9)
+        );
+        assert_eq!(
+            with_segment(t, TimeSegment::Second, 0, HourCycle::H24),
+            time(14, 5, 0)
+        );
+        assert_eq!(with_segment(t, TimeSegment::Hour, 24, HourCycle::H24), t);
+        assert_eq!(with_segment(t, TimeSegment::Hour, 0, HourCycle::H12), t);
+    }
+
+    #[test]
+    fn test_format_time() {
+        let t = time(14, 5, 9);
+        assert_eq!(format_time(t, HourCycle::H24, false), "14:05");
+        assert_eq!(format_time(t, HourCycle::H24, true), "14:05:09");
+        assert_eq!(format_time(t, HourCycle::H12, false), "2:05 PM");
+        assert_eq!(
+            format_time(time(0, 30, 0), HourCycle::H12, true),
+            "12:30:00 AM"
+        );
+    }
+
+    #[test]
+    fn test_time_picker_props() {
+        let props = TimePickerProps {
+            value: Some(NaiveTime::from_hms_opt(9, 30, 0)),
+            default_value: None,
+            on_change: Some(Callback::from(|_: NaiveTime| {})),
+            hour_cycle: HourCycle::H12,
+            show_seconds: false,
+            minute_step: 15,
+            disabled: false,
+            aria_label: AttrValue::from("Time"),
+            class: Classes::new(),
+        };
+
+        assert_eq!(props.hour_cycle, HourCycle::H12);
+        assert_eq!(props.minute_step, 15);
+        assert_eq!(HourCycle::default(), HourCycle::H24);
+    }
+
+    #[test]
+    fn test_controlled_clear_stays_controlled() {
+        // A parent clearing the time passes `None`, which must not fall back
+        // to the internal state of an uncontrolled picker
+        let cleared: Option<NaiveTime> = None;
+        let props = yew::props!(TimePickerProps { value: cleared });
+        assert_eq!(props.value, Some(None));
+
+        let props = yew::props!(TimePickerProps {});
+        assert_eq!(props.value, None);
+    }
+}
@@ -32,6 +32,11 @@ use yew::prelude::*;
 
 /// Toast position
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ToastPosition {
     /// Top left corner
     TopLeft,
@@ -49,6 +54,11 @@ pub enum ToastPosition {
 
 /// Toast variant
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ToastVariant {
     /// Default variant
     Default,
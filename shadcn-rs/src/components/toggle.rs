@@ -35,6 +35,11 @@ use yew::prelude::*;
 
 /// Toggle button variant
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ToggleVariant {
     /// Default variant
     Default,
@@ -29,6 +29,11 @@ use yew::prelude::*;
 
 /// Toggle group type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ToggleGroupType {
     /// Only one toggle can be pressed at a time
     Single,
@@ -38,6 +43,11 @@ pub enum ToggleGroupType {
 
 /// Toggle group orientation
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum ToggleGroupOrientation {
     /// Horizontal layout
     Horizontal,
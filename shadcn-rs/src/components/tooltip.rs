@@ -186,7 +186,7 @@ mod tests {
 
         for position in positions {
             let props = TooltipContentProps {
-                position: position.clone(),
+                position,
                 class: Classes::new(),
                 children: Children::new(vec![]),
             };
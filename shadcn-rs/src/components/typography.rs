@@ -24,6 +24,11 @@ use yew::prelude::*;
 
 /// Typography variant type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TypographyVariant {
     /// Heading 1 (largest)
     H1,
@@ -55,6 +60,11 @@ pub enum TypographyVariant {
 
 /// Text alignment
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TextAlign {
     /// Align left
     Left,
@@ -68,6 +78,11 @@ pub enum TextAlign {
 
 /// Text color
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TextColor {
     /// Default color
     Default,
@@ -87,6 +102,11 @@ pub enum TextColor {
 
 /// Font weight
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum FontWeight {
     /// Normal weight (400)
     Normal,
@@ -4,7 +4,10 @@
 
 pub mod use_click_outside;
 pub mod use_controllable_state;
+pub mod use_element_size;
 pub mod use_escape_key;
+#[cfg(feature = "serde")]
+pub mod use_persisted_state;
 pub mod use_toggle;
 
 // Re-export hooks
@@ -12,5 +15,8 @@ pub use use_click_outside::{use_click_outside, use_click_outside_conditional};
 pub use use_controllable_state::{
     use_controllable_bool, use_controllable_state, use_controllable_state_optional,
 };
+pub use use_element_size::use_element_size;
 pub use use_escape_key::{use_escape_key, use_escape_key_conditional, use_key_press};
+#[cfg(feature = "serde")]
+pub use use_persisted_state::use_persisted_state;
 pub use use_toggle::{use_toggle, use_toggle_with_controls};
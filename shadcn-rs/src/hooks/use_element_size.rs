@@ -0,0 +1,77 @@
+//! useElementSize hook for tracking the size of an element
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::Closure;
+use web_sys::{Element, ResizeObserver};
+use yew::prelude::*;
+
+/// Hook tracking the size of a referenced element as it resizes
+///
+/// Returns the `(width, height)` of the element in CSS pixels, measured
+/// by a `ResizeObserver` when the element is first laid out and whenever
+/// its size changes. Returns `None` until then, and while `enabled` is
+/// false.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use yew::prelude::*;
+/// use shadcn_rs::use_element_size;
+///
+/// #[function_component(Panel)]
+/// fn panel() -> Html {
+///     let panel_ref = use_node_ref();
+///     let size = use_element_size(panel_ref.clone(), true);
+///
+///     html! {
+///         <div ref={panel_ref}>
+///             if let Some((width, _)) = size {
+///                 { format!("{}px wide", width) }
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_element_size(node_ref: NodeRef, enabled: bool) -> Option<(f64, f64)> {
+    let size = use_state_eq(|| None::<(f64, f64)>);
+
+    {
+        let size = size.clone();
+        use_effect_with((node_ref, enabled), move |(node_ref, enabled)| {
+            let observer = if *enabled {
+                node_ref.cast::<Element>().and_then(|element| {
+                    let measured = element.clone();
+                    let callback = Closure::<dyn Fn()>::new(move || {
+                        let rect = measured.get_bounding_client_rect();
+                        size.set(Some((rect.width(), rect.height())));
+                    });
+                    let observer = ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                    observer.observe(&element);
+                    Some((observer, callback))
+                })
+            } else {
+                size.set(None);
+                None
+            };
+
+            // Return cleanup function
+            move || {
+                if let Some((observer, _callback)) = observer {
+                    observer.disconnect();
+                }
+            }
+        });
+    }
+
+    *size
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_use_element_size_compiles() {
+        // This test just verifies the hook API compiles correctly
+        // Actual behavior testing requires wasm-bindgen-test in a browser environment
+    }
+}
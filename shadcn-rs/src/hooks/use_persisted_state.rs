@@ -0,0 +1,151 @@
+//! usePersistedState hook for state saved to localStorage
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Serialize, de::DeserializeOwned};
+use yew::prelude::*;
+
+/// Hook for state that is saved to `localStorage` under a key
+///
+/// The stored value is read on mount and again whenever `key` changes,
+/// falling back to `default` when it is missing or cannot be decoded.
+/// Every update is written back as JSON; a failed write is logged as a
+/// console warning.
+/// Pairs with the controlled props of components such as `DataTable`
+/// (`sort_state`, `filter_state`, `column_state`), `Resizable` (`sizes`) and
+/// `Sidebar` (`collapsed`).
+///
+/// Requires the `serde` feature.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use yew::prelude::*;
+/// use shadcn_rs::{Sidebar, use_persisted_state};
+///
+/// #[function_component(Layout)]
+/// fn layout() -> Html {
+///     let (collapsed, set_collapsed) = use_persisted_state("app:sidebar-collapsed", false);
+///
+///     html! {
+///         <>
+///             <button onclick={move |_| set_collapsed.emit(!collapsed)}>
+///                 { "Toggle sidebar" }
+///             </button>
+///             <Sidebar {collapsed}>
+///                 { "Navigation" }
+///             </Sidebar>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_persisted_state<T>(key: impl Into<AttrValue>, default: T) -> (T, Callback<T>)
+where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    let key: AttrValue = key.into();
+    let state =
+        use_state_eq(|| LocalStorage::get(key.as_str()).unwrap_or_else(|_| default.clone()));
+
+    // Load the value stored under a new key; the initializer already read
+    // the key the hook mounted with
+    let loaded_key = use_mut_ref(|| key.clone());
+    {
+        let state = state.clone();
+        use_effect_with(key.clone(), move |key| {
+            let changed = *loaded_key.borrow() != *key;
+            if changed {
+                *loaded_key.borrow_mut() = key.clone();
+                state.set(LocalStorage::get(key.as_str()).unwrap_or(default));
+            }
+        });
+    }
+
+    let set_state = {
+        let state = state.clone();
+        Callback::from(move |value: T| {
+            // Storage may be unavailable or full; the in-memory state still updates
+            if let Err(error) = LocalStorage::set(key.as_str(), &value) {
+                gloo::console::warn!(format!("Failed to persist \"{key}\": {error}"));
+            }
+            state.set(value);
+        })
+    };
+
+    ((*state).clone(), set_state)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::components::{
+        ColumnPin, DataTableColumnFilter, DataTableFilterState, DataTableFilterValue,
+        DataTableSort, DataTableState, ResizableOrientation,
+    };
+    use crate::types::{Size, Variant};
+
+    #[test]
+    fn test_use_persisted_state_compiles() {
+        // This test just verifies the hook API compiles correctly
+        // Actual behavior testing requires wasm-bindgen-test in a browser environment
+    }
+
+    #[test]
+    fn test_enum_serialization() {
+        assert_eq!(serde_json::to_string(&Size::Sm).unwrap(), "\"sm\"");
+        assert_eq!(
+            serde_json::from_str::<Variant>("\"destructive\"").unwrap(),
+            Variant::Destructive
+        );
+        assert_eq!(
+            serde_json::to_string(&ResizableOrientation::Vertical).unwrap(),
+            "\"vertical\""
+        );
+    }
+
+    #[test]
+    fn test_data_table_state_round_trip() {
+        let sort = vec![DataTableSort::descending("amount")];
+        let json = serde_json::to_string(&sort).unwrap();
+        assert_eq!(json, r#"[{"column":"amount","direction":"descending"}]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<DataTableSort>>(&json).unwrap(),
+            sort
+        );
+
+        let filters = DataTableFilterState {
+            global: "ken".to_string(),
+            columns: vec![DataTableColumnFilter {
+                column: "amount".to_string(),
+                value: DataTableFilterValue::Range {
+                    min: Some(100.0),
+                    max: None,
+                },
+            }],
+        };
+        let json = serde_json::to_string(&filters).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DataTableFilterState>(&json).unwrap(),
+            filters
+        );
+
+        let columns = DataTableState::default()
+            .with_pin("email", Some(ColumnPin::Left))
+            .with_width("email", 200.0)
+            .with_hidden("status", true);
+        let json = serde_json::to_string(&columns).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DataTableState>(&json).unwrap(),
+            columns
+        );
+    }
+
+    #[test]
+    fn test_partial_state_uses_defaults() {
+        let state: DataTableState = serde_json::from_str(r#"{"hidden_columns":["a"]}"#).unwrap();
+        assert!(state.is_hidden("a"));
+        assert!(state.column_order.is_empty());
+
+        let filters: DataTableFilterState = serde_json::from_str("{}").unwrap();
+        assert!(filters.is_empty());
+    }
+}
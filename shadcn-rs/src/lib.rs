@@ -30,6 +30,7 @@
 //! - Dark mode support via CSS variables
 //! - Touch gesture support for mobile
 //! - Type-safe component APIs with Rust enums
+//! - Optional `serde` feature for persisting layout state (`use_persisted_state`)
 //!
 //! # CSS
 //!
@@ -54,9 +55,11 @@ pub use types::{Alignment, Color, Position, Size, Variant};
 pub use utils::{Portal, class_if, class_names, generate_id, use_portal};
 
 // Re-export commonly used hooks
+#[cfg(feature = "serde")]
+pub use hooks::use_persisted_state;
 pub use hooks::{
     use_click_outside, use_click_outside_conditional, use_controllable_bool,
-    use_controllable_state, use_controllable_state_optional, use_escape_key,
+    use_controllable_state, use_controllable_state_optional, use_element_size, use_escape_key,
     use_escape_key_conditional, use_key_press, use_toggle, use_toggle_with_controls,
 };
 
@@ -2,6 +2,11 @@
 
 /// Content alignment options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum Alignment {
     /// Start alignment (left in LTR, right in RTL)
     #[default]
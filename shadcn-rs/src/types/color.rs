@@ -2,6 +2,11 @@
 
 /// Component color options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum Color {
     /// Default color (from theme)
     #[default]
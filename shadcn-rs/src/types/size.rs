@@ -2,6 +2,11 @@
 
 /// Standard component sizes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum Size {
     /// Extra small size
     Xs,
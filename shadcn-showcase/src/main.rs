@@ -121,7 +121,10 @@ fn switch(route: Route) -> Html {
         Route::Combobox => html! { <ComboboxPage /> },
         Route::Command => html! { <CommandPage /> },
         Route::DatePicker => html! { <DatePickerPage /> },
+        Route::DateRangePicker => html! { <DateRangePickerPage /> },
+        Route::DateTimePicker => html! { <DateTimePickerPage /> },
         Route::InputOtp => html! { <InputOtpPage /> },
+        Route::TimePicker => html! { <TimePickerPage /> },
 
         // Tier 8 - Complex
         Route::Carousel => html! { <CarouselPage /> },
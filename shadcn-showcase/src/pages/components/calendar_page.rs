@@ -1,16 +1,41 @@
 //! Calendar component showcase page
 
-use shadcn_rs::{Calendar, CalendarMode};
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate};
+use shadcn_rs::{Calendar, CalendarDay, CalendarMode, Locale, LocaleProvider};
 use yew::prelude::*;
 
 use crate::components::{ComponentPage, Example, PropDoc};
 
 #[function_component(CalendarPage)]
 pub fn calendar_page() -> Html {
+    let events: HashMap<NaiveDate, Vec<AttrValue>> = [4, 11, 12, 20, 27]
+        .into_iter()
+        .filter_map(|day| NaiveDate::from_ymd_opt(2024, 6, day))
+        .map(|date| (date, vec![AttrValue::from("marked")]))
+        .collect();
+    let is_weekend = Callback::from(|date: NaiveDate| date.weekday().num_days_from_monday() >= 5);
+    let price = Callback::from(|day: CalendarDay| {
+        let price = if day.date.weekday().num_days_from_monday() >= 4 {
+            "$129"
+        } else {
+            "$89"
+        };
+        html! {
+            <>
+                <span>{ day.date.day() }</span>
+                if !day.disabled {
+                    <span class="text-xs text-muted-foreground">{ price }</span>
+                }
+            </>
+        }
+    });
+
     let examples = vec![
         Example {
             title: "Default",
-            description: "A date picker calendar.",
+            description: "A date picker calendar. Focus a day to move with the arrow keys, Page Up/Down and Home/End.",
             demo: html! {
                 <Calendar class="rounded-md border" />
             },
@@ -27,45 +52,267 @@ pub fn calendar_page() -> Html {
             },
             code: r#"<Calendar mode="single" selected={selected} on_select={on_select} />"#,
         },
+        Example {
+            title: "Multiple Dates",
+            description: "Click dates to add them to or remove them from the selection.",
+            demo: html! {
+                <Calendar
+                    mode={CalendarMode::Multiple}
+                    class="rounded-md border"
+                />
+            },
+            code: r#"<Calendar mode={CalendarMode::Multiple} values={values} on_values_change={on_values_change} />"#,
+        },
+        Example {
+            title: "Date Range",
+            description: "Pick the first and last day of a stay of 2 to 14 nights.",
+            demo: html! {
+                <Calendar
+                    mode={CalendarMode::Range}
+                    min_range_days={3}
+                    max_range_days={15}
+                    class="rounded-md border"
+                />
+            },
+            code: r#"<Calendar
+    mode={CalendarMode::Range}
+    range={range}
+    on_range_change={on_range_change}
+    min_range_days={3}
+    max_range_days={15}
+/>"#,
+        },
+        Example {
+            title: "Multiple Months",
+            description: "Two months side by side for booking a range. Click a caption to jump to another month or year.",
+            demo: html! {
+                <Calendar
+                    mode={CalendarMode::Range}
+                    number_of_months={2}
+                    class="rounded-md border"
+                />
+            },
+            code: r#"<Calendar mode={CalendarMode::Range} number_of_months={2} />"#,
+        },
+        Example {
+            title: "Week Numbers",
+            description: "Weeks starting on Monday with ISO week numbers.",
+            demo: html! {
+                <Calendar
+                    first_day_of_week={1}
+                    show_week_numbers=true
+                    class="rounded-md border"
+                />
+            },
+            code: r#"<Calendar first_day_of_week={1} show_week_numbers=true />"#,
+        },
+        Example {
+            title: "Localized",
+            description: "A LocaleProvider sets month and day names and the first day of the week.",
+            demo: html! {
+                <div class="flex gap-4">
+                    <LocaleProvider locale={Locale::de()}>
+                        <Calendar show_week_numbers=true class="rounded-md border" />
+                    </LocaleProvider>
+                    <LocaleProvider locale={Locale::ja()}>
+                        <Calendar class="rounded-md border" />
+                    </LocaleProvider>
+                </div>
+            },
+            code: r#"<LocaleProvider locale={Locale::de()}>
+    <Calendar show_week_numbers=true />
+</LocaleProvider>"#,
+        },
+        Example {
+            title: "Date Limits",
+            description: "Dates outside a range, and specific dates, cannot be selected.",
+            demo: html! {
+                <Calendar
+                    selected="2024-06-12"
+                    min_date="2024-06-03"
+                    max_date="2024-06-28"
+                    disabled_dates={vec![AttrValue::from("2024-06-14"), AttrValue::from("2024-06-21")]}
+                    class="rounded-md border"
+                />
+            },
+            code: r#"<Calendar
+    selected="2024-06-12"
+    min_date="2024-06-03"
+    max_date="2024-06-28"
+    disabled_dates={vec![AttrValue::from("2024-06-14"), AttrValue::from("2024-06-21")]}
+/>"#,
+        },
+        Example {
+            title: "Event Markers",
+            description: "Modifiers add classes to days, here a dot for events; a predicate disables weekends.",
+            demo: html! {
+                <Calendar
+                    selected="2024-06-12"
+                    modifiers={events}
+                    disabled={is_weekend}
+                    class="rounded-md border"
+                />
+            },
+            code: r#"let events: HashMap<NaiveDate, Vec<AttrValue>> = event_dates
+    .into_iter()
+    .map(|date| (date, vec![AttrValue::from("marked")]))
+    .collect();
+
+html! {
+    <Calendar
+        modifiers={events}
+        disabled={Callback::from(|date: NaiveDate| date.weekday().num_days_from_monday() >= 5)}
+    />
+}"#,
+        },
+        Example {
+            title: "Custom Day Content",
+            description: "Render a price per night under each day.",
+            demo: html! {
+                <Calendar
+                    mode={CalendarMode::Range}
+                    selected="2024-06-12"
+                    min_date="2024-06-10"
+                    render_day={price}
+                    class="rounded-md border"
+                />
+            },
+            code: r#"<Calendar
+    mode={CalendarMode::Range}
+    min_date="2024-06-10"
+    render_day={Callback::from(|day: CalendarDay| html! {
+        <>
+            <span>{ day.date.day() }</span>
+            if !day.disabled {
+                <span class="text-xs">{ price_per_night(day.date) }</span>
+            }
+        </>
+    })}
+/>"#,
+        },
     ];
 
     let props = vec![
         PropDoc {
             name: "mode",
-            prop_type: "&str",
-            default: "\"single\"",
+            prop_type: "CalendarMode",
+            default: "Single",
             description: "Selection mode (single, multiple, range)",
         },
         PropDoc {
             name: "selected",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Selected date (YYYY-MM-DD); the calendar opens on its month",
+        },
+        PropDoc {
+            name: "onselect",
+            prop_type: "Callback<String>",
+            default: "-",
+            description: "Selection handler",
+        },
+        PropDoc {
+            name: "value",
             prop_type: "Option<NaiveDate>",
             default: "-",
-            description: "Selected date(s)",
+            description: "Selected date in single mode (controlled)",
         },
         PropDoc {
-            name: "on_select",
+            name: "on_change",
             prop_type: "Callback<NaiveDate>",
             default: "-",
-            description: "Selection handler",
+            description: "Called with the selected date in single mode",
+        },
+        PropDoc {
+            name: "values",
+            prop_type: "Option<Vec<NaiveDate>>",
+            default: "-",
+            description: "Selected dates in multiple mode (controlled)",
+        },
+        PropDoc {
+            name: "on_values_change",
+            prop_type: "Callback<Vec<NaiveDate>>",
+            default: "-",
+            description: "Called with the selected dates in multiple mode",
+        },
+        PropDoc {
+            name: "range",
+            prop_type: "Option<DateRange>",
+            default: "-",
+            description: "Selected range in range mode (controlled)",
+        },
+        PropDoc {
+            name: "on_range_change",
+            prop_type: "Callback<DateRange>",
+            default: "-",
+            description: "Called once both days of a range are picked",
+        },
+        PropDoc {
+            name: "min_range_days",
+            prop_type: "Option<u32>",
+            default: "-",
+            description: "Minimum days in a range, bounds included",
+        },
+        PropDoc {
+            name: "max_range_days",
+            prop_type: "Option<u32>",
+            default: "-",
+            description: "Maximum days in a range, bounds included",
         },
         PropDoc {
             name: "disabled_dates",
-            prop_type: "Vec<NaiveDate>",
+            prop_type: "Vec<AttrValue>",
             default: "[]",
             description: "Dates to disable",
         },
+        PropDoc {
+            name: "disabled",
+            prop_type: "Option<Callback<NaiveDate, bool>>",
+            default: "-",
+            description: "Returns true for dates that cannot be selected",
+        },
+        PropDoc {
+            name: "modifiers",
+            prop_type: "HashMap<NaiveDate, Vec<AttrValue>>",
+            default: "{}",
+            description: "CSS classes added to the buttons of dates, such as \"marked\"",
+        },
+        PropDoc {
+            name: "render_day",
+            prop_type: "Option<Callback<CalendarDay, Html>>",
+            default: "-",
+            description: "Content of the day buttons instead of the day of the month",
+        },
         PropDoc {
             name: "min_date",
-            prop_type: "Option<NaiveDate>",
+            prop_type: "Option<AttrValue>",
             default: "-",
             description: "Minimum selectable date",
         },
         PropDoc {
             name: "max_date",
-            prop_type: "Option<NaiveDate>",
+            prop_type: "Option<AttrValue>",
             default: "-",
             description: "Maximum selectable date",
         },
+        PropDoc {
+            name: "first_day_of_week",
+            prop_type: "Option<u8>",
+            default: "-",
+            description: "First day of the week (0 = Sunday, 1 = Monday), defaults to the locale's",
+        },
+        PropDoc {
+            name: "number_of_months",
+            prop_type: "u32",
+            default: "1",
+            description: "Number of months shown side by side",
+        },
+        PropDoc {
+            name: "show_week_numbers",
+            prop_type: "bool",
+            default: "false",
+            description: "Show ISO week numbers",
+        },
     ];
 
     html! { <ComponentPage name="Calendar" description="A date field component that allows users to enter and edit date." {examples} {props} /> }
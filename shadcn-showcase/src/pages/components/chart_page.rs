@@ -1,12 +1,18 @@
 //! Chart component showcase page
 
-use shadcn_rs::{Chart, ChartData, ChartType};
+use shadcn_rs::{Chart, ChartData, ChartPoint, ChartSeries, ChartType};
 use yew::prelude::*;
 
 use crate::components::{ComponentPage, Example, PropDoc};
 
 #[function_component(ChartPage)]
 pub fn chart_page() -> Html {
+    let clicked = use_state(|| None::<ChartPoint>);
+    let on_point_click = {
+        let clicked = clicked.clone();
+        Callback::from(move |point: ChartPoint| clicked.set(Some(point)))
+    };
+
     let bar_data = vec![
         ChartData {
             label: "Jan".to_string(),
@@ -84,6 +90,45 @@ pub fn chart_page() -> Html {
         },
     ];
 
+    let device_series = vec![
+        ChartSeries::from_pairs(
+            "Desktop",
+            [
+                ("Jan", 186.0),
+                ("Feb", 305.0),
+                ("Mar", 237.0),
+                ("Apr", 73.0),
+            ],
+        ),
+        ChartSeries::from_pairs(
+            "Mobile",
+            [
+                ("Jan", 80.0),
+                ("Feb", 200.0),
+                ("Mar", 120.0),
+                ("Apr", 190.0),
+            ],
+        ),
+    ];
+
+    let visitor_series = vec![
+        ChartSeries::from_pairs(
+            "Visitors",
+            [
+                ("Mon", 120.0),
+                ("Tue", 160.0),
+                ("Wed", 140.0),
+                ("Thu", 210.0),
+                ("Fri", 180.0),
+            ],
+        ),
+        ChartSeries::from_pairs(
+            "Signups",
+            [("Mon", 24.0), ("Tue", 38.0), ("Thu", 52.0), ("Fri", 41.0)],
+        )
+        .color("#e11d48"),
+    ];
+
     let examples = vec![
         Example {
             title: "Bar Chart",
@@ -137,6 +182,30 @@ pub fn chart_page() -> Html {
     data={data}
     width={400}
     height={300}
+/>"##,
+        },
+        Example {
+            title: "Donut Chart",
+            description: "A donut chart with the total in the middle and custom colors.",
+            demo: html! {
+                <Chart
+                    chart_type={ChartType::Donut}
+                    data={pie_data.clone()}
+                    width={400}
+                    height={300}
+                    colors={vec![
+                        AttrValue::from("#2563eb"),
+                        AttrValue::from("#60a5fa"),
+                        AttrValue::from("#93c5fd"),
+                        AttrValue::from("#bfdbfe"),
+                        AttrValue::from("#dbeafe"),
+                    ]}
+                />
+            },
+            code: r##"<Chart
+    chart_type={ChartType::Donut}
+    data={data}
+    colors={vec![AttrValue::from("#2563eb"), AttrValue::from("#60a5fa"), /* ... */]}
 />"##,
         },
         Example {
@@ -155,6 +224,137 @@ pub fn chart_page() -> Html {
     data={data}
     width={500}
     height={300}
+/>"##,
+        },
+        Example {
+            title: "Grouped Bars",
+            description: "Several series side by side in each category, with a legend listing the series.",
+            demo: html! {
+                <Chart
+                    chart_type={ChartType::Bar}
+                    series={device_series.clone()}
+                    width={500}
+                    height={300}
+                />
+            },
+            code: r##"let series = vec![
+    ChartSeries::from_pairs("Desktop", [("Jan", 186.0), ("Feb", 305.0), /* ... */]),
+    ChartSeries::from_pairs("Mobile", [("Jan", 80.0), ("Feb", 200.0), /* ... */]),
+];
+
+<Chart chart_type={ChartType::Bar} {series} />"##,
+        },
+        Example {
+            title: "Stacked Bars",
+            description: "Series stacked on each other in each category.",
+            demo: html! {
+                <Chart
+                    chart_type={ChartType::Bar}
+                    series={device_series.clone()}
+                    stacked={true}
+                    width={500}
+                    height={300}
+                />
+            },
+            code: r##"<Chart chart_type={ChartType::Bar} {series} stacked={true} />"##,
+        },
+        Example {
+            title: "Multiple Lines",
+            description: "One line per series. A series without a point for a category breaks its line there.",
+            demo: html! {
+                <Chart
+                    chart_type={ChartType::Line}
+                    series={visitor_series}
+                    width={500}
+                    height={300}
+                />
+            },
+            code: r##"let series = vec![
+    ChartSeries::from_pairs("Visitors", [("Mon", 120.0), ("Tue", 160.0), /* ... */]),
+    ChartSeries::from_pairs("Signups", [("Mon", 24.0), ("Tue", 38.0), ("Thu", 52.0), /* ... */])
+        .color("#e11d48"),
+];
+
+<Chart chart_type={ChartType::Line} {series} />"##,
+        },
+        Example {
+            title: "Stacked Area",
+            description: "Areas piled up on each other, showing the total and its parts.",
+            demo: html! {
+                <Chart
+                    chart_type={ChartType::Area}
+                    series={device_series.clone()}
+                    stacked={true}
+                    show_values={false}
+                    width={500}
+                    height={300}
+                />
+            },
+            code: r##"<Chart chart_type={ChartType::Area} {series} stacked={true} show_values={false} />"##,
+        },
+        Example {
+            title: "Formatted Values",
+            description: "Axis ticks, data labels and the legend formatted by a callback.",
+            demo: html! {
+                <Chart
+                    chart_type={ChartType::Bar}
+                    data={bar_data.clone()}
+                    format_value={Callback::from(|value: f64| AttrValue::from(format!("${}", value)))}
+                    width={500}
+                    height={300}
+                />
+            },
+            code: r##"<Chart
+    chart_type={ChartType::Bar}
+    data={data}
+    format_value={Callback::from(|value: f64| AttrValue::from(format!("${}", value)))}
+/>"##,
+        },
+        Example {
+            title: "Interactive",
+            description: "Hover for the values of every series, click a legend entry to hide its series, and click a bar to drill down.",
+            demo: html! {
+                <div class="grid gap-2">
+                    <Chart
+                        chart_type={ChartType::Bar}
+                        series={device_series.clone()}
+                        {on_point_click}
+                        width={500}
+                        height={300}
+                    />
+                    <p class="text-sm text-muted-foreground">
+                        {
+                            clicked.as_ref().map_or("Click a bar".to_string(), |point| {
+                                format!("{} in {}: {}", point.name, point.label, point.value)
+                            })
+                        }
+                    </p>
+                </div>
+            },
+            code: r##"<Chart
+    chart_type={ChartType::Bar}
+    {series}
+    on_point_click={Callback::from(|point: ChartPoint| { /* ... */ })}
+/>"##,
+        },
+        Example {
+            title: "Responsive",
+            description: "Fills the width of its container and redraws as it resizes, keeping a 16:9 aspect ratio. Resize the window to see it reflow.",
+            demo: html! {
+                <div class="w-full">
+                    <Chart
+                        chart_type={ChartType::Line}
+                        series={device_series}
+                        responsive={true}
+                        aspect_ratio={Some(16.0 / 9.0)}
+                    />
+                </div>
+            },
+            code: r##"<Chart
+    chart_type={ChartType::Line}
+    {series}
+    responsive={true}
+    aspect_ratio={Some(16.0 / 9.0)}
 />"##,
         },
     ];
@@ -169,20 +369,44 @@ pub fn chart_page() -> Html {
         PropDoc {
             name: "data",
             prop_type: "Vec<ChartData>",
-            default: "-",
-            description: "Chart data points",
+            default: "[]",
+            description: "Chart data points, a single series",
+        },
+        PropDoc {
+            name: "series",
+            prop_type: "Vec<ChartSeries>",
+            default: "[]",
+            description: "Named series of points, used instead of data when not empty",
+        },
+        PropDoc {
+            name: "stacked",
+            prop_type: "bool",
+            default: "false",
+            description: "Stack the series of bar and area charts",
         },
         PropDoc {
             name: "width",
             prop_type: "u32",
             default: "500",
-            description: "Chart width in pixels",
+            description: "Chart width in pixels, the initial width of a responsive chart",
         },
         PropDoc {
             name: "height",
             prop_type: "u32",
             default: "300",
-            description: "Chart height in pixels",
+            description: "Chart height in pixels, unless given by aspect_ratio",
+        },
+        PropDoc {
+            name: "responsive",
+            prop_type: "bool",
+            default: "false",
+            description: "Fill the width of the container and redraw as it resizes",
+        },
+        PropDoc {
+            name: "aspect_ratio",
+            prop_type: "Option<f64>",
+            default: "-",
+            description: "Ratio of width to height, giving the height from the width",
         },
         PropDoc {
             name: "show_legend",
@@ -202,11 +426,35 @@ pub fn chart_page() -> Html {
             default: "true",
             description: "Show axis labels",
         },
+        PropDoc {
+            name: "show_values",
+            prop_type: "bool",
+            default: "true",
+            description: "Show the value of each data point",
+        },
+        PropDoc {
+            name: "show_tooltip",
+            prop_type: "bool",
+            default: "true",
+            description: "Show a tooltip with the values at the hovered category or slice",
+        },
         PropDoc {
             name: "colors",
             prop_type: "Option<Vec<AttrValue>>",
             default: "-",
-            description: "Custom color scheme",
+            description: "Colors used in turn, defaults to the theme's chart palette",
+        },
+        PropDoc {
+            name: "format_value",
+            prop_type: "Option<Callback<f64, AttrValue>>",
+            default: "-",
+            description: "Formats the axis ticks, data labels and legend values",
+        },
+        PropDoc {
+            name: "on_point_click",
+            prop_type: "Option<Callback<ChartPoint>>",
+            default: "-",
+            description: "Called with the clicked bar, point or slice",
         },
     ];
 
@@ -1,71 +1,572 @@
 //! DataTable component showcase page
 
-use shadcn_rs::{Checkbox, Table, TableBody, TableCell, TableHead, TableHeader, TableRow};
+use shadcn_rs::{
+    Alignment, ColumnPin, DataTable, DataTableAggregate, DataTableCellEdit, DataTableColumn,
+    DataTableEditor, DataTableFilter, DataTableSort, DataTableState, DataTableValue,
+};
+use std::rc::Rc;
 use yew::prelude::*;
 
 use crate::components::{ComponentPage, Example, PropDoc};
 
+#[derive(Clone, PartialEq)]
+struct Payment {
+    status: &'static str,
+    email: &'static str,
+    amount: f64,
+}
+
+fn payments() -> Vec<Payment> {
+    vec![
+        Payment {
+            status: "Success",
+            email: "ken99@example.com",
+            amount: 316.0,
+        },
+        Payment {
+            status: "Processing",
+            email: "abe45@example.com",
+            amount: 242.0,
+        },
+        Payment {
+            status: "Failed",
+            email: "monserrat44@example.com",
+            amount: 837.0,
+        },
+    ]
+}
+
+fn many_payments() -> Vec<Payment> {
+    let statuses = ["Success", "Processing", "Failed"];
+    (0..42)
+        .map(|i| Payment {
+            status: statuses[i % statuses.len()],
+            email: "customer@example.com",
+            amount: 100.0 + (i as f64 * 37.0) % 900.0,
+        })
+        .collect()
+}
+
+fn large_payments() -> Vec<Payment> {
+    let statuses = ["Success", "Processing", "Failed"];
+    (0..50_000)
+        .map(|i| Payment {
+            status: statuses[i % statuses.len()],
+            email: "customer@example.com",
+            amount: (i as f64 * 7.31) % 1000.0,
+        })
+        .collect()
+}
+
+fn payment_columns() -> Vec<DataTableColumn<Payment>> {
+    vec![
+        DataTableColumn::new("status", "Status", |p: Payment| p.status),
+        DataTableColumn::new("email", "Email", |p: Payment| p.email),
+        DataTableColumn::new("amount", "Amount", |p: Payment| p.amount)
+            .cell(|p: Payment| html! { format!("${:.2}", p.amount) })
+            .align(Alignment::End),
+    ]
+}
+
+fn filterable_columns() -> Vec<DataTableColumn<Payment>> {
+    vec![
+        DataTableColumn::new("status", "Status", |p: Payment| p.status).filter(
+            DataTableFilter::Select(vec!["Success".into(), "Processing".into(), "Failed".into()]),
+        ),
+        DataTableColumn::new("email", "Email", |p: Payment| p.email)
+            .filter(DataTableFilter::Contains),
+        DataTableColumn::new("amount", "Amount", |p: Payment| p.amount)
+            .cell(|p: Payment| html! { format!("${:.2}", p.amount) })
+            .align(Alignment::End)
+            .filter(DataTableFilter::Range),
+    ]
+}
+
+fn grouped_columns() -> Vec<DataTableColumn<Payment>> {
+    vec![
+        DataTableColumn::new("status", "Status", |p: Payment| p.status),
+        DataTableColumn::new("email", "Email", |p: Payment| p.email)
+            .aggregate(DataTableAggregate::Count),
+        DataTableColumn::new("amount", "Amount", |p: Payment| p.amount)
+            .cell(|p: Payment| html! { format!("${:.2}", p.amount) })
+            .align(Alignment::End)
+            .aggregate(DataTableAggregate::Sum),
+    ]
+}
+
+#[derive(Clone, PartialEq)]
+struct FileEntry {
+    name: &'static str,
+    size: u32,
+    children: Vec<FileEntry>,
+}
+
+fn file(name: &'static str, size: u32) -> FileEntry {
+    FileEntry {
+        name,
+        size,
+        children: Vec::new(),
+    }
+}
+
+fn folder(name: &'static str, children: Vec<FileEntry>) -> FileEntry {
+    FileEntry {
+        name,
+        size: children.iter().map(|child| child.size).sum(),
+        children,
+    }
+}
+
+fn files() -> Vec<FileEntry> {
+    vec![
+        folder(
+            "src",
+            vec![
+                folder(
+                    "components",
+                    vec![file("button.rs", 12), file("data_table.rs", 96)],
+                ),
+                file("lib.rs", 4),
+            ],
+        ),
+        folder("styles", vec![file("components.css", 64)]),
+        file("Cargo.toml", 1),
+    ]
+}
+
+fn file_columns() -> Vec<DataTableColumn<FileEntry>> {
+    vec![
+        DataTableColumn::new("name", "Name", |f: FileEntry| f.name),
+        DataTableColumn::new("size", "Size", |f: FileEntry| f.size)
+            .cell(|f: FileEntry| html! { format!("{} KB", f.size) })
+            .align(Alignment::End),
+    ]
+}
+
+#[derive(Clone, PartialEq)]
+struct Invoice {
+    id: u32,
+    customer: String,
+    status: String,
+    amount: f64,
+    paid: bool,
+    due: String,
+}
+
+fn invoices() -> Vec<Invoice> {
+    [
+        ("Acme Corp", "Sent", 1_250.0, false, "2024-07-01"),
+        ("Globex", "Draft", 480.0, false, "2024-07-15"),
+        ("Initech", "Paid", 2_100.0, true, "2024-06-20"),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(id, (customer, status, amount, paid, due))| Invoice {
+        id: id as u32 + 1,
+        customer: customer.to_string(),
+        status: status.to_string(),
+        amount,
+        paid,
+        due: due.to_string(),
+    })
+    .collect()
+}
+
+fn invoice_columns() -> Vec<DataTableColumn<Invoice>> {
+    vec![
+        DataTableColumn::new("customer", "Customer", |i: Invoice| i.customer)
+            .editor(DataTableEditor::Text)
+            .validator(|value: DataTableValue| {
+                if value.to_string().trim().is_empty() {
+                    Err("Customer is required")
+                } else {
+                    Ok(())
+                }
+            }),
+        DataTableColumn::new("status", "Status", |i: Invoice| i.status).editor(
+            DataTableEditor::Select(vec!["Draft".into(), "Sent".into(), "Paid".into()]),
+        ),
+        DataTableColumn::new("amount", "Amount", |i: Invoice| i.amount)
+            .cell(|i: Invoice| html! { format!("${:.2}", i.amount) })
+            .align(Alignment::End)
+            .editor(DataTableEditor::Number)
+            .validator(|value: DataTableValue| match value.as_number() {
+                Some(amount) if amount > 0.0 => Ok(()),
+                _ => Err("Enter an amount above zero"),
+            }),
+        DataTableColumn::new("paid", "Paid", |i: Invoice| i.paid).editor(DataTableEditor::Checkbox),
+        DataTableColumn::new("due", "Due", |i: Invoice| i.due).editor(DataTableEditor::Date),
+    ]
+}
+
+/// Apply a committed cell edit to an invoice
+fn apply_edit(invoice: &mut Invoice, edit: &DataTableCellEdit) {
+    match (edit.column_id.as_str(), &edit.value) {
+        ("customer", value) => invoice.customer = value.to_string(),
+        ("status", value) => invoice.status = value.to_string(),
+        ("amount", DataTableValue::Number(amount)) => invoice.amount = *amount,
+        ("paid", DataTableValue::Bool(paid)) => invoice.paid = *paid,
+        ("due", value) => invoice.due = value.to_string(),
+        _ => {}
+    }
+}
+
 #[function_component(DataTablePage)]
 pub fn data_table_page() -> Html {
-    let examples = vec![Example {
-        title: "Default",
-        description: "A data table with sorting and selection.",
-        demo: html! {
-            <div class="rounded-md border">
-                <Table>
-                    <TableHeader>
-                        <TableRow>
-                            <TableHead class="w-[50px]">
-                                <Checkbox />
-                            </TableHead>
-                            <TableHead>{ "Status" }</TableHead>
-                            <TableHead>{ "Email" }</TableHead>
-                            <TableHead class="text-right">{ "Amount" }</TableHead>
-                        </TableRow>
-                    </TableHeader>
-                    <TableBody>
-                        <TableRow>
-                            <TableCell><Checkbox /></TableCell>
-                            <TableCell>{ "Success" }</TableCell>
-                            <TableCell>{ "ken99@example.com" }</TableCell>
-                            <TableCell class="text-right">{ "$316.00" }</TableCell>
-                        </TableRow>
-                        <TableRow>
-                            <TableCell><Checkbox /></TableCell>
-                            <TableCell>{ "Processing" }</TableCell>
-                            <TableCell>{ "abe45@example.com" }</TableCell>
-                            <TableCell class="text-right">{ "$242.00" }</TableCell>
-                        </TableRow>
-                        <TableRow>
-                            <TableCell><Checkbox /></TableCell>
-                            <TableCell>{ "Failed" }</TableCell>
-                            <TableCell>{ "monserrat44@example.com" }</TableCell>
-                            <TableCell class="text-right">{ "$837.00" }</TableCell>
-                        </TableRow>
-                    </TableBody>
-                </Table>
-            </div>
-        },
-        code: r#"<DataTable
-    columns={columns}
+    // Shared so the tables only recompute their rows when the data changes
+    let payment_rows = use_memo((), |_| payments());
+    let many_payment_rows = use_memo((), |_| many_payments());
+    let large_payment_rows = use_memo((), |_| large_payments());
+    let file_rows = use_memo((), |_| files());
+    let invoice_rows = use_state(|| Rc::new(invoices()));
+    let on_cell_edit = {
+        let invoice_rows = invoice_rows.clone();
+        Callback::from(move |edit: DataTableCellEdit| {
+            let mut rows = (**invoice_rows).clone();
+            if let Some(invoice) = rows
+                .iter_mut()
+                .find(|invoice| invoice.id.to_string() == edit.row_id.as_str())
+            {
+                apply_edit(invoice, &edit);
+            }
+            invoice_rows.set(Rc::new(rows));
+        })
+    };
+
+    let examples = vec![
+        Example {
+            title: "Default",
+            description: "A data table rendering typed rows through column definitions.",
+            demo: html! {
+                <DataTable<Payment> data={payment_rows.clone()} columns={payment_columns()} />
+            },
+            code: r#"let columns = vec![
+    DataTableColumn::new("status", "Status", |p: Payment| p.status),
+    DataTableColumn::new("email", "Email", |p: Payment| p.email),
+    DataTableColumn::new("amount", "Amount", |p: Payment| p.amount)
+        .cell(|p: Payment| html! { format!("${:.2}", p.amount) })
+        .align(Alignment::End),
+];
+
+<DataTable<Payment> data={data} columns={columns} />"#,
+        },
+        Example {
+            title: "Sorting",
+            description: "Click a header to cycle ascending, descending and unsorted. Shift-click to sort by several columns.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={payment_rows.clone()}
+                    columns={payment_columns()}
+                    sortable={true}
+                    default_sort={vec![DataTableSort::descending("amount")]}
+                />
+            },
+            code: r#"<DataTable<Payment>
     data={data}
+    columns={columns}
     sortable={true}
+    default_sort={vec![DataTableSort::descending("amount")]}
+/>"#,
+        },
+        Example {
+            title: "Filtering",
+            description: "A global search box plus per-column text, range and multi-select filters.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={payment_rows.clone()}
+                    columns={filterable_columns()}
+                    filterable={true}
+                />
+            },
+            code: r#"let columns = vec![
+    DataTableColumn::new("status", "Status", |p: Payment| p.status)
+        .filter(DataTableFilter::Select(vec!["Success".into(), "Processing".into(), "Failed".into()])),
+    DataTableColumn::new("email", "Email", |p: Payment| p.email)
+        .filter(DataTableFilter::Contains),
+    DataTableColumn::new("amount", "Amount", |p: Payment| p.amount)
+        .filter(DataTableFilter::Range),
+];
+
+<DataTable<Payment>
+    data={data}
+    columns={columns}
+    filterable={true}
+    on_filter_change={on_filter_change}
+/>"#,
+        },
+        Example {
+            title: "Selection",
+            description: "Select rows with the checkboxes, shift-click to select a range. Selection is keyed by row id, so it survives sorting.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={payment_rows.clone()}
+                    columns={payment_columns()}
+                    sortable={true}
+                    selectable={true}
+                    row_id={Callback::from(|p: Payment| AttrValue::from(p.email))}
+                />
+            },
+            code: r#"<DataTable<Payment>
+    data={data}
+    columns={columns}
     selectable={true}
+    row_id={Callback::from(|p: Payment| AttrValue::from(p.email))}
+    selected={Some((*selected).clone())}
+    on_selection_change={on_selection_change}
 />"#,
-    }];
+        },
+        Example {
+            title: "Pagination",
+            description: "Paged rows with a page size selector. Set manual_pagination and total_rows to page on the server.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={many_payment_rows.clone()}
+                    columns={payment_columns()}
+                    sortable={true}
+                    paginated={true}
+                    rows_per_page={5}
+                    page_size_options={vec![5, 10, 20]}
+                />
+            },
+            code: r#"// Client-side
+<DataTable<Payment>
+    data={data}
+    columns={columns}
+    paginated={true}
+    rows_per_page={5}
+    page_size_options={vec![5, 10, 20]}
+/>
+
+// Server-side: `data` holds the current page only
+<DataTable<Payment>
+    data={page_rows}
+    columns={columns}
+    paginated={true}
+    manual_pagination={true}
+    total_rows={Some(total)}
+    page={Some(*page)}
+    on_page_change={on_page_change}
+/>"#,
+        },
+        Example {
+            title: "Column Layout",
+            description: "Drag header borders to resize, drag headers to reorder, and toggle or pin columns from the Columns menu. The status column starts pinned left.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={payment_rows.clone()}
+                    columns={payment_columns()}
+                    resizable_columns={true}
+                    reorderable_columns={true}
+                    column_menu={true}
+                    default_column_state={DataTableState::default().with_pin("status", Some(ColumnPin::Left))}
+                />
+            },
+            code: r#"// Save `state` per user and pass it back as `column_state`
+let on_column_state_change = {
+    let layout = layout.clone();
+    Callback::from(move |state: DataTableState| layout.set(state))
+};
+
+<DataTable<Payment>
+    data={data}
+    columns={columns}
+    resizable_columns={true}
+    reorderable_columns={true}
+    column_menu={true}
+    column_state={Some((*layout).clone())}
+    on_column_state_change={on_column_state_change}
+/>"#,
+        },
+        Example {
+            title: "Editing",
+            description: "Click a cell (or press Enter) to edit it. Enter commits, Escape cancels and Tab moves to the next editable cell. Invalid values are rejected with a message.",
+            demo: html! {
+                <DataTable<Invoice>
+                    data={(*invoice_rows).clone()}
+                    columns={invoice_columns()}
+                    editable={true}
+                    row_id={Callback::from(|i: Invoice| AttrValue::from(i.id.to_string()))}
+                    on_cell_edit={on_cell_edit}
+                />
+            },
+            code: r#"let columns = vec![
+    DataTableColumn::new("customer", "Customer", |i: Invoice| i.customer)
+        .editor(DataTableEditor::Text)
+        .validator(|value: DataTableValue| {
+            if value.to_string().trim().is_empty() {
+                Err("Customer is required")
+            } else {
+                Ok(())
+            }
+        }),
+    DataTableColumn::new("status", "Status", |i: Invoice| i.status)
+        .editor(DataTableEditor::Select(vec!["Draft".into(), "Sent".into(), "Paid".into()])),
+    DataTableColumn::new("amount", "Amount", |i: Invoice| i.amount)
+        .editor(DataTableEditor::Number),
+    DataTableColumn::new("paid", "Paid", |i: Invoice| i.paid)
+        .editor(DataTableEditor::Checkbox),
+    DataTableColumn::new("due", "Due", |i: Invoice| i.due)
+        .editor(DataTableEditor::Date),
+];
+
+// The table reports edits; apply them to your data
+let on_cell_edit = Callback::from(move |edit: DataTableCellEdit| {
+    // edit.row_id, edit.column_id, edit.value
+});
+
+<DataTable<Invoice>
+    data={data}
+    columns={columns}
+    editable={true}
+    row_id={Callback::from(|i: Invoice| AttrValue::from(i.id.to_string()))}
+    on_cell_edit={on_cell_edit}
+/>"#,
+        },
+        Example {
+            title: "Grouping",
+            description: "Rows grouped by status with collapsible group headers. Group headers show the count of emails and the total amount.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={many_payment_rows.clone()}
+                    columns={grouped_columns()}
+                    sortable={true}
+                    group_by={vec![AttrValue::from("status")]}
+                    default_collapsed_groups={vec![AttrValue::from("status:Failed")]}
+                    max_height="400px"
+                />
+            },
+            code: r#"let columns = vec![
+    DataTableColumn::new("status", "Status", |p: Payment| p.status),
+    DataTableColumn::new("email", "Email", |p: Payment| p.email)
+        .aggregate(DataTableAggregate::Count),
+    DataTableColumn::new("amount", "Amount", |p: Payment| p.amount)
+        .aggregate(DataTableAggregate::Sum),
+];
+
+// Group keys are "column:value" segments joined by "/"
+<DataTable<Payment>
+    data={data}
+    columns={columns}
+    group_by={vec![AttrValue::from("status")]}
+    default_collapsed_groups={vec![AttrValue::from("status:Failed")]}
+/>"#,
+        },
+        Example {
+            title: "Expandable Rows",
+            description: "A detail panel below each expanded row, and tree data whose children are shown when their parent is expanded.",
+            demo: html! {
+                <div class="space-y-4">
+                    <DataTable<Payment>
+                        data={payment_rows.clone()}
+                        columns={payment_columns()}
+                        render_detail={Callback::from(|p: Payment| html! {
+                            <p class="text-sm text-muted-foreground">
+                                { format!("Receipt for {} sent to {}.", p.status, p.email) }
+                            </p>
+                        })}
+                    />
+                    <DataTable<FileEntry>
+                        data={file_rows.clone()}
+                        columns={file_columns()}
+                        row_id={Callback::from(|f: FileEntry| AttrValue::from(f.name))}
+                        row_children={Callback::from(|f: FileEntry| f.children)}
+                        has_children={Callback::from(|f: FileEntry| !f.children.is_empty())}
+                        default_expanded={vec![AttrValue::from("src")]}
+                    />
+                </div>
+            },
+            code: r#"<DataTable<Payment>
+    data={data}
+    columns={columns}
+    render_detail={Callback::from(|p: Payment| html! {
+        <p>{ format!("Receipt sent to {}.", p.email) }</p>
+    })}
+/>
+
+<DataTable<FileEntry>
+    data={files}
+    columns={columns}
+    row_id={Callback::from(|f: FileEntry| AttrValue::from(f.name))}
+    row_children={Callback::from(|f: FileEntry| f.children)}
+    has_children={Callback::from(|f: FileEntry| !f.children.is_empty())}
+    default_expanded={vec![AttrValue::from("src")]}
+/>"#,
+        },
+        Example {
+            title: "Export",
+            description: "Export the filtered and sorted rows of all pages as CSV or TSV (and JSON with the serde feature), or copy the selected rows as TSV to paste them into a spreadsheet.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={many_payment_rows.clone()}
+                    columns={filterable_columns()}
+                    sortable={true}
+                    filterable={true}
+                    selectable={true}
+                    paginated={true}
+                    exportable={true}
+                    export_filename="payments"
+                />
+            },
+            code: r#"<DataTable<Payment>
+    data={data}
+    columns={columns}
+    filterable={true}
+    selectable={true}
+    paginated={true}
+    exportable={true}
+    export_filename="payments"
+/>
+
+// Or export programmatically
+let csv = export_rows(&data, &columns, &DataTableState::default(), DataTableExportFormat::Csv);"#,
+        },
+        Example {
+            title: "Virtualized",
+            description: "50,000 rows with only the visible ones mounted. The header stays in place while the rows scroll.",
+            demo: html! {
+                <DataTable<Payment>
+                    data={large_payment_rows.clone()}
+                    columns={payment_columns()}
+                    sortable={true}
+                    virtualized={true}
+                    sticky_header={true}
+                    max_height="400px"
+                />
+            },
+            code: r#"<DataTable<Payment>
+    data={data}
+    columns={columns}
+    virtualized={true}
+    sticky_header={true}
+    max_height="400px"
+/>
+
+// Variable row heights, inside an existing ScrollArea
+<ScrollArea max_height="600px">
+    <DataTable<Payment>
+        data={data}
+        columns={columns}
+        virtualized={true}
+        measure_rows={true}
+        row_height={48.0}
+        overscan={20}
+    />
+</ScrollArea>"#,
+        },
+    ];
 
     let props = vec![
         PropDoc {
             name: "columns",
-            prop_type: "Vec<Column>",
-            default: "-",
-            description: "Column definitions",
+            prop_type: "Vec<DataTableColumn<T>>",
+            default: "[]",
+            description: "Column definitions (id, header, accessor, cell renderer, width, alignment)",
         },
         PropDoc {
             name: "data",
-            prop_type: "Vec<T>",
+            prop_type: "Rc<Vec<T>>",
             default: "-",
-            description: "Data rows",
+            description: "Data rows, compared by identity when recomputing rows",
         },
         PropDoc {
             name: "sortable",
@@ -73,6 +574,48 @@ pub fn data_table_page() -> Html {
             default: "false",
             description: "Enable sorting",
         },
+        PropDoc {
+            name: "sort_state",
+            prop_type: "Option<Vec<DataTableSort>>",
+            default: "None",
+            description: "Controlled sort state, ordered by priority",
+        },
+        PropDoc {
+            name: "on_sort_change",
+            prop_type: "Option<Callback<Vec<DataTableSort>>>",
+            default: "None",
+            description: "Called when a header is clicked",
+        },
+        PropDoc {
+            name: "manual_sorting",
+            prop_type: "bool",
+            default: "false",
+            description: "Skip client-side sorting (server-side sorting)",
+        },
+        PropDoc {
+            name: "filterable",
+            prop_type: "bool",
+            default: "false",
+            description: "Show the global search box and column filter inputs",
+        },
+        PropDoc {
+            name: "filter_state",
+            prop_type: "Option<DataTableFilterState>",
+            default: "None",
+            description: "Controlled filter state (global query and column filters)",
+        },
+        PropDoc {
+            name: "on_filter_change",
+            prop_type: "Option<Callback<DataTableFilterState>>",
+            default: "None",
+            description: "Called when a filter changes",
+        },
+        PropDoc {
+            name: "manual_filtering",
+            prop_type: "bool",
+            default: "false",
+            description: "Skip client-side filtering (server-side filtering)",
+        },
         PropDoc {
             name: "selectable",
             prop_type: "bool",
@@ -80,17 +623,215 @@ pub fn data_table_page() -> Html {
             description: "Enable row selection",
         },
         PropDoc {
-            name: "pagination",
+            name: "selection_mode",
+            prop_type: "SelectionMode",
+            default: "None",
+            description: "Single or Multiple (Multiple when only selectable is set)",
+        },
+        PropDoc {
+            name: "row_id",
+            prop_type: "Option<Callback<T, AttrValue>>",
+            default: "None",
+            description: "Unique row id used to key selection (defaults to the row index)",
+        },
+        PropDoc {
+            name: "selected",
+            prop_type: "Option<Vec<AttrValue>>",
+            default: "None",
+            description: "Controlled selected row ids",
+        },
+        PropDoc {
+            name: "on_selection_change",
+            prop_type: "Option<Callback<Vec<AttrValue>>>",
+            default: "None",
+            description: "Called with the selected row ids",
+        },
+        PropDoc {
+            name: "editable",
+            prop_type: "bool",
+            default: "false",
+            description: "Enable inline editing of columns with an editor",
+        },
+        PropDoc {
+            name: "on_cell_edit",
+            prop_type: "Option<Callback<DataTableCellEdit>>",
+            default: "None",
+            description: "Called with the row id, column id and value of each valid edit",
+        },
+        PropDoc {
+            name: "group_by",
+            prop_type: "Vec<AttrValue>",
+            default: "[]",
+            description: "Column ids to group the rows of the page by, outermost first",
+        },
+        PropDoc {
+            name: "collapsed_groups",
+            prop_type: "Option<Vec<AttrValue>>",
+            default: "None",
+            description: "Collapsed group keys (controlled)",
+        },
+        PropDoc {
+            name: "on_collapsed_groups_change",
+            prop_type: "Option<Callback<Vec<AttrValue>>>",
+            default: "None",
+            description: "Called with the collapsed group keys",
+        },
+        PropDoc {
+            name: "render_detail",
+            prop_type: "Option<Callback<T, Html>>",
+            default: "None",
+            description: "Detail panel shown below an expanded row",
+        },
+        PropDoc {
+            name: "row_children",
+            prop_type: "Option<Callback<T, Vec<T>>>",
+            default: "None",
+            description: "Child rows for tree data",
+        },
+        PropDoc {
+            name: "has_children",
+            prop_type: "Option<Callback<T, bool>>",
+            default: "None",
+            description: "Whether a row has children, so row_children only runs for expanded rows",
+        },
+        PropDoc {
+            name: "expanded",
+            prop_type: "Option<Vec<AttrValue>>",
+            default: "None",
+            description: "Expanded row ids (controlled)",
+        },
+        PropDoc {
+            name: "on_expanded_change",
+            prop_type: "Option<Callback<Vec<AttrValue>>>",
+            default: "None",
+            description: "Called with the expanded row ids",
+        },
+        PropDoc {
+            name: "paginated",
             prop_type: "bool",
             default: "false",
             description: "Enable pagination",
         },
         PropDoc {
-            name: "page_size",
+            name: "rows_per_page",
             prop_type: "usize",
             default: "10",
             description: "Rows per page",
         },
+        PropDoc {
+            name: "page_size_options",
+            prop_type: "Vec<usize>",
+            default: "[10, 20, 50, 100]",
+            description: "Choices of the page size selector",
+        },
+        PropDoc {
+            name: "page",
+            prop_type: "Option<usize>",
+            default: "None",
+            description: "Controlled zero-based page index",
+        },
+        PropDoc {
+            name: "on_page_change",
+            prop_type: "Option<Callback<usize>>",
+            default: "None",
+            description: "Called with the new zero-based page index",
+        },
+        PropDoc {
+            name: "manual_pagination",
+            prop_type: "bool",
+            default: "false",
+            description: "Data is already paged (server-side pagination)",
+        },
+        PropDoc {
+            name: "total_rows",
+            prop_type: "Option<usize>",
+            default: "None",
+            description: "Total row count for manual pagination",
+        },
+        PropDoc {
+            name: "column_state",
+            prop_type: "Option<DataTableState>",
+            default: "None",
+            description: "Controlled column order, widths, pinning and visibility",
+        },
+        PropDoc {
+            name: "on_column_state_change",
+            prop_type: "Option<Callback<DataTableState>>",
+            default: "None",
+            description: "Called when columns are resized, moved, pinned or hidden",
+        },
+        PropDoc {
+            name: "resizable_columns",
+            prop_type: "bool",
+            default: "false",
+            description: "Drag header borders (or use arrow keys) to resize columns",
+        },
+        PropDoc {
+            name: "reorderable_columns",
+            prop_type: "bool",
+            default: "false",
+            description: "Drag headers to reorder columns",
+        },
+        PropDoc {
+            name: "column_menu",
+            prop_type: "bool",
+            default: "false",
+            description: "Show the Columns menu for hiding and pinning columns",
+        },
+        PropDoc {
+            name: "exportable",
+            prop_type: "bool",
+            default: "false",
+            description: "Show the Export menu, and a Copy button for selected rows",
+        },
+        PropDoc {
+            name: "export_filename",
+            prop_type: "AttrValue",
+            default: "\"export\"",
+            description: "File name of exports, without the extension",
+        },
+        PropDoc {
+            name: "on_export",
+            prop_type: "Option<Callback<DataTableExport>>",
+            default: "None",
+            description: "Called with each export instead of downloading a file",
+        },
+        PropDoc {
+            name: "virtualized",
+            prop_type: "bool",
+            default: "false",
+            description: "Only mount the rows visible in the scroll viewport",
+        },
+        PropDoc {
+            name: "row_height",
+            prop_type: "f64",
+            default: "40.0",
+            description: "Row height in pixels (estimate when measuring)",
+        },
+        PropDoc {
+            name: "measure_rows",
+            prop_type: "bool",
+            default: "false",
+            description: "Measure rendered row heights instead of assuming row_height",
+        },
+        PropDoc {
+            name: "overscan",
+            prop_type: "usize",
+            default: "10",
+            description: "Rows mounted above and below the viewport",
+        },
+        PropDoc {
+            name: "max_height",
+            prop_type: "Option<AttrValue>",
+            default: "None",
+            description: "Scroll the table inside a ScrollArea of this height",
+        },
+        PropDoc {
+            name: "sticky_header",
+            prop_type: "bool",
+            default: "false",
+            description: "Keep the header visible while scrolling",
+        },
     ];
 
     html! { <ComponentPage name="Data Table" description="Powerful table with sorting, filtering, and pagination." {examples} {props} /> }
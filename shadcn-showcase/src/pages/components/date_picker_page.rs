@@ -1,6 +1,7 @@
 //! DatePicker component showcase page
 
-use shadcn_rs::DatePicker;
+use chrono::NaiveDate;
+use shadcn_rs::{DatePicker, Locale, LocaleProvider};
 use yew::prelude::*;
 
 use crate::components::{ComponentPage, Example, PropDoc};
@@ -30,6 +31,46 @@ pub fn date_picker_page() -> Html {
     <DatePicker placeholder="Select date" />
 </div>"#,
         },
+        Example {
+            title: "Text Entry",
+            description: "Type a date in the display format, or pick it from the calendar. Invalid dates are flagged.",
+            demo: html! {
+                <LocaleProvider locale={Locale::de()}>
+                    <DatePicker editable=true placeholder="TT.MM.JJJJ" />
+                </LocaleProvider>
+            },
+            code: r#"<LocaleProvider locale={Locale::de()}>
+    <DatePicker editable=true placeholder="TT.MM.JJJJ" on_change={on_change} />
+</LocaleProvider>"#,
+        },
+        Example {
+            title: "Localized",
+            description: "The selected date is shown in the date format of the locale.",
+            demo: html! {
+                <LocaleProvider locale={Locale::fr()}>
+                    <DatePicker default_value={NaiveDate::from_ymd_opt(2024, 7, 14)} />
+                </LocaleProvider>
+            },
+            code: r#"<LocaleProvider locale={Locale::fr()}>
+    <DatePicker default_value={NaiveDate::from_ymd_opt(2024, 7, 14)} />
+</LocaleProvider>"#,
+        },
+        Example {
+            title: "Date Range Limits",
+            description: "Only dates between the minimum and maximum can be picked.",
+            demo: html! {
+                <DatePicker
+                    default_value={NaiveDate::from_ymd_opt(2024, 6, 12)}
+                    min_date="2024-06-03"
+                    max_date="2024-06-28"
+                />
+            },
+            code: r#"<DatePicker
+    default_value={NaiveDate::from_ymd_opt(2024, 6, 12)}
+    min_date="2024-06-03"
+    max_date="2024-06-28"
+/>"#,
+        },
     ];
 
     let props = vec![
@@ -39,6 +80,18 @@ pub fn date_picker_page() -> Html {
             default: "-",
             description: "Controlled selected date",
         },
+        PropDoc {
+            name: "default_value",
+            prop_type: "Option<NaiveDate>",
+            default: "-",
+            description: "Initially selected date (uncontrolled)",
+        },
+        PropDoc {
+            name: "editable",
+            prop_type: "bool",
+            default: "false",
+            description: "Type the date into a text input parsed with the display format",
+        },
         PropDoc {
             name: "placeholder",
             prop_type: "Option<String>",
@@ -57,11 +110,23 @@ pub fn date_picker_page() -> Html {
             default: "false",
             description: "Disable the picker",
         },
+        PropDoc {
+            name: "min_date",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Minimum selectable date (YYYY-MM-DD)",
+        },
+        PropDoc {
+            name: "max_date",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Maximum selectable date (YYYY-MM-DD)",
+        },
         PropDoc {
             name: "format",
-            prop_type: "&str",
-            default: "\"%Y-%m-%d\"",
-            description: "Date format string",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Display format pattern such as \"DD.MM.YYYY\", defaults to the locale's",
         },
     ];
 
@@ -0,0 +1,132 @@
+//! DateRangePicker component showcase page
+
+use chrono::{Days, NaiveDate};
+use shadcn_rs::{DateRange, DateRangePicker, DateRangePreset, Locale, LocaleProvider, use_locale};
+use yew::prelude::*;
+
+use crate::components::{ComponentPage, Example, PropDoc};
+
+#[function_component(DateRangePickerPage)]
+pub fn date_range_picker_page() -> Html {
+    let locale = use_locale();
+    let mut custom_presets = DateRangePreset::defaults(&locale);
+    custom_presets.push(DateRangePreset::last_days(&locale, 90));
+    custom_presets.push(DateRangePreset::new("Next 2 weeks", |today: NaiveDate| {
+        DateRange::new(today, today + Days::new(13))
+    }));
+
+    let week = NaiveDate::from_ymd_opt(2024, 7, 8)
+        .zip(NaiveDate::from_ymd_opt(2024, 7, 14))
+        .map(|(start, end)| DateRange::new(start, end));
+
+    let examples = vec![
+        Example {
+            title: "Default",
+            description: "Two months side by side with the default presets.",
+            demo: html! {
+                <DateRangePicker />
+            },
+            code: r#"<DateRangePicker on_change={on_change} />"#,
+        },
+        Example {
+            title: "Custom Presets",
+            description: "Presets are computed from today's date; add your own next to the defaults.",
+            demo: html! {
+                <DateRangePicker presets={custom_presets} />
+            },
+            code: r#"let locale = use_locale();
+let mut presets = DateRangePreset::defaults(&locale);
+presets.push(DateRangePreset::last_days(&locale, 90));
+presets.push(DateRangePreset::new("Next 2 weeks", |today: NaiveDate| {
+    DateRange::new(today, today + Days::new(13))
+}));
+
+html! { <DateRangePicker {presets} /> }"#,
+        },
+        Example {
+            title: "Calendar Only",
+            description: "Without presets, a single month and at most 14 days.",
+            demo: html! {
+                <DateRangePicker presets={Vec::new()} number_of_months={1} max_range_days={14} />
+            },
+            code: r#"<DateRangePicker presets={Vec::new()} number_of_months={1} max_range_days={14} />"#,
+        },
+        Example {
+            title: "Localized",
+            description: "The presets and the selected range follow the language and date format of the locale.",
+            demo: html! {
+                <LocaleProvider locale={Locale::de()}>
+                    <DateRangePicker default_value={week} placeholder="Zeitraum wählen" />
+                </LocaleProvider>
+            },
+            code: r#"<LocaleProvider locale={Locale::de()}>
+    <DateRangePicker default_value={week} placeholder="Zeitraum wählen" />
+</LocaleProvider>"#,
+        },
+    ];
+
+    let props = vec![
+        PropDoc {
+            name: "value",
+            prop_type: "Option<DateRange>",
+            default: "-",
+            description: "Controlled selected range",
+        },
+        PropDoc {
+            name: "default_value",
+            prop_type: "Option<DateRange>",
+            default: "-",
+            description: "Initially selected range (uncontrolled)",
+        },
+        PropDoc {
+            name: "on_change",
+            prop_type: "Callback<DateRange>",
+            default: "-",
+            description: "Called when a preset is chosen or a range is completed",
+        },
+        PropDoc {
+            name: "presets",
+            prop_type: "Option<Vec<DateRangePreset>>",
+            default: "DateRangePreset::defaults(&locale)",
+            description: "Ranges of the preset sidebar, hidden when empty",
+        },
+        PropDoc {
+            name: "number_of_months",
+            prop_type: "u32",
+            default: "2",
+            description: "Months shown side by side",
+        },
+        PropDoc {
+            name: "placeholder",
+            prop_type: "AttrValue",
+            default: "\"Pick a date range\"",
+            description: "Placeholder text",
+        },
+        PropDoc {
+            name: "disabled",
+            prop_type: "bool",
+            default: "false",
+            description: "Disable the picker",
+        },
+        PropDoc {
+            name: "min_date / max_date",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Selectable date limits (YYYY-MM-DD)",
+        },
+        PropDoc {
+            name: "min_range_days / max_range_days",
+            prop_type: "Option<u32>",
+            default: "-",
+            description: "Range length limits in days, bounds included",
+        },
+        PropDoc {
+            name: "format",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Display format pattern such as \"DD.MM.YYYY\", defaults to the locale's",
+        },
+    ];
+
+    html! { <ComponentPage name="Date Range Picker" description="A date range picker with presets and a two-month calendar." {examples} {props} /> }
+}
@@ -0,0 +1,147 @@
+//! DateTimePicker component showcase page
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use shadcn_rs::{DateTimePicker, HourCycle, Locale, LocaleProvider};
+use yew::prelude::*;
+
+use crate::components::{ComponentPage, Example, PropDoc};
+
+#[function_component(DateTimePickerPage)]
+pub fn date_time_picker_page() -> Html {
+    let zoned = use_state(|| None::<DateTime<FixedOffset>>);
+    let on_zoned_change = {
+        let zoned = zoned.clone();
+        Callback::from(move |at: DateTime<FixedOffset>| zoned.set(Some(at)))
+    };
+
+    let meeting = NaiveDate::from_ymd_opt(2024, 9, 16).and_then(|date| date.and_hms_opt(15, 0, 0));
+
+    let examples = vec![
+        Example {
+            title: "Default",
+            description: "Pick a date on the calendar, then set the time below it.",
+            demo: html! {
+                <DateTimePicker />
+            },
+            code: r#"<DateTimePicker on_change={on_change} />"#,
+        },
+        Example {
+            title: "12-Hour Clock",
+            description: "Quarter-hour steps on a 12-hour clock.",
+            demo: html! {
+                <DateTimePicker
+                    default_value={meeting}
+                    hour_cycle={HourCycle::H12}
+                    minute_step={15}
+                />
+            },
+            code: r#"<DateTimePicker
+    default_value={NaiveDate::from_ymd_opt(2024, 9, 16).and_then(|date| date.and_hms_opt(15, 0, 0))}
+    hour_cycle={HourCycle::H12}
+    minute_step={15}
+/>"#,
+        },
+        Example {
+            title: "Time Zone",
+            description: "With a UTC offset, the value is also reported as a timezone-aware DateTime.",
+            demo: html! {
+                <div class="grid gap-2">
+                    <DateTimePicker
+                        utc_offset={FixedOffset::east_opt(9 * 3600)}
+                        {on_zoned_change}
+                    />
+                    <p class="text-sm text-muted-foreground">
+                        { zoned.map_or("No time selected".to_string(), |at| format!("UTC: {}", at.naive_utc())) }
+                    </p>
+                </div>
+            },
+            code: r#"<DateTimePicker
+    utc_offset={FixedOffset::east_opt(9 * 3600)}
+    on_zoned_change={Callback::from(|at: DateTime<FixedOffset>| { /* ... */ })}
+/>"#,
+        },
+        Example {
+            title: "Localized",
+            description: "The date is shown in the date format of the locale.",
+            demo: html! {
+                <LocaleProvider locale={Locale::de()}>
+                    <DateTimePicker default_value={meeting} placeholder="Datum und Uhrzeit" />
+                </LocaleProvider>
+            },
+            code: r#"<LocaleProvider locale={Locale::de()}>
+    <DateTimePicker default_value={meeting} placeholder="Datum und Uhrzeit" />
+</LocaleProvider>"#,
+        },
+    ];
+
+    let props = vec![
+        PropDoc {
+            name: "value",
+            prop_type: "Option<NaiveDateTime>",
+            default: "-",
+            description: "Controlled local date and time",
+        },
+        PropDoc {
+            name: "default_value",
+            prop_type: "Option<NaiveDateTime>",
+            default: "-",
+            description: "Initial local date and time (uncontrolled)",
+        },
+        PropDoc {
+            name: "on_change",
+            prop_type: "Callback<NaiveDateTime>",
+            default: "-",
+            description: "Called when the date or time changes",
+        },
+        PropDoc {
+            name: "utc_offset",
+            prop_type: "Option<FixedOffset>",
+            default: "-",
+            description: "Offset of the local value, the browser's time zone when not set",
+        },
+        PropDoc {
+            name: "on_zoned_change",
+            prop_type: "Callback<DateTime<FixedOffset>>",
+            default: "-",
+            description: "Called with the timezone-aware value",
+        },
+        PropDoc {
+            name: "default_time",
+            prop_type: "NaiveTime",
+            default: "00:00",
+            description: "Time of a date picked before any time is set",
+        },
+        PropDoc {
+            name: "hour_cycle",
+            prop_type: "HourCycle",
+            default: "H24",
+            description: "12-hour or 24-hour clock",
+        },
+        PropDoc {
+            name: "show_seconds",
+            prop_type: "bool",
+            default: "false",
+            description: "Show a seconds field",
+        },
+        PropDoc {
+            name: "minute_step",
+            prop_type: "u32",
+            default: "1",
+            description: "Increment between the offered minutes",
+        },
+        PropDoc {
+            name: "min_date / max_date",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Selectable date limits (YYYY-MM-DD)",
+        },
+        PropDoc {
+            name: "format",
+            prop_type: "Option<AttrValue>",
+            default: "-",
+            description: "Date display format pattern, defaults to the locale's",
+        },
+    ];
+
+    html! { <ComponentPage name="Date Time Picker" description="A calendar and time picker in a popover, producing a date and time." {examples} {props} /> }
+}
@@ -66,7 +66,10 @@ mod calendar_page;
 mod combobox_page;
 mod command_page;
 mod date_picker_page;
+mod date_range_picker_page;
+mod date_time_picker_page;
 mod input_otp_page;
+mod time_picker_page;
 
 // Tier 8 - Complex
 mod carousel_page;
@@ -135,7 +138,10 @@ pub use calendar_page::CalendarPage;
 pub use combobox_page::ComboboxPage;
 pub use command_page::CommandPage;
 pub use date_picker_page::DatePickerPage;
+pub use date_range_picker_page::DateRangePickerPage;
+pub use date_time_picker_page::DateTimePickerPage;
 pub use input_otp_page::InputOtpPage;
+pub use time_picker_page::TimePickerPage;
 
 pub use carousel_page::CarouselPage;
 pub use chart_page::ChartPage;
@@ -7,6 +7,12 @@ use crate::components::{ComponentPage, Example, PropDoc};
 
 #[function_component(ResizablePage)]
 pub fn resizable_page() -> Html {
+    let sizes = use_state(|| vec![30.0, 70.0]);
+    let on_sizes_change = {
+        let sizes = sizes.clone();
+        Callback::from(move |next: Vec<f64>| sizes.set(next))
+    };
+
     let examples = vec![
         Example {
             title: "Horizontal",
@@ -40,7 +46,7 @@ pub fn resizable_page() -> Html {
             title: "Vertical",
             description: "Vertically resizable panels.",
             demo: html! {
-                <Resizable orientation={ResizableOrientation::Vertical} class="min-h-[200px] max-w-md rounded-lg border">
+                <Resizable orientation={ResizableOrientation::Vertical} default_sizes={vec![25.0, 75.0]} class="min-h-[200px] max-w-md rounded-lg border">
                     <ResizablePanel index={0} default_size={25.0}>
                         <div class="flex h-full items-center justify-center p-6">
                             <span class="font-semibold">{ "Header" }</span>
@@ -54,7 +60,7 @@ pub fn resizable_page() -> Html {
                     </ResizablePanel>
                 </Resizable>
             },
-            code: r##"<Resizable orientation={ResizableOrientation::Vertical}>
+            code: r##"<Resizable orientation={ResizableOrientation::Vertical} default_sizes={vec![25.0, 75.0]}>
     <ResizablePanel index={0} default_size={25.0}>
         { "Header" }
     </ResizablePanel>
@@ -64,6 +70,49 @@ pub fn resizable_page() -> Html {
     </ResizablePanel>
 </Resizable>"##,
         },
+        Example {
+            title: "Controlled",
+            description: "Panel sizes owned by the parent, e.g. to save them with use_persisted_state (serde feature).",
+            demo: html! {
+                <div class="space-y-2">
+                    <Resizable
+                        sizes={Some((*sizes).clone())}
+                        on_sizes_change={on_sizes_change}
+                        class="min-h-[200px] max-w-md rounded-lg border"
+                    >
+                        <ResizablePanel index={0}>
+                            <div class="flex h-full items-center justify-center p-6">
+                                <span class="font-semibold">{ "Sidebar" }</span>
+                            </div>
+                        </ResizablePanel>
+                        <ResizableHandle />
+                        <ResizablePanel index={1}>
+                            <div class="flex h-full items-center justify-center p-6">
+                                <span class="font-semibold">{ "Content" }</span>
+                            </div>
+                        </ResizablePanel>
+                    </Resizable>
+                    <p class="text-sm text-muted-foreground">
+                        { format!("Sizes: {:.0}% / {:.0}%", sizes[0], sizes[1]) }
+                    </p>
+                </div>
+            },
+            code: r##"// With the serde feature, sizes survive a reload:
+// let (sizes, set_sizes) = use_persisted_state("app:panels", vec![30.0, 70.0]);
+let sizes = use_state(|| vec![30.0, 70.0]);
+let on_sizes_change = {
+    let sizes = sizes.clone();
+    Callback::from(move |next: Vec<f64>| sizes.set(next))
+};
+
+html! {
+    <Resizable sizes={Some((*sizes).clone())} {on_sizes_change}>
+        <ResizablePanel index={0}>{ "Sidebar" }</ResizablePanel>
+        <ResizableHandle />
+        <ResizablePanel index={1}>{ "Content" }</ResizablePanel>
+    </Resizable>
+}"##,
+        },
     ];
 
     let props = vec![
@@ -73,6 +122,24 @@ pub fn resizable_page() -> Html {
             default: "Horizontal",
             description: "Panel orientation (Horizontal or Vertical)",
         },
+        PropDoc {
+            name: "sizes",
+            prop_type: "Option<Vec<f64>>",
+            default: "None",
+            description: "Panel sizes % (controlled)",
+        },
+        PropDoc {
+            name: "default_sizes",
+            prop_type: "Vec<f64>",
+            default: "[50.0, 50.0]",
+            description: "Initial panel sizes % (uncontrolled)",
+        },
+        PropDoc {
+            name: "on_sizes_change",
+            prop_type: "Option<Callback<Vec<f64>>>",
+            default: "None",
+            description: "Called with the new sizes while dragging",
+        },
         PropDoc {
             name: "index",
             prop_type: "usize",
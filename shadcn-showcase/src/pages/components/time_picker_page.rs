@@ -0,0 +1,98 @@
+//! TimePicker component showcase page
+
+use chrono::NaiveTime;
+use shadcn_rs::{HourCycle, TimePicker};
+use yew::prelude::*;
+
+use crate::components::{ComponentPage, Example, PropDoc};
+
+#[function_component(TimePickerPage)]
+pub fn time_picker_page() -> Html {
+    let examples = vec![
+        Example {
+            title: "Default",
+            description: "Hours and minutes on a 24-hour clock.",
+            demo: html! {
+                <TimePicker />
+            },
+            code: r#"<TimePicker on_change={on_change} />"#,
+        },
+        Example {
+            title: "12-Hour Clock",
+            description: "Hours 1 to 12 with an AM/PM field.",
+            demo: html! {
+                <TimePicker
+                    hour_cycle={HourCycle::H12}
+                    default_value={NaiveTime::from_hms_opt(14, 30, 0)}
+                />
+            },
+            code: r#"<TimePicker
+    hour_cycle={HourCycle::H12}
+    default_value={NaiveTime::from_hms_opt(14, 30, 0)}
+/>"#,
+        },
+        Example {
+            title: "Minute Step",
+            description: "Offer minutes in quarter-hour increments.",
+            demo: html! {
+                <TimePicker minute_step={15} default_value={NaiveTime::from_hms_opt(9, 0, 0)} />
+            },
+            code: r#"<TimePicker minute_step={15} default_value={NaiveTime::from_hms_opt(9, 0, 0)} />"#,
+        },
+        Example {
+            title: "With Seconds",
+            description: "Add a seconds field.",
+            demo: html! {
+                <TimePicker show_seconds=true default_value={NaiveTime::from_hms_opt(8, 15, 42)} />
+            },
+            code: r#"<TimePicker show_seconds=true default_value={NaiveTime::from_hms_opt(8, 15, 42)} />"#,
+        },
+    ];
+
+    let props = vec![
+        PropDoc {
+            name: "value",
+            prop_type: "Option<NaiveTime>",
+            default: "-",
+            description: "Controlled selected time",
+        },
+        PropDoc {
+            name: "default_value",
+            prop_type: "Option<NaiveTime>",
+            default: "-",
+            description: "Initially selected time (uncontrolled)",
+        },
+        PropDoc {
+            name: "on_change",
+            prop_type: "Callback<NaiveTime>",
+            default: "-",
+            description: "Called when a field changes",
+        },
+        PropDoc {
+            name: "hour_cycle",
+            prop_type: "HourCycle",
+            default: "H24",
+            description: "12-hour (with AM/PM) or 24-hour clock",
+        },
+        PropDoc {
+            name: "show_seconds",
+            prop_type: "bool",
+            default: "false",
+            description: "Show a seconds field",
+        },
+        PropDoc {
+            name: "minute_step",
+            prop_type: "u32",
+            default: "1",
+            description: "Increment between the offered minutes",
+        },
+        PropDoc {
+            name: "disabled",
+            prop_type: "bool",
+            default: "false",
+            description: "Disable the fields",
+        },
+    ];
+
+    html! { <ComponentPage name="Time Picker" description="Hour, minute and second fields for picking a time of day." {examples} {props} /> }
+}
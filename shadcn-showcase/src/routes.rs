@@ -140,8 +140,14 @@ pub enum Route {
     Command,
     #[at("/components/date-picker")]
     DatePicker,
+    #[at("/components/date-range-picker")]
+    DateRangePicker,
+    #[at("/components/date-time-picker")]
+    DateTimePicker,
     #[at("/components/input-otp")]
     InputOtp,
+    #[at("/components/time-picker")]
+    TimePicker,
 
     // Tier 8 - Complex
     #[at("/components/carousel")]
@@ -448,10 +454,22 @@ pub fn get_nav_groups() -> Vec<NavGroup> {
                     label: "Date Picker",
                     route: Route::DatePicker,
                 },
+                NavItem {
+                    label: "Date Range Picker",
+                    route: Route::DateRangePicker,
+                },
+                NavItem {
+                    label: "Date Time Picker",
+                    route: Route::DateTimePicker,
+                },
                 NavItem {
                     label: "Input OTP",
                     route: Route::InputOtp,
                 },
+                NavItem {
+                    label: "Time Picker",
+                    route: Route::TimePicker,
+                },
             ],
         },
         NavGroup {